@@ -36,7 +36,43 @@ type XpsPrintoutPageConversion = {
 	outputPath: string;
 };
 
-type NativeOneNoteConverter = (notebookPath: string, outputDirectory: string, baseDir: string)=> Promise<void>;
+// See ConversionReport in the onenote-converter package
+type PageConversionReport = {
+	title: string|null;
+	parseError: string|null;
+	renderError: string|null;
+};
+type SectionConversionReport = {
+	name: string;
+	sourcePath: string;
+	error: string|null;
+	pages: PageConversionReport[];
+};
+type ConversionReport = {
+	sections: SectionConversionReport[];
+};
+
+// Returns a description of each section in the report that failed to convert, or that has
+// pages that failed to convert.
+const conversionErrors = (report: ConversionReport) => {
+	const errors = [];
+	for (const section of report.sections) {
+		if (section.error) {
+			errors.push(`Failed to import section ${section.name}: ${section.error}`);
+			continue;
+		}
+
+		const pageErrors = section.pages
+			.map(page => page.parseError ?? (page.renderError && `${page.title ?? 'Untitled Page'}: ${page.renderError}`))
+			.filter(error => !!error);
+		if (pageErrors.length) {
+			errors.push(`${pageErrors.length} page(s) in section ${section.name} failed to import. First error: ${pageErrors[0]}`);
+		}
+	}
+	return errors;
+};
+
+type NativeOneNoteConverter = (notebookPath: string, outputDirectory: string, baseDir: string)=> Promise<string>;
 const getOneNoteConverter = (): NativeOneNoteConverter => {
 	try {
 		return shim.requireDynamic('@joplin/onenote-converter').oneNoteConverter;
@@ -153,7 +189,12 @@ export default class InteropService_Importer_OneNote extends InteropService_Impo
 				// shown. Work around this by temporarily disabling the dialog:
 				setEnableUnresponsiveCheck(false);
 
-				await oneNoteConverter(notebookFilePath, resolve(outputDirectory2), notebookBaseDir);
+				const report: ConversionReport = JSON.parse(
+					await oneNoteConverter(notebookFilePath, resolve(outputDirectory2), notebookBaseDir),
+				);
+				for (const error of conversionErrors(report)) {
+					this.options_.onError?.(error);
+				}
 			} catch (error) {
 				// Forward only the error message. Usually the stack trace points to bytes in the WASM file.
 				// It's very difficult to use and can cause the error report to be longer than the maximum
@@ -1,5 +1,6 @@
 
 const fs = require('node:fs');
+const os = require('node:os');
 const path = require('node:path');
 
 function mkdirSyncRecursive(filepath) {
@@ -33,6 +34,20 @@ function normalizeAndAppendFile(filePath, data) {
 	fs.appendFileSync(filePath, data);
 }
 
+function normalizeAndRemoveFile(filePath) {
+	filePath = path.normalize(filePath);
+	fs.unlinkSync(filePath);
+}
+
+function makeTempDir() {
+	return fs.mkdtempSync(path.join(os.tmpdir(), 'onenote-converter-'));
+}
+
+function normalizeAndRemoveDir(dirPath) {
+	dirPath = path.normalize(dirPath);
+	fs.rmSync(dirPath, { recursive: true, force: true });
+}
+
 function fileReader(path) {
 	const fd = fs.openSync(path);
 	// TODO: When Node v20 is EOL, replace this with the { bigint: true }
@@ -72,6 +87,9 @@ module.exports = {
 	removePrefix,
 	normalizeAndWriteFile,
 	normalizeAndAppendFile,
+	normalizeAndRemoveFile,
+	makeTempDir,
+	normalizeAndRemoveDir,
 	fileReader,
 	isWindows,
 };
@@ -20,6 +20,24 @@ pub trait FileApiDriver: Send + Sync {
     /// Note: If `stream.read` fails, the file may be left in a partially-written state.
     fn stream_to_file(&self, path: &str, stream: &mut dyn Read) -> ApiResult<()>;
 
+    /// Writes data from `stream` to the file at `path`, like [`FileApiDriver::stream_to_file`],
+    /// but keeps at most `buffer_size` bytes of the stream in memory at a time.
+    fn stream_to_file_with_buffer_size(
+        &self,
+        path: &str,
+        stream: &mut dyn Read,
+        buffer_size: usize,
+    ) -> ApiResult<()>;
+
+    fn remove_file(&self, path: &str) -> ApiResult<()>;
+
+    /// Creates a new, empty directory for temporary files in the system's temporary
+    /// directory, and returns its path.
+    fn make_temp_dir(&self) -> ApiResult<String>;
+
+    /// Removes the directory at `path` and everything in it.
+    fn remove_dir(&self, path: &str) -> ApiResult<()>;
+
     // These functions correspond to the similarly-named
     // NodeJS path functions and should behave like the NodeJS
     // functions (rather than the corresponding Rust functions).
@@ -3,9 +3,10 @@ use super::FileApiDriver;
 use super::FileHandle;
 use std::fs;
 use std::fs::File;
-use std::io::BufReader;
+use std::io::{BufReader, Write};
 use std::path;
 use std::path::Path;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 pub struct FileApiDriverImpl {}
 
@@ -53,6 +54,52 @@ impl FileApiDriver for FileApiDriverImpl {
         Ok(())
     }
 
+    fn stream_to_file_with_buffer_size(
+        &self,
+        path: &str,
+        data: &mut dyn std::io::Read,
+        buffer_size: usize,
+    ) -> ApiResult<()> {
+        let mut f = File::create(path)?;
+        let mut buffer = vec![0; buffer_size.max(1)];
+
+        loop {
+            let size = match data.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(size) => size,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+
+            f.write_all(&buffer[0..size])?;
+        }
+        Ok(())
+    }
+
+    fn remove_file(&self, path: &str) -> ApiResult<()> {
+        fs::remove_file(path)
+    }
+
+    fn make_temp_dir(&self) -> ApiResult<String> {
+        static NEXT_ID: AtomicU32 = AtomicU32::new(0);
+
+        loop {
+            let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+            let name = format!("onenote-converter-{}-{}", std::process::id(), id);
+            let path = std::env::temp_dir().join(name);
+            match fs::create_dir(&path) {
+                Ok(()) => return Ok(path.to_string_lossy().into()),
+                // Left over from an earlier process with the same ID
+                Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    fn remove_dir(&self, path: &str) -> ApiResult<()> {
+        fs::remove_dir_all(path)
+    }
+
     fn exists(&self, path: &str) -> ApiResult<bool> {
         fs::exists(path)
     }
@@ -31,6 +31,15 @@ extern "C" {
     #[wasm_bindgen(js_name = normalizeAndAppendFile, catch)]
     fn append_file(path: &str, data: &[u8]) -> std::result::Result<JsValue, JsValue>;
 
+    #[wasm_bindgen(js_name = normalizeAndRemoveFile, catch)]
+    fn remove_file(path: &str) -> std::result::Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = makeTempDir, catch)]
+    fn make_temp_dir() -> std::result::Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = normalizeAndRemoveDir, catch)]
+    fn remove_dir(path: &str) -> std::result::Result<JsValue, JsValue>;
+
     #[wasm_bindgen(js_name = isDirectory, catch)]
     fn is_directory(path: &str) -> std::result::Result<bool, JsValue>;
 
@@ -149,40 +158,42 @@ impl FileApiDriver for FileApiDriverImpl {
     }
 
     fn stream_to_file(&self, path: &str, data: &mut dyn std::io::Read) -> ApiResult<()> {
-        // Create and clear the file. This is important for zero-size files
-        self.write_file(path, &[])?;
-
-        let mut chunk_size = 1024 * 1024; // 1 MB
+        let chunk_size = 1024 * 1024; // 1 MB
         let max_chunk_size = 50 * 1024 * 1024;
-        let mut buffer = vec![0; chunk_size];
-
-        loop {
-            let size = match data.read(&mut buffer) {
-                Ok(size) => size,
-                // Interrupted errors can be retried
-                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {
-                    continue;
-                }
-                Err(err) => {
-                    return Err(err);
-                }
-            };
+        stream_to_file_in_chunks(path, data, chunk_size, max_chunk_size)
+    }
 
-            if size == 0 {
-                break;
-            }
+    fn stream_to_file_with_buffer_size(
+        &self,
+        path: &str,
+        data: &mut dyn std::io::Read,
+        buffer_size: usize,
+    ) -> ApiResult<()> {
+        let buffer_size = buffer_size.max(1);
+        stream_to_file_in_chunks(path, data, buffer_size, buffer_size)
+    }
 
-            if let Err(error) = append_file(path, &buffer[0..size]) {
-                return Err(handle_error(error, &format!("writing file {}", path)));
-            }
+    fn remove_file(&self, path: &str) -> ApiResult<()> {
+        if let Err(error) = remove_file(path) {
+            Err(handle_error(error, &format!("removing file {}", path)))
+        } else {
+            Ok(())
+        }
+    }
 
-            // For performance, try to increase the chunk size
-            if size == chunk_size && chunk_size < max_chunk_size {
-                chunk_size = (chunk_size * 2).min(max_chunk_size);
-                buffer.resize(chunk_size, 0);
-            }
+    fn make_temp_dir(&self) -> ApiResult<String> {
+        match make_temp_dir() {
+            Ok(path) => Ok(path.as_string().unwrap()),
+            Err(e) => Err(handle_error(e, "creating temporary directory")),
+        }
+    }
+
+    fn remove_dir(&self, path: &str) -> ApiResult<()> {
+        if let Err(error) = remove_dir(path) {
+            Err(handle_error(error, &format!("removing directory {}", path)))
+        } else {
+            Ok(())
         }
-        Ok(())
     }
 
     fn exists(&self, path: &str) -> ApiResult<bool> {
@@ -219,6 +230,48 @@ impl FileApiDriver for FileApiDriverImpl {
     }
 }
 
+fn stream_to_file_in_chunks(
+    path: &str,
+    data: &mut dyn std::io::Read,
+    mut chunk_size: usize,
+    max_chunk_size: usize,
+) -> ApiResult<()> {
+    // Create and clear the file. This is important for zero-size files
+    if let Err(error) = write_file(path, &[]) {
+        return Err(handle_error(error, &format!("writing file {}", path)));
+    }
+
+    let mut buffer = vec![0; chunk_size];
+
+    loop {
+        let size = match data.read(&mut buffer) {
+            Ok(size) => size,
+            // Interrupted errors can be retried
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {
+                continue;
+            }
+            Err(err) => {
+                return Err(err);
+            }
+        };
+
+        if size == 0 {
+            break;
+        }
+
+        if let Err(error) = append_file(path, &buffer[0..size]) {
+            return Err(handle_error(error, &format!("writing file {}", path)));
+        }
+
+        // For performance, try to increase the chunk size
+        if size == chunk_size && chunk_size < max_chunk_size {
+            chunk_size = (chunk_size * 2).min(max_chunk_size);
+            buffer.resize(chunk_size, 0);
+        }
+    }
+    Ok(())
+}
+
 struct SeekableFileHandle {
     handle: JsFileHandle,
     offset: u64,
@@ -1,6 +1,4 @@
-use std::sync::Mutex;
-
-use lazy_static::lazy_static;
+use std::cell::RefCell;
 
 #[macro_export]
 #[cfg(target_arch = "wasm32")]
@@ -39,18 +37,19 @@ macro_rules! log_warn {
 	}
 }
 
-lazy_static! {
-    static ref CURRENT_PAGE: Mutex<Option<String>> = Mutex::new(None);
+thread_local! {
+    // Tracked per thread, since sections may be parsed on different threads
+    static CURRENT_PAGE: RefCell<Option<String>> = const { RefCell::new(None) };
 }
 
 pub fn set_current_page(page_name: String) {
-    let mut current_page = CURRENT_PAGE.lock().unwrap();
-    *current_page = Some(page_name.to_string());
+    CURRENT_PAGE.with_borrow_mut(|current_page| *current_page = Some(page_name));
 }
 
 pub fn get_current_page() -> String {
-    let current_page = CURRENT_PAGE.lock().unwrap();
-    current_page
-        .clone()
-        .unwrap_or_else(|| String::from("[None]"))
+    CURRENT_PAGE.with_borrow(|current_page| {
+        current_page
+            .clone()
+            .unwrap_or_else(|| String::from("[None]"))
+    })
 }
@@ -2,11 +2,11 @@ use crate::{
     FileHandle,
     errors::{ErrorKind, Result},
 };
-use bytes::Buf;
+use bytes::{Buf, Bytes};
 use paste::paste;
 use std::{
     cell::RefCell,
-    io::{Cursor, Read, Seek, SeekFrom},
+    io::{Read, Seek, SeekFrom},
     mem,
     rc::Rc,
 };
@@ -47,6 +47,10 @@ enum ReaderData<'a> {
     /// small amounts of data.
     BufferRef { buffer: &'a [u8] },
 
+    /// Wraps a reference-counted buffer. Data references into the buffer
+    /// share it rather than copying it.
+    Shared { buffer: Bytes },
+
     /// Wraps a handle to a file. This handles large amounts of data
     /// that won't necessarily fit into memory.
     /// Invariant: The internal file handle's offset should match the
@@ -120,6 +124,10 @@ impl<'a> Reader<'a> {
                 let start = self.data_offset as usize;
                 (&buffer[start..start + count]).copy_to_slice(output);
             }
+            ReaderData::Shared { buffer } => {
+                let start = self.data_offset as usize;
+                (&buffer[start..start + count]).copy_to_slice(output);
+            }
             ReaderData::File(file) => {
                 file.borrow_mut().read_exact(output)?;
             }
@@ -134,6 +142,7 @@ impl<'a> Reader<'a> {
             ReaderData::BufferRef { buffer, .. } => {
                 Ok(buffer.get(self.data_offset as usize).copied())
             }
+            ReaderData::Shared { buffer } => Ok(buffer.get(self.data_offset as usize).copied()),
             ReaderData::File(file) => {
                 let mut file = file.borrow_mut();
                 let mut buf = [0u8];
@@ -160,8 +169,14 @@ impl<'a> Reader<'a> {
             ReaderData::BufferRef { buffer } => {
                 let start = self.data_offset as usize;
                 // Cloning needs to be done early with BufferRef, since we don't own the original
-                // data. Large data should generally use `ReaderData::File`.
-                Ok(ReaderDataRef::Vec(buffer[start..start + size].to_vec()))
+                // data. Large data should generally use `ReaderData::File` or `ReaderData::Shared`.
+                Ok(ReaderDataRef::Bytes(Bytes::copy_from_slice(
+                    &buffer[start..start + size],
+                )))
+            }
+            ReaderData::Shared { buffer } => {
+                let start = self.data_offset as usize;
+                Ok(ReaderDataRef::Bytes(buffer.slice(start..start + size)))
             }
             ReaderData::File(file) => Ok(ReaderDataRef::FilePointer(ReaderFilePointer {
                 file: file.clone(),
@@ -237,7 +252,7 @@ impl<'a> TryFrom<Box<dyn FileHandle>> for Reader<'a> {
     type Error = crate::errors::Error;
 
     fn try_from(mut handle: Box<dyn FileHandle>) -> Result<Self> {
-        let initial_offset = handle.seek(SeekFrom::Current(0))?;
+        let initial_offset = handle.stream_position()?;
         Ok(Self {
             data_len: handle.byte_length(),
             data_offset: initial_offset,
@@ -246,6 +261,16 @@ impl<'a> TryFrom<Box<dyn FileHandle>> for Reader<'a> {
     }
 }
 
+impl From<Bytes> for Reader<'static> {
+    fn from(value: Bytes) -> Self {
+        Self {
+            data_len: value.len() as u64,
+            data_offset: 0,
+            data: ReaderData::Shared { buffer: value },
+        }
+    }
+}
+
 impl<'a> From<&'a [u8]> for Reader<'a> {
     fn from(value: &'a [u8]) -> Self {
         Self {
@@ -257,17 +282,26 @@ impl<'a> From<&'a [u8]> for Reader<'a> {
 }
 
 pub enum ReaderDataRef {
-    Vec(Vec<u8>),
+    Bytes(Bytes),
     FilePointer(ReaderFilePointer),
 }
 
 impl ReaderDataRef {
     pub fn read(&self) -> Box<dyn Read> {
         match self {
-            ReaderDataRef::Vec(slice) => Box::new(Cursor::new(slice.to_vec())),
+            // Cloning `Bytes` only increments a reference count
+            ReaderDataRef::Bytes(bytes) => Box::new(bytes.clone().reader()),
             ReaderDataRef::FilePointer(ptr) => Box::new(ptr.clone()),
         }
     }
+
+    /// The referenced data, if it is held in memory.
+    pub fn bytes(&self) -> Option<Bytes> {
+        match self {
+            ReaderDataRef::Bytes(bytes) => Some(bytes.clone()),
+            ReaderDataRef::FilePointer(_) => None,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -353,4 +387,23 @@ mod test {
         // Reading the data_ref should not affect the original reader
         assert_eq!(reader.get_u8().unwrap(), 4);
     }
+
+    #[test]
+    fn should_share_data_from_shared_buffers() {
+        let data = Bytes::from_static(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        let mut reader = Reader::from(data.clone());
+
+        reader.advance(2).unwrap();
+        let data_ref = reader.as_data_ref(3).unwrap();
+        assert_eq!(reader.get_u8().unwrap(), 3);
+
+        // The data_ref should point into the original buffer rather than a copy
+        let bytes = data_ref.bytes().unwrap();
+        assert_eq!(&bytes[..], [3, 4, 5]);
+        assert_eq!(bytes.as_ptr(), data[2..].as_ptr());
+
+        let mut output = vec![];
+        data_ref.read().read_to_end(&mut output).unwrap();
+        assert_eq!(output, [3, 4, 5]);
+    }
 }
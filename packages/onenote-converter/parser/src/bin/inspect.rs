@@ -1,37 +1,74 @@
 use parser::Parser;
+use parser::inspect::{Inspector, Value};
 use parser_utils::errors::Error;
-use std::{
-    env::{self, Args},
-    process::exit,
-};
+use std::{env, fs, io, path::Path, process::exit};
 
 pub fn main() {
-    let config = match Config::from_args(&mut env::args()) {
+    let config = match Config::from_args(env::args().collect()) {
         Ok(config) => config,
         Err(error) => {
-            print_help_text(&error.program_name, error.reason);
+            print_help_text(&error.program_name, &error.reason);
             exit(1)
         }
     };
 
-    let input_path_string = &config.input_file;
+    let input_path = config.input_file.as_str();
+    let output = match &config.command {
+        Command::Section => {
+            let section = Parser::new()
+                .parse_section(input_path)
+                .unwrap_or_else(|error| handle_parse_error(&config, error));
+            println!("{:#?}", section);
+            return;
+        }
+        Command::Raw => {
+            let onestore = Parser::new()
+                .parse_onestore_raw(input_path)
+                .unwrap_or_else(|error| handle_parse_error(&config, error));
+            println!("{:#?}", onestore);
+            return;
+        }
+        Command::Spaces { include_objects } => open(&config).object_spaces(*include_objects),
+        Command::Object { id } => open(&config)
+            .object(id)
+            .unwrap_or_else(|error| handle_parse_error(&config, error)),
+        Command::Nodes { include_data } => open(&config).file_node_tree(*include_data),
+        Command::Extract { output_dir } => match extract_files(&open(&config), output_dir) {
+            Ok(result) => result,
+            Err(error) => {
+                eprintln!("Unable to write files: {error}");
+                exit(4)
+            }
+        },
+    };
 
-    let mut parser = Parser::new();
-    if config.output_mode == OutputMode::Section {
-        let parsed_section = match parser.parse_section(input_path_string) {
-            Ok(section) => section,
-            Err(error) => handle_parse_error(&config, error),
-        };
+    match config.format {
+        Format::Json => println!("{}", output.to_json()),
+        Format::Tree => print!("{}", output.to_tree()),
+    }
+}
 
-        println!("{:#?}", parsed_section);
-    } else {
-        let parsed_onestore = match parser.parse_onestore_raw(input_path_string) {
-            Ok(section) => section,
-            Err(error) => handle_parse_error(&config, error),
-        };
+fn open(config: &Config) -> Inspector {
+    Inspector::open(&config.input_file).unwrap_or_else(|error| handle_parse_error(config, error))
+}
+
+fn extract_files(inspector: &Inspector, output_dir: &str) -> io::Result<Value> {
+    fs::create_dir_all(output_dir)?;
 
-        println!("{:#?}", parsed_onestore);
+    let mut written = Vec::new();
+    for file in inspector.stored_files() {
+        let path = Path::new(output_dir).join(format!("{}.bin", file.id));
+        let mut reader = file.read().map_err(io::Error::other)?;
+        io::copy(&mut reader, &mut fs::File::create(&path)?)?;
+
+        written.push(Value::record([
+            ("id", file.id.as_str().into()),
+            ("size", file.size.into()),
+            ("path", path.to_string_lossy().to_string().into()),
+        ]));
     }
+
+    Ok(Value::record([("files", written.into())]))
 }
 
 fn handle_parse_error(config: &Config, error: Error) -> ! {
@@ -41,70 +78,124 @@ fn handle_parse_error(config: &Config, error: Error) -> ! {
 }
 
 fn print_help_text(program_name: &str, error: &str) {
-    let error_info = if error.is_empty() { "" } else { error };
-
-    eprintln!("Usage: {program_name} <input_file> [--section|--onestore]");
+    eprintln!("Usage: {program_name} <command> <input_file> [arguments] [--format json|tree]");
     eprintln!("Description: Prints debug information about the given <input_file>");
-    eprintln!("{error_info}");
+    eprintln!("Commands:");
+    eprintln!("  spaces <input_file> [--objects]    List object spaces and their revisions");
+    eprintln!("  object <input_file> <id>           Dump the properties of an object ({{GUID}},n)");
+    eprintln!("  nodes <input_file> [--data]        Print the file node tree, with offsets");
+    eprintln!("  extract <input_file> <output_dir>  Write stored files (images, attachments)");
+    eprintln!("  section <input_file>               Print the parsed section");
+    eprintln!("  raw <input_file>                   Print the raw OneStore data");
+    eprintln!("{error}");
 }
 
 struct ConfigParseError {
-    reason: &'static str,
+    reason: String,
     program_name: String,
 }
 
-#[derive(PartialEq)]
-enum OutputMode {
-    /// Lower-level output
-    FileContent,
+enum Command {
+    /// Lists object spaces and revisions
+    Spaces { include_objects: bool },
+    /// Dumps a single object's properties
+    Object { id: String },
+    /// Shows the file node list tree
+    Nodes { include_data: bool },
+    /// Writes the file data store's files to a directory
+    Extract { output_dir: String },
     /// Higher-level output, including the parsed objects
     Section,
+    /// Lower-level debug output
+    Raw,
+}
+
+enum Format {
+    Json,
+    Tree,
 }
 
 struct Config {
+    command: Command,
     input_file: String,
-    output_mode: OutputMode,
+    format: Format,
     program_name: String,
 }
 
 impl Config {
-    pub fn from_args(args: &mut Args) -> Result<Self, ConfigParseError> {
-        let Some(program_name) = &args.next() else {
+    pub fn from_args(args: Vec<String>) -> Result<Self, ConfigParseError> {
+        let mut args = args.into_iter();
+        let Some(program_name) = args.next() else {
             return Err(ConfigParseError {
-                reason: "Missing program name",
+                reason: "Missing program name".into(),
                 program_name: "??".into(),
             });
         };
-        let program_name = program_name.to_string();
-        let Some(input_file) = args.next() else {
-            return Err(ConfigParseError {
-                reason: "Not enough arguments",
-                program_name,
-            });
+        let error = |reason: &str| ConfigParseError {
+            reason: reason.into(),
+            program_name: program_name.clone(),
         };
 
-        let output_mode = args.next().unwrap_or("--onestore".into());
-        let output_mode = match output_mode.as_str() {
-            "--onestore" => Ok(OutputMode::FileContent),
-            "--section" => Ok(OutputMode::Section),
-            _ => {
-                return Err(ConfigParseError {
-                    reason: "Invalid output mode (expected --onestore or --section)",
-                    program_name,
-                });
+        // Split flags from positional arguments
+        let mut positional = Vec::new();
+        let mut flags = Vec::new();
+        let mut format = Format::Tree;
+        while let Some(arg) = args.next() {
+            if arg == "--format" {
+                format = match args.next().as_deref() {
+                    Some("json") => Format::Json,
+                    Some("tree") => Format::Tree,
+                    _ => return Err(error("Invalid format (expected json or tree)")),
+                };
+            } else if arg.starts_with("--") {
+                flags.push(arg);
+            } else {
+                positional.push(arg);
             }
-        }?;
+        }
 
-        if args.next().is_some() {
-            return Err(ConfigParseError {
-                reason: "Too many arguments",
-                program_name,
-            });
+        let mut positional = positional.into_iter();
+        let (Some(command), Some(input_file)) = (positional.next(), positional.next()) else {
+            return Err(error("Not enough arguments"));
+        };
+        let mut take_flag = |name: &str| {
+            let index = flags.iter().position(|flag| flag == name);
+            index.map(|index| flags.remove(index)).is_some()
+        };
+
+        let command = match command.as_str() {
+            "spaces" => Command::Spaces {
+                include_objects: take_flag("--objects"),
+            },
+            "object" => Command::Object {
+                id: positional
+                    .next()
+                    .ok_or_else(|| error("Missing object ID"))?,
+            },
+            "nodes" => Command::Nodes {
+                include_data: take_flag("--data"),
+            },
+            "extract" => Command::Extract {
+                output_dir: positional
+                    .next()
+                    .ok_or_else(|| error("Missing output directory"))?,
+            },
+            "section" => Command::Section,
+            "raw" => Command::Raw,
+            _ => return Err(error("Unknown command")),
+        };
+
+        if positional.next().is_some() {
+            return Err(error("Too many arguments"));
+        }
+        if let Some(flag) = flags.first() {
+            return Err(error(&format!("Unknown option: {flag}")));
         }
 
         Ok(Config {
+            command,
             input_file,
-            output_mode,
+            format,
             program_name,
         })
     }
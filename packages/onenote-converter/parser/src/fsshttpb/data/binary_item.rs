@@ -1,5 +1,6 @@
 use crate::shared::compact_u64::CompactU64;
 use crate::shared::file_data_ref::FileBlob;
+use bytes::Bytes;
 use parser_utils::Reader;
 use parser_utils::errors::Result;
 
@@ -26,7 +27,7 @@ impl BinaryItem {
 impl From<BinaryItem> for FileBlob {
     fn from(value: BinaryItem) -> Self {
         let size = value.0.len();
-        let data = value.0;
+        let data = Bytes::from(value.0);
 
         FileBlob::new(Box::new(data), size)
     }
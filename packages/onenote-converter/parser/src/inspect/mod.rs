@@ -0,0 +1,499 @@
+//! Low-level inspection of OneNote 2016 (`.one`/`.onetoc2`) files.
+//!
+//! Used by the `inspect` binary to triage files that fail to import.
+
+use std::io::Read;
+
+use crate::local_onestore::OneStoreFile;
+use crate::local_onestore::file_node::FileNode;
+use crate::local_onestore::file_structure::FileNodeList;
+use crate::one::property::PropertyType;
+use crate::one::property_set::PropertySetId;
+use crate::onestore::object::Object;
+use crate::shared::compact_id::CompactId;
+use crate::shared::exguid::ExGuid;
+use crate::shared::file_data_ref::FileBlob;
+use crate::shared::prop_set::{PropertySet, probable_string};
+use crate::shared::property::{PropertyId, PropertyValue};
+use parser_utils::errors::{ErrorKind, Result};
+use parser_utils::parse::Parse;
+use parser_utils::{fs_driver, reader::Reader};
+
+mod value;
+
+pub use value::Value;
+
+/// Property values longer than this are truncated in the output.
+const MAX_BYTES_SHOWN: usize = 256;
+
+/// Describes the low-level structure of a OneNote 2016 file.
+pub struct Inspector {
+    file: OneStoreFile,
+}
+
+/// A file stored in a OneNote file's file data store (for example, an image or an attachment).
+pub struct StoredFile {
+    /// The file's ID, without braces.
+    pub id: String,
+    /// The size of the file, in bytes.
+    pub size: usize,
+    blob: FileBlob,
+}
+
+impl StoredFile {
+    /// Opens the file's content for reading.
+    pub fn read(&self) -> Result<Box<dyn Read>> {
+        self.blob.read()
+    }
+}
+
+impl Inspector {
+    /// Parses the file at `path`.
+    pub fn open(path: &str) -> Result<Inspector> {
+        let file = fs_driver().open_file(path)?;
+        Self::parse(&mut Reader::try_from(file)?)
+    }
+
+    /// Parses a file from a byte array.
+    pub fn from_data(data: &[u8]) -> Result<Inspector> {
+        Self::parse(&mut Reader::new(data))
+    }
+
+    fn parse(reader: &mut Reader) -> Result<Inspector> {
+        let file = OneStoreFile::parse(reader).map_err(|error| match error.kind {
+            ErrorKind::NotLocalOneStore(_) => ErrorKind::NotLocalOneStore(
+                "Only OneNote 2016 files can be inspected. Files downloaded from OneDrive or SharePoint are not supported".into(),
+            )
+            .into(),
+            _ => error,
+        })?;
+        Ok(Inspector { file })
+    }
+
+    /// Lists the file's object spaces, along with their revisions. If `include_objects` is
+    /// true, also lists the ID and type of each object in each space.
+    pub fn object_spaces(&self, include_objects: bool) -> Value {
+        let root = &self.file.root_file_node_list;
+        let spaces = root
+            .object_spaces
+            .iter()
+            .map(|space| {
+                let revision_list = &space.revision_list;
+                let revisions = revision_list
+                    .revisions
+                    .iter()
+                    .map(|revision| {
+                        let object_count: usize = revision
+                            .object_groups
+                            .iter()
+                            .map(|group| group.objects.len())
+                            .sum();
+                        Value::record([
+                            ("id", format_exguid(revision.id).into()),
+                            (
+                                "dependency",
+                                revision.dependency_id.map(format_exguid).into(),
+                            ),
+                            ("role", revision.role_and_context.role.into()),
+                            (
+                                "context",
+                                format_exguid(revision.role_and_context.context).into(),
+                            ),
+                            (
+                                "content_root",
+                                revision.content_root().map(format_exguid).into(),
+                            ),
+                            (
+                                "metadata_root",
+                                revision.metadata_root().map(format_exguid).into(),
+                            ),
+                            ("object_groups", revision.object_groups.len().into()),
+                            ("objects", object_count.into()),
+                        ])
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut assignments = revision_list
+                    .role_assignments
+                    .iter()
+                    .map(|(role, revision)| {
+                        (
+                            role.role,
+                            format_exguid(role.context),
+                            format_exguid(*revision),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                assignments.sort();
+                let assignments = assignments
+                    .into_iter()
+                    .map(|(role, context, revision)| {
+                        Value::record([
+                            ("role", role.into()),
+                            ("context", context.into()),
+                            ("revision", revision.into()),
+                        ])
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut result = Value::record([
+                    ("id", format_exguid(space.id).into()),
+                    ("is_root", (space.id == root.root_object_space.id).into()),
+                    (
+                        "current_revision",
+                        revision_list
+                            .default_content_revision()
+                            .map(|revision| format_exguid(revision.id))
+                            .into(),
+                    ),
+                    ("role_assignments", assignments.into()),
+                    ("revisions", revisions.into()),
+                ]);
+
+                if include_objects {
+                    let mut objects = space
+                        .id_to_object
+                        .iter()
+                        .map(|(id, object)| (format_exguid(*id), jcid_name(&object.data)))
+                        .collect::<Vec<_>>();
+                    objects.sort();
+                    let objects = objects
+                        .into_iter()
+                        .map(|(id, object_type)| {
+                            Value::record([("id", id.into()), ("type", object_type.into())])
+                        })
+                        .collect::<Vec<_>>();
+                    result.push_field("objects", objects.into());
+                }
+
+                result
+            })
+            .collect::<Vec<_>>();
+
+        Value::record([
+            ("object_spaces", spaces.into()),
+            ("stored_files", self.stored_files().len().into()),
+        ])
+    }
+
+    /// Describes the object with the given ID. `id` has the form `{GUID},value`.
+    pub fn object(&self, id: &str) -> Result<Value> {
+        let id = ExGuid::from_str(id)?;
+        let (space, object) = self
+            .file
+            .root_file_node_list
+            .object_spaces
+            .iter()
+            .find_map(|space| space.id_to_object.get(&id).map(|object| (space, object)))
+            .ok_or_else(|| {
+                ErrorKind::ResolutionFailed(
+                    format!("No object with ID {}", format_exguid(id)).into(),
+                )
+            })?;
+        let object = &object.data;
+
+        let file_data_size = match &object.file_data {
+            Some(file_data) => Some(file_data.load()?.len()),
+            None => None,
+        };
+
+        let mut references = ReferenceCursor::new(object);
+        let properties = describe_properties(object.props.properties(), &mut references);
+
+        Ok(Value::record([
+            ("id", format_exguid(id).into()),
+            ("object_space", format_exguid(space.id).into()),
+            ("jcid", format!("0x{:08X}", object.jc_id.0).into()),
+            ("type", jcid_name(object).into()),
+            ("file_data_size", file_data_size.into()),
+            ("properties", properties),
+        ]))
+    }
+
+    /// Describes the tree of file nodes, starting at the root file node list. If `include_data`
+    /// is true, the parsed content of each node is included.
+    pub fn file_node_tree(&self, include_data: bool) -> Value {
+        Value::record([
+            (
+                "root_file_node_list",
+                describe_file_node_list(&self.file.raw_file_node_list, include_data),
+            ),
+            (
+                "hashed_chunk_list",
+                self.file
+                    .hashed_chunk_list
+                    .iter()
+                    .flat_map(|fragment| fragment.file_nodes.iter())
+                    .map(|node| describe_file_node(node, include_data))
+                    .collect::<Vec<_>>()
+                    .into(),
+            ),
+        ])
+    }
+
+    /// Lists the files stored in the file's file data store.
+    pub fn stored_files(&self) -> Vec<StoredFile> {
+        let Some(store) = &self.file.root_file_node_list.file_data_store else {
+            return Vec::new();
+        };
+
+        store
+            .files
+            .iter()
+            .map(|file| StoredFile {
+                id: file.id.0.to_string().to_uppercase(),
+                size: file.file_data.0.len(),
+                blob: file.file_data.0.clone(),
+            })
+            .collect()
+    }
+}
+
+fn format_exguid(id: ExGuid) -> String {
+    format!("{},{}", id.guid, id.value)
+}
+
+fn jcid_name(object: &Object) -> String {
+    match PropertySetId::from_jcid(object.jc_id) {
+        Some(id) => format!("{:?}", id),
+        None => format!("Unknown (0x{:08X})", object.jc_id.0),
+    }
+}
+
+fn describe_file_node_list(list: &FileNodeList, include_data: bool) -> Value {
+    list.file_node_sequence
+        .iter()
+        .map(|node| describe_file_node(node, include_data))
+        .collect::<Vec<_>>()
+        .into()
+}
+
+fn describe_file_node(node: &FileNode, include_data: bool) -> Value {
+    let mut result = Value::record([
+        ("offset", format!("0x{:08X}", node.offset).into()),
+        ("type", node.fnd.type_name().into()),
+        ("node_id", format!("0x{:03X}", node.node_type_id).into()),
+        ("size", node.size.into()),
+    ]);
+
+    if let Some(children) = node.fnd.child_list() {
+        result.push_field("children", describe_file_node_list(children, include_data));
+    } else if include_data {
+        result.push_field("data", format!("{:?}", node.fnd).into());
+    }
+
+    result
+}
+
+/// Assigns the object, object space and context IDs referenced by an object to its properties.
+/// IDs are stored in a separate list, in the same order as the properties that use them.
+struct ReferenceCursor<'a> {
+    object: &'a Object,
+    object_index: usize,
+    object_space_index: usize,
+    context_index: usize,
+}
+
+impl<'a> ReferenceCursor<'a> {
+    fn new(object: &'a Object) -> Self {
+        Self {
+            object,
+            object_index: 0,
+            object_space_index: 0,
+            context_index: 0,
+        }
+    }
+
+    fn next_object(&mut self) -> Value {
+        let ids = self.object.props.object_ids();
+        let result = self.resolve(ids.get(self.object_index), self.object_index);
+        self.object_index += 1;
+        result
+    }
+
+    fn next_context(&mut self) -> Value {
+        let ids = self.object.props.context_ids();
+        let result = self.resolve(ids.get(self.context_index), self.context_index);
+        self.context_index += 1;
+        result
+    }
+
+    fn next_object_space(&mut self) -> Value {
+        let ids = self.object.props.object_space_ids();
+        let index = self.object_space_index;
+        self.object_space_index += 1;
+
+        match ids.get(index) {
+            Some(id) => self
+                .object
+                .mapping
+                .get_object_space(index, id)
+                .map(|cell_id| format_exguid(cell_id.0))
+                .unwrap_or_else(|| format!("Unresolved ({:?})", id))
+                .into(),
+            None => "Missing".into(),
+        }
+    }
+
+    fn resolve(&self, id: Option<&CompactId>, index: usize) -> Value {
+        match id {
+            Some(id) => self
+                .object
+                .mapping
+                .resolve_id(index, id)
+                .map(format_exguid)
+                .unwrap_or_else(|| format!("Unresolved ({:?})", id))
+                .into(),
+            None => "Missing".into(),
+        }
+    }
+}
+
+fn describe_properties(properties: &PropertySet, references: &mut ReferenceCursor) -> Value {
+    properties
+        .entries()
+        .map(|(id, value)| {
+            let name = PropertyType::from_property_id(id).map(|name| format!("{:?}", name));
+            Value::record([
+                ("id", format!("0x{:08X}", id.value()).into()),
+                ("name", name.into()),
+                ("type", property_type_name(id).into()),
+                ("value", describe_value(value, references)),
+            ])
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+fn describe_value(value: &PropertyValue, references: &mut ReferenceCursor) -> Value {
+    match value {
+        PropertyValue::Empty => Value::Null,
+        PropertyValue::Bool(value) => (*value).into(),
+        PropertyValue::U8(value) => u64::from(*value).into(),
+        PropertyValue::U16(value) => u64::from(*value).into(),
+        PropertyValue::U32(value) => (*value).into(),
+        PropertyValue::U64(value) => (*value).into(),
+        PropertyValue::Vec(data) => match probable_string(data) {
+            Some(text) => text.into(),
+            None => format_bytes(data).into(),
+        },
+        PropertyValue::ObjectId => references.next_object(),
+        PropertyValue::ObjectIds(count) => (0..*count)
+            .map(|_| references.next_object())
+            .collect::<Vec<_>>()
+            .into(),
+        PropertyValue::ObjectSpaceId => references.next_object_space(),
+        PropertyValue::ObjectSpaceIds(count) => (0..*count)
+            .map(|_| references.next_object_space())
+            .collect::<Vec<_>>()
+            .into(),
+        PropertyValue::ContextId => references.next_context(),
+        PropertyValue::ContextIds(count) => (0..*count)
+            .map(|_| references.next_context())
+            .collect::<Vec<_>>()
+            .into(),
+        PropertyValue::PropertyValues(_, sets) => sets
+            .iter()
+            .map(|set| describe_properties(set, references))
+            .collect::<Vec<_>>()
+            .into(),
+        PropertyValue::PropertySet(set) => describe_properties(set, references),
+    }
+}
+
+fn format_bytes(data: &[u8]) -> String {
+    let shown = data
+        .iter()
+        .take(MAX_BYTES_SHOWN)
+        .map(|byte| format!("{:02X}", byte))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if data.len() > MAX_BYTES_SHOWN {
+        format!("{} ... ({} bytes)", shown, data.len())
+    } else {
+        shown
+    }
+}
+
+/// See [\[MS-ONESTORE\] 2.6.6].
+///
+/// [\[MS-ONESTORE\] 2.6.6]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-onestore/17d8c39e-6cc2-4fcd-8d10-aee950fd0ab2
+fn property_type_name(id: PropertyId) -> &'static str {
+    match id.prop_type() {
+        0x1 => "NoData",
+        0x2 => "Bool",
+        0x3 => "OneByteOfData",
+        0x4 => "TwoBytesOfData",
+        0x5 => "FourBytesOfData",
+        0x6 => "EightBytesOfData",
+        0x7 => "FourBytesOfLengthFollowedByData",
+        0x8 => "ObjectID",
+        0x9 => "ArrayOfObjectIDs",
+        0xA => "ObjectSpaceID",
+        0xB => "ArrayOfObjectSpaceIDs",
+        0xC => "ContextID",
+        0xD => "ArrayOfContextIDs",
+        0x10 => "ArrayOfPropertyValues",
+        0x11 => "PropertySet",
+        _ => "Unknown",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Inspector, Value};
+    use parser_utils::fs_driver;
+
+    fn field<'a>(value: &'a Value, name: &str) -> &'a Value {
+        match value {
+            Value::Record(fields) => {
+                &fields
+                    .iter()
+                    .find(|(field_name, _)| field_name == name)
+                    .unwrap()
+                    .1
+            }
+            _ => panic!("Not a record: {:?}", value),
+        }
+    }
+
+    #[test]
+    fn should_describe_objects_in_object_spaces() {
+        let data = fs_driver()
+            .read_file("../test-data/onenote-2016/OneWithFileData.one")
+            .unwrap();
+        let inspector = Inspector::from_data(&data).unwrap();
+
+        let spaces = inspector.object_spaces(true);
+        let Value::List(spaces) = field(&spaces, "object_spaces") else {
+            panic!("object_spaces should be a list");
+        };
+        assert!(!spaces.is_empty());
+
+        // Every listed object should be describable
+        let Value::List(objects) = field(&spaces[0], "objects") else {
+            panic!("objects should be a list");
+        };
+        let Value::Text(id) = field(&objects[0], "id") else {
+            panic!("IDs should be strings");
+        };
+        let object = inspector.object(id).unwrap();
+        assert_eq!(field(&object, "id"), &Value::Text(id.clone()));
+        assert!(matches!(field(&object, "properties"), Value::List(_)));
+    }
+
+    #[test]
+    fn should_list_stored_files() {
+        let data = fs_driver()
+            .read_file("../test-data/onenote-2016/OneWithFileData.one")
+            .unwrap();
+        let inspector = Inspector::from_data(&data).unwrap();
+
+        let files = inspector.stored_files();
+        assert!(!files.is_empty());
+        let mut content = Vec::new();
+        files[0].read().unwrap().read_to_end(&mut content).unwrap();
+        assert_eq!(content.len(), files[0].size);
+    }
+}
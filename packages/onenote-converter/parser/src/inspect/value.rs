@@ -0,0 +1,248 @@
+use std::fmt::Write;
+
+/// A generic description of parsed data that can be printed as JSON or as an indented tree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// A missing value.
+    Null,
+    /// A boolean.
+    Bool(bool),
+    /// An unsigned integer.
+    Number(u64),
+    /// A string.
+    Text(String),
+    /// An ordered list of values.
+    List(Vec<Value>),
+    /// An ordered list of named values.
+    Record(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Creates a record from `(name, value)` pairs.
+    pub fn record<const N: usize>(fields: [(&str, Value); N]) -> Value {
+        Value::Record(
+            fields
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        )
+    }
+
+    /// Adds a field to a record. Does nothing for other types of value.
+    pub fn push_field(&mut self, name: &str, value: Value) {
+        if let Value::Record(fields) = self {
+            fields.push((name.to_string(), value));
+        }
+    }
+
+    /// Formats the value as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        let mut output = String::new();
+        self.write_json(&mut output, 0);
+        output
+    }
+
+    /// Formats the value as an indented, YAML-like tree.
+    pub fn to_tree(&self) -> String {
+        let mut output = String::new();
+        match self {
+            Value::List(_) | Value::Record(_) => self.write_tree(&mut output, 0),
+            scalar => {
+                output.push_str(&scalar.scalar_to_string());
+                output.push('\n');
+            }
+        }
+        output
+    }
+
+    fn is_compound(&self) -> bool {
+        match self {
+            Value::List(items) => !items.is_empty(),
+            Value::Record(fields) => !fields.is_empty(),
+            _ => false,
+        }
+    }
+
+    fn scalar_to_string(&self) -> String {
+        match self {
+            Value::Null => "null".into(),
+            Value::Bool(value) => value.to_string(),
+            Value::Number(value) => value.to_string(),
+            Value::Text(text) if text.is_empty() => "\"\"".into(),
+            Value::Text(text) => text.clone(),
+            Value::List(_) => "[]".into(),
+            Value::Record(_) => "{}".into(),
+        }
+    }
+
+    fn write_tree(&self, output: &mut String, indent: usize) {
+        let padding = "  ".repeat(indent);
+        match self {
+            Value::Record(fields) => {
+                for (name, value) in fields {
+                    if value.is_compound() {
+                        let _ = writeln!(output, "{padding}{name}:");
+                        value.write_tree(output, indent + 1);
+                    } else {
+                        let _ = writeln!(output, "{padding}{name}: {}", value.scalar_to_string());
+                    }
+                }
+            }
+            Value::List(items) => {
+                for item in items {
+                    if item.is_compound() {
+                        // Start the item's first line with a "- " marker, then continue
+                        // at the same indentation.
+                        let mut item_output = String::new();
+                        item.write_tree(&mut item_output, indent + 1);
+                        output.push_str(&padding);
+                        output.push_str("- ");
+                        output.push_str(&item_output[padding.len() + 2..]);
+                    } else {
+                        let _ = writeln!(output, "{padding}- {}", item.scalar_to_string());
+                    }
+                }
+            }
+            scalar => {
+                let _ = writeln!(output, "{padding}{}", scalar.scalar_to_string());
+            }
+        }
+    }
+
+    fn write_json(&self, output: &mut String, indent: usize) {
+        let padding = "  ".repeat(indent + 1);
+        match self {
+            Value::Null => output.push_str("null"),
+            Value::Bool(value) => output.push_str(&value.to_string()),
+            Value::Number(value) => output.push_str(&value.to_string()),
+            Value::Text(text) => write_json_string(output, text),
+            Value::List(items) if items.is_empty() => output.push_str("[]"),
+            Value::List(items) => {
+                output.push_str("[\n");
+                for (index, item) in items.iter().enumerate() {
+                    output.push_str(&padding);
+                    item.write_json(output, indent + 1);
+                    output.push_str(if index + 1 < items.len() { ",\n" } else { "\n" });
+                }
+                output.push_str(&"  ".repeat(indent));
+                output.push(']');
+            }
+            Value::Record(fields) if fields.is_empty() => output.push_str("{}"),
+            Value::Record(fields) => {
+                output.push_str("{\n");
+                for (index, (name, value)) in fields.iter().enumerate() {
+                    output.push_str(&padding);
+                    write_json_string(output, name);
+                    output.push_str(": ");
+                    value.write_json(output, indent + 1);
+                    output.push_str(if index + 1 < fields.len() {
+                        ",\n"
+                    } else {
+                        "\n"
+                    });
+                }
+                output.push_str(&"  ".repeat(indent));
+                output.push('}');
+            }
+        }
+    }
+}
+
+fn write_json_string(output: &mut String, text: &str) {
+    output.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(output, "\\u{:04x}", c as u32);
+            }
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<u64> for Value {
+    fn from(value: u64) -> Self {
+        Value::Number(value)
+    }
+}
+
+impl From<u32> for Value {
+    fn from(value: u32) -> Self {
+        Value::Number(value.into())
+    }
+}
+
+impl From<usize> for Value {
+    fn from(value: usize) -> Self {
+        Value::Number(value as u64)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Text(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Text(value)
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(Value::Null)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(value: Vec<T>) -> Self {
+        Value::List(value.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Value;
+
+    fn example() -> Value {
+        Value::record([
+            ("name", "Test \"file\"".into()),
+            ("size", 12u32.into()),
+            ("parent", Value::Null),
+            (
+                "children",
+                vec![Value::record([("id", 1u32.into()), ("leaf", true.into())])].into(),
+            ),
+        ])
+    }
+
+    #[test]
+    fn should_format_json() {
+        assert_eq!(
+            example().to_json(),
+            "{\n  \"name\": \"Test \\\"file\\\"\",\n  \"size\": 12,\n  \"parent\": null,\n  \"children\": [\n    {\n      \"id\": 1,\n      \"leaf\": true\n    }\n  ]\n}"
+        );
+    }
+
+    #[test]
+    fn should_format_tree() {
+        assert_eq!(
+            example().to_tree(),
+            "name: Test \"file\"\nsize: 12\nparent: null\nchildren:\n  - id: 1\n    leaf: true\n"
+        );
+    }
+}
@@ -6,6 +6,7 @@ mod fsshttpb;
 #[macro_use]
 mod macros;
 mod fsshttpb_onestore;
+pub mod inspect;
 mod local_onestore;
 mod one;
 mod onenote;
@@ -21,13 +22,21 @@ pub mod notebook {
 
 /// The data that represents a OneNote section.
 pub mod section {
-    pub use crate::onenote::section::{Section, SectionEntry};
+    pub use crate::onenote::page_series::PageSeries;
+    pub use crate::onenote::section::{Section, SectionEntry, SectionFile};
 }
 
 /// The data that represents a OneNote page.
 pub mod page {
-    pub use crate::onenote::page::Page;
+    pub use crate::one::property::rule_lines::RuleLines;
+    pub use crate::onenote::diagnostics::{PageDiagnostics, SkippedObject};
+    pub use crate::onenote::page::{Page, PageBackgroundStyle, Title, TitleField};
     pub use crate::onenote::page_content::PageContent;
+    pub use crate::onenote::page_text::{
+        TextChunk, TextContent, TextSource, format_ocr_text, visit_text_content,
+    };
+    pub use crate::onenote::page_tree::PageNode;
+    pub use crate::onenote::printout::Printout;
 }
 
 /// The data that represents the contents of a OneNote section.
@@ -36,11 +45,13 @@ pub mod contents {
     pub use crate::onenote::embedded_file::EmbeddedFile;
     pub use crate::onenote::image::Image;
     pub use crate::onenote::ink::{Ink, InkBoundingBox, InkPoint, InkStroke};
-    pub use crate::onenote::list::List;
+    pub use crate::onenote::list::{List, ListFormat, ListFormatPart, NumberStyle};
     pub use crate::onenote::note_tag::NoteTag;
+    pub use crate::onenote::ocr_text::OcrText;
     pub use crate::onenote::outline::{Outline, OutlineElement, OutlineItem};
     pub use crate::onenote::rich_text::{EmbeddedObject, RichText};
     pub use crate::onenote::table::{Table, TableCell};
+    pub use crate::onenote::unknown_content::UnknownContent;
 }
 
 /// Collection of properties used by the OneNote file format.
@@ -49,6 +60,7 @@ pub mod property {
     pub mod common {
         pub use crate::one::property::color::Color;
         pub use crate::one::property::color_ref::ColorRef;
+        pub use crate::one::property::language_id::language_tag;
     }
 
     /// Properties related to embedded files.
@@ -58,7 +70,7 @@ pub mod property {
 
     /// Properties related to note tags.
     pub mod note_tag {
-        pub use crate::one::property::note_tag::ActionItemStatus;
+        pub use crate::one::property::note_tag::{ActionItemStatus, ActionItemType};
         pub use crate::one::property::note_tag_shape::NoteTagShape;
     }
 
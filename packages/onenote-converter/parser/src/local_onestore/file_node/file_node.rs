@@ -21,7 +21,9 @@ use crate::shared::guid::Guid;
 #[allow(dead_code)]
 pub struct FileNode {
     /// Specifies the type of the structure
-    node_type_id: u32,
+    pub node_type_id: u32,
+    /// The position of the node within the file
+    pub offset: u64,
 
     stp_format: u32,
     cb_format: u32,
@@ -39,7 +41,23 @@ enum FileNodeDataRef {
 }
 
 impl FileNode {
+    /// Wraps `fnd` in a node with otherwise empty header fields. Useful for building
+    /// node lists in tests.
+    #[cfg(test)]
+    pub fn from_data(fnd: FileNodeData) -> Self {
+        Self {
+            node_type_id: 0,
+            offset: 0,
+            stp_format: 0,
+            cb_format: 0,
+            base_type: 0,
+            size: 0,
+            fnd,
+        }
+    }
+
     pub fn parse(reader: parser_utils::Reader, context: &mut ParseContext) -> Result<Self> {
+        let offset = reader.offset();
         let remaining_0 = reader.remaining();
         let first_line = reader.get_u32()?;
         let node_id = first_line & 0x3FF; // First 10 bits
@@ -181,6 +199,7 @@ impl FileNode {
 
         let node = Self {
             node_type_id: node_id,
+            offset,
             stp_format,
             cb_format,
             base_type,
@@ -255,6 +274,86 @@ pub enum FileNodeData {
     Null,
 }
 
+impl FileNodeData {
+    /// The name of the node's structure, as used in MS-ONESTORE.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            FileNodeData::ObjectSpaceManifestRootFND(_) => "ObjectSpaceManifestRootFND",
+            FileNodeData::ObjectSpaceManifestListReferenceFND(_) => {
+                "ObjectSpaceManifestListReferenceFND"
+            }
+            FileNodeData::ObjectSpaceManifestListStartFND(_) => "ObjectSpaceManifestListStartFND",
+            FileNodeData::RevisionManifestListReferenceFND(_) => "RevisionManifestListReferenceFND",
+            FileNodeData::RevisionManifestListStartFND(_) => "RevisionManifestListStartFND",
+            FileNodeData::RevisionManifestStart4FND(_) => "RevisionManifestStart4FND",
+            FileNodeData::RevisionManifestEndFND => "RevisionManifestEndFND",
+            FileNodeData::RevisionManifestStart6FND(_) => "RevisionManifestStart6FND",
+            FileNodeData::RevisionManifestStart7FND(_) => "RevisionManifestStart7FND",
+            FileNodeData::GlobalIdTableStartFNDX(_) => "GlobalIdTableStartFNDX",
+            FileNodeData::GlobalIdTableStart2FND => "GlobalIdTableStart2FND",
+            FileNodeData::GlobalIdTableEntryFNDX(_) => "GlobalIdTableEntryFNDX",
+            FileNodeData::GlobalIdTableEntry2FNDX(_) => "GlobalIdTableEntry2FNDX",
+            FileNodeData::GlobalIdTableEntry3FNDX(_) => "GlobalIdTableEntry3FNDX",
+            FileNodeData::GlobalIdTableEndFNDX => "GlobalIdTableEndFNDX",
+            FileNodeData::ObjectDeclarationWithRefCountFNDX(_) => {
+                "ObjectDeclarationWithRefCountFNDX"
+            }
+            FileNodeData::ObjectDeclarationWithRefCount2FNDX(_) => {
+                "ObjectDeclarationWithRefCount2FNDX"
+            }
+            FileNodeData::ObjectRevisionWithRefCountFNDX(_) => "ObjectRevisionWithRefCountFNDX",
+            FileNodeData::ObjectRevisionWithRefCount2FNDX(_) => "ObjectRevisionWithRefCount2FNDX",
+            FileNodeData::RootObjectReference2FNDX(_) => "RootObjectReference2FNDX",
+            FileNodeData::RootObjectReference3FND(_) => "RootObjectReference3FND",
+            FileNodeData::RevisionRoleDeclarationFND(_) => "RevisionRoleDeclarationFND",
+            FileNodeData::RevisionRoleAndContextDeclarationFND(_) => {
+                "RevisionRoleAndContextDeclarationFND"
+            }
+            FileNodeData::ObjectDeclarationFileData3RefCountFND(_) => {
+                "ObjectDeclarationFileData3RefCountFND"
+            }
+            FileNodeData::ObjectDeclarationFileData3LargeRefCountFND(_) => {
+                "ObjectDeclarationFileData3LargeRefCountFND"
+            }
+            FileNodeData::ObjectDataEncryptionKeyV2FNDX(_) => "ObjectDataEncryptionKeyV2FNDX",
+            FileNodeData::ObjectInfoDependencyOverridesFND(_) => "ObjectInfoDependencyOverridesFND",
+            FileNodeData::DataSignatureGroupDefinitionFND(_) => "DataSignatureGroupDefinitionFND",
+            FileNodeData::FileDataStoreListReferenceFND(_) => "FileDataStoreListReferenceFND",
+            FileNodeData::FileDataStoreObjectReferenceFND(_) => "FileDataStoreObjectReferenceFND",
+            FileNodeData::ObjectDeclaration2RefCountFND(_) => "ObjectDeclaration2RefCountFND",
+            FileNodeData::ObjectDeclaration2LargeRefCountFND(_) => {
+                "ObjectDeclaration2LargeRefCountFND"
+            }
+            FileNodeData::ObjectGroupListReferenceFND(_) => "ObjectGroupListReferenceFND",
+            FileNodeData::ObjectGroupStartFND(_) => "ObjectGroupStartFND",
+            FileNodeData::ObjectGroupEndFND => "ObjectGroupEndFND",
+            FileNodeData::HashedChunkDescriptor2FND(_) => "HashedChunkDescriptor2FND",
+            FileNodeData::ReadOnlyObjectDeclaration2RefCountFND(_) => {
+                "ReadOnlyObjectDeclaration2RefCountFND"
+            }
+            FileNodeData::ReadOnlyObjectDeclaration2LargeRefCountFND(_) => {
+                "ReadOnlyObjectDeclaration2LargeRefCountFND"
+            }
+            FileNodeData::ChunkTerminatorFND => "ChunkTerminatorFND",
+            FileNodeData::UnknownNode(_) => "UnknownNode",
+            FileNodeData::Null => "Null",
+        }
+    }
+
+    /// The file node list this node points to, if any.
+    pub fn child_list(&self) -> Option<&FileNodeList> {
+        match self {
+            FileNodeData::ObjectSpaceManifestListReferenceFND(data) => {
+                Some(&data.last_revision.list)
+            }
+            FileNodeData::RevisionManifestListReferenceFND(data) => Some(&data.list),
+            FileNodeData::FileDataStoreListReferenceFND(data) => Some(&data.list),
+            FileNodeData::ObjectGroupListReferenceFND(data) => Some(&data.list),
+            _ => None,
+        }
+    }
+}
+
 trait ParseWithRef
 where
     Self: Sized,
@@ -374,7 +473,7 @@ pub struct RevisionManifestStart4FND {
     pub rid: ExGuid,
     pub rid_dependent: ExGuid,
     reserved_time_creation: u64,
-    revision_role: u32,
+    pub revision_role: u32,
     odcs_default: u16,
 }
 
@@ -384,7 +483,7 @@ pub struct RevisionManifestStart6FND {
     pub rid: ExGuid,
     /// ID of a dependency revision
     pub rid_dependent: ExGuid,
-    revision_role: u32,
+    pub revision_role: u32,
     odcs_default: u16,
 }
 
@@ -392,7 +491,8 @@ pub struct RevisionManifestStart6FND {
 #[allow(dead_code)]
 pub struct RevisionManifestStart7FND {
     pub base: RevisionManifestStart6FND,
-    gctxid: ExGuid,
+    /// The revision's context
+    pub gctxid: ExGuid,
 }
 
 #[derive(Debug, Clone, Parse)]
@@ -418,9 +518,9 @@ pub struct GlobalIdTableEntry2FNDX {
 #[derive(Debug, Clone, Parse)]
 #[allow(dead_code)]
 pub struct GlobalIdTableEntry3FNDX {
-    i_index_copy_from_start: u32,
-    c_entries_to_copy: u32,
-    i_index_copy_to_start: u32,
+    pub i_index_copy_from_start: u32,
+    pub c_entries_to_copy: u32,
+    pub i_index_copy_to_start: u32,
 }
 
 #[derive(Debug, Clone)]
@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 pub mod file_node;
 mod file_node_chunk_reference;
 
@@ -2,9 +2,9 @@
 //! Parses `.one` files and has partial support for `.onetoc2` files.
 
 mod common;
-mod file_node;
-mod file_structure;
-mod objects;
+pub(crate) mod file_node;
+pub(crate) mod file_structure;
+pub(crate) mod objects;
 mod one_store_file;
 
 pub use one_store_file::OneStoreFile;
@@ -18,7 +18,7 @@ pub struct FileDataStore {
 
 #[derive(Debug)]
 pub struct File {
-    id: Guid,
+    pub id: Guid,
     pub file_data: FileData,
 }
 
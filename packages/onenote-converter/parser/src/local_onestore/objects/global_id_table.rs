@@ -1,45 +1,51 @@
-use std::collections::HashMap;
-
 use crate::{
     local_onestore::{
         file_node::FileNodeData, file_structure::FileNodeDataIterator,
         objects::id_mapping::IdMapping,
     },
-    shared::{compact_id::CompactId, exguid::ExGuid},
+    shared::{compact_id::CompactId, exguid::ExGuid, guid::Guid},
 };
 use parser_utils::{errors::Result, log_warn};
 
 /// Lower-level structure for mapping local `CompactId`s to global `ExGuid`s. Applies to a
 /// particular region of a OneStore file.
 ///
-/// In `.onetoc2` files, `GlobalIdTable`s may depend on other `GlobalIdTable`s.
+/// In `.onetoc2` files, `GlobalIdTable`s may copy entries from the table of the dependency
+/// revision. These references are resolved while parsing, so the resulting `id_map` contains
+/// every entry that can be referenced from the table's region.
 ///
 /// See [\[MS-ONESTORE\] 2.1.3](https://learn.microsoft.com/en-us/openspecs/office_file_formats/ms-onestore/a243bd78-6cfd-4e18-96c7-e8c2095ce6b0)
 #[derive(Debug, Clone)]
 pub struct GlobalIdTable {
     pub id_map: IdMapping,
-    /// Only used in .onetoc2 files
-    _reference_map: IdReferenceMapping,
 }
 
 impl GlobalIdTable {
-    pub fn try_parse(iterator: &mut FileNodeDataIterator) -> Result<Option<Self>> {
+    /// Parses a global ID table, if the iterator points to one. `dependency` should be the
+    /// table in effect at the end of the dependency revision (if any). It is used to resolve
+    /// entries that reference another table.
+    pub fn try_parse(
+        iterator: &mut FileNodeDataIterator,
+        dependency: Option<&GlobalIdTable>,
+    ) -> Result<Option<Self>> {
         let next = iterator.peek();
 
         match next {
             Some(
                 FileNodeData::GlobalIdTableStart2FND | FileNodeData::GlobalIdTableStartFNDX(_),
-            ) => Ok(Some(GlobalIdTable::parse(iterator)?)),
+            ) => Ok(Some(GlobalIdTable::parse(iterator, dependency)?)),
             _ => Ok(None),
         }
     }
 
-    fn parse(iterator: &mut FileNodeDataIterator) -> Result<Self> {
+    fn parse(
+        iterator: &mut FileNodeDataIterator,
+        dependency: Option<&GlobalIdTable>,
+    ) -> Result<Self> {
         // Skip the start node
         iterator.next();
 
         let mut id_map = IdMapping::new();
-        let mut reference_map = IdReferenceMapping::new();
 
         for node in iterator {
             match node {
@@ -50,12 +56,19 @@ impl GlobalIdTable {
                     id_map.add_mapping(entry.index, entry.guid);
                 }
                 FileNodeData::GlobalIdTableEntry2FNDX(entry) => {
-                    reference_map
-                        .parent_references
-                        .insert(entry.i_index_map_from, entry.i_index_map_to);
+                    // Maps an entry from the dependency revision's table to a new index.
+                    let guid = Self::resolve_dependency_index(dependency, entry.i_index_map_from)?;
+                    id_map.add_mapping(entry.i_index_map_to, guid);
                 }
-                FileNodeData::GlobalIdTableEntry3FNDX(_entry) => {
-                    todo!("Not implemented");
+                FileNodeData::GlobalIdTableEntry3FNDX(entry) => {
+                    // Copies a range of entries from the dependency revision's table.
+                    for offset in 0..entry.c_entries_to_copy {
+                        let guid = Self::resolve_dependency_index(
+                            dependency,
+                            entry.i_index_copy_from_start + offset,
+                        )?;
+                        id_map.add_mapping(entry.i_index_copy_to_start + offset, guid);
+                    }
                 }
                 FileNodeData::UnknownNode(node) => {
                     log_warn!(
@@ -73,18 +86,29 @@ impl GlobalIdTable {
             }
         }
 
-        Ok(Self {
-            id_map,
-            _reference_map: reference_map,
+        Ok(Self { id_map })
+    }
+
+    fn resolve_dependency_index(dependency: Option<&GlobalIdTable>, index: u32) -> Result<Guid> {
+        let dependency = dependency.ok_or_else(|| {
+            onestore_parse_error!(
+                "Global ID table references index {} of a dependency table, but the revision has no dependency",
+                index
+            )
+        })?;
+
+        dependency.id_map.get(index).ok_or_else(|| {
+            onestore_parse_error!(
+                "Global ID table references missing index {} in the dependency table",
+                index
+            )
+            .into()
         })
     }
 
     pub fn fallback() -> Self {
-        let id_map = IdMapping::new();
-        let reference_map = IdReferenceMapping::new();
         Self {
-            id_map,
-            _reference_map: reference_map,
+            id_map: IdMapping::new(),
         }
     }
 
@@ -93,22 +117,101 @@ impl GlobalIdTable {
     }
 }
 
-#[derive(Clone)]
-struct IdReferenceMapping {
-    /// Maps from indexes in dependency revisions to indexes in the current revision.
-    parent_references: HashMap<u32, u32>,
-}
+#[cfg(test)]
+mod test {
+    use super::GlobalIdTable;
+    use crate::local_onestore::file_node::file_node::{
+        GlobalIdTableEntry2FNDX, GlobalIdTableEntry3FNDX, GlobalIdTableEntryFNDX,
+    };
+    use crate::local_onestore::file_node::{FileNode, FileNodeData};
+    use crate::local_onestore::file_structure::FileNodeList;
+    use crate::shared::compact_id::CompactId;
+    use crate::shared::exguid::ExGuid;
+
+    fn build_table(
+        entries: Vec<FileNodeData>,
+        dependency: Option<&GlobalIdTable>,
+    ) -> GlobalIdTable {
+        let mut nodes = vec![FileNode::from_data(FileNodeData::GlobalIdTableStart2FND)];
+        nodes.extend(entries.into_iter().map(FileNode::from_data));
+        nodes.push(FileNode::from_data(FileNodeData::GlobalIdTableEndFNDX));
 
-impl std::fmt::Debug for IdReferenceMapping {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "[IdReferenceMapping]")
+        let list = FileNodeList {
+            file_node_sequence: nodes,
+        };
+        GlobalIdTable::try_parse(&mut list.iter_data(), dependency)
+            .unwrap()
+            .unwrap()
     }
-}
 
-impl IdReferenceMapping {
-    fn new() -> Self {
-        Self {
-            parent_references: HashMap::new(),
-        }
+    #[test]
+    fn should_resolve_entries_from_the_dependency_table() {
+        let guid_a = guid!({ 1A5A319C - C26B - 41AA - B9C5 - 9BD8C44E07D4 });
+        let guid_b = guid!({ 4A3717F8 - 1C14 - 49E7 - 9526 - 81D942DE1741 });
+        let guid_c = guid!({ 7B5C52E4 - D88C - 4DA7 - AEB1 - 5378D02996D3 });
+
+        let dependency = build_table(
+            vec![
+                FileNodeData::GlobalIdTableEntryFNDX(GlobalIdTableEntryFNDX {
+                    index: 0,
+                    guid: guid_a,
+                }),
+                FileNodeData::GlobalIdTableEntryFNDX(GlobalIdTableEntryFNDX {
+                    index: 1,
+                    guid: guid_b,
+                }),
+                FileNodeData::GlobalIdTableEntryFNDX(GlobalIdTableEntryFNDX {
+                    index: 2,
+                    guid: guid_c,
+                }),
+            ],
+            None,
+        );
+
+        let table = build_table(
+            vec![
+                FileNodeData::GlobalIdTableEntry2FNDX(GlobalIdTableEntry2FNDX {
+                    i_index_map_from: 2,
+                    i_index_map_to: 0,
+                }),
+                FileNodeData::GlobalIdTableEntry3FNDX(GlobalIdTableEntry3FNDX {
+                    i_index_copy_from_start: 0,
+                    c_entries_to_copy: 2,
+                    i_index_copy_to_start: 5,
+                }),
+            ],
+            Some(&dependency),
+        );
+
+        let resolve = |guid_index| table.resolve_id(&CompactId { n: 3, guid_index }).unwrap();
+        assert_eq!(resolve(0), ExGuid::from_guid(guid_c, 3));
+        assert_eq!(resolve(5), ExGuid::from_guid(guid_a, 3));
+        assert_eq!(resolve(6), ExGuid::from_guid(guid_b, 3));
+        assert!(
+            table
+                .resolve_id(&CompactId {
+                    n: 3,
+                    guid_index: 1
+                })
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn should_fail_to_copy_entries_without_a_dependency() {
+        let list = FileNodeList {
+            file_node_sequence: vec![
+                FileNode::from_data(FileNodeData::GlobalIdTableStart2FND),
+                FileNode::from_data(FileNodeData::GlobalIdTableEntry2FNDX(
+                    GlobalIdTableEntry2FNDX {
+                        i_index_map_from: 0,
+                        i_index_map_to: 0,
+                    },
+                )),
+                FileNode::from_data(FileNodeData::GlobalIdTableEndFNDX),
+            ],
+        };
+
+        assert!(GlobalIdTable::try_parse(&mut list.iter_data(), None).is_err());
     }
 }
@@ -29,6 +29,10 @@ impl IdMapping {
         Ok(ExGuid::from_guid(*guid, id.n.into()))
     }
 
+    pub fn get(&self, guid_index: u32) -> Option<Guid> {
+        self.0.get(&guid_index).copied()
+    }
+
     pub fn add_mapping(&mut self, guid_index: u32, guid: Guid) {
         self.0.insert(guid_index, guid);
     }
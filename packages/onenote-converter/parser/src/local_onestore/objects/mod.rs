@@ -1,9 +1,9 @@
-mod file_data_store;
+pub(crate) mod file_data_store;
 mod global_id_table;
 mod id_mapping;
 pub mod object;
 mod object_group_list;
-mod object_space;
+pub(crate) mod object_space;
 pub mod parse_context;
 pub mod revision;
 pub mod revision_manifest_list;
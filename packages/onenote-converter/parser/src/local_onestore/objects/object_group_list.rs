@@ -62,7 +62,8 @@ impl ObjectGroupList {
             }
         };
         let id = start.oid;
-        let id_table = GlobalIdTable::try_parse(iterator)?
+        // Object groups always declare their own, self-contained, ID table.
+        let id_table = GlobalIdTable::try_parse(iterator, None)?
             .ok_or_else(|| onestore_parse_error!("Global ID table not found in ObjectGroupList"))?;
         let mut objects = Vec::new();
 
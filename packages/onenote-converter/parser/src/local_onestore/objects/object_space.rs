@@ -1,4 +1,7 @@
-use std::{collections::HashMap, rc::Rc};
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
 
 use crate::{
     local_onestore::{
@@ -67,11 +70,21 @@ impl ObjectSpace {
     }
 
     fn index_content(&mut self) -> Result<()> {
-        for revision in &self.revision_list.revisions {
-            // TODO: Use global_id_tables if present. This may be required for parsing
-            //      .onetoc2 files, which allow references from one ID table to another.
-            let _global_id_tables = &revision.global_id_tables;
+        // Index revisions outside the current content's dependency chain first, then the chain
+        // from oldest to newest. This way, objects from the current revision replace older
+        // copies with the same ID.
+        let mut chain = self.revision_list.default_content_chain();
+        chain.reverse();
+        let chain_ids: HashSet<ExGuid> = chain.iter().map(|revision| revision.id).collect();
+        let other_revisions = self
+            .revision_list
+            .revisions
+            .iter()
+            .filter(|revision| !chain_ids.contains(&revision.id));
 
+        for revision in other_revisions.chain(chain.iter()) {
+            // Each object group's ID table has already been resolved against the tables of
+            // the revision's dependencies (see `GlobalIdTable`).
             for object_group in &revision.object_groups {
                 let id_table = &object_group.id_table;
                 for object_ref in &object_group.objects {
@@ -92,22 +105,15 @@ impl crate::onestore::object_space::ObjectSpace for ObjectSpace {
 
     fn content_root(&self) -> Option<ExGuid> {
         self.revision_list
-            .revisions
+            .default_content_chain()
             .iter()
-            // TODO: It would make more sense to use the **last** revision, rather than
-            //       the first to get the content root. However, doing so seems to return
-            //       version history information, rather than the true content root.
-            //       In the future, if there are issues related to importing the wrong versions
-            //       of pages, look into this.
-            // .rev()
             .find_map(|revision| revision.content_root())
     }
 
     fn metadata_root(&self) -> Option<ExGuid> {
         self.revision_list
-            .revisions
+            .default_content_chain()
             .iter()
-            // .rev() // TODO: Why does calling .rev() result in the wrong metadata being returned?
             .find_map(|revision| revision.metadata_root())
     }
 }
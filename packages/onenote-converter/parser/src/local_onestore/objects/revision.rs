@@ -23,12 +23,44 @@ use parser_utils::{
 #[derive(Debug)]
 pub struct Revision {
     pub id: ExGuid,
-    _parent_id: ExGuid,
+    /// The revision this revision was built on, if any.
+    pub dependency_id: Option<ExGuid>,
+    /// The (role, context) pair this revision was declared with. Later revisions and
+    /// role declarations can reassign the pair (see [`RevisionManifestList`]).
+    ///
+    /// [`RevisionManifestList`]: super::revision_manifest_list::RevisionManifestList
+    pub role_and_context: RevisionRoleAndContext,
     pub object_groups: Vec<ObjectGroupList>,
-    pub global_id_tables: Vec<GlobalIdTable>,
+    /// The global ID table in effect at the end of this revision. This is either the
+    /// last table declared by the revision or, if there is none, the dependency
+    /// revision's table.
+    id_table: Option<GlobalIdTable>,
     root_objects: HashMap<RootRole, ExGuid>,
 }
 
+/// Identifies one of the revisions of an object space.
+///
+/// See MS-ONESTORE 2.1.12 ("Revision Role and Context").
+#[derive(Eq, PartialEq, Hash, Debug, Clone, Copy)]
+pub struct RevisionRoleAndContext {
+    pub role: u32,
+    pub context: ExGuid,
+}
+
+impl RevisionRoleAndContext {
+    /// The role of revisions that hold the current content of an object space.
+    pub const DEFAULT_CONTENT_ROLE: u32 = 0x1;
+
+    pub fn new(role: u32, context: ExGuid) -> Self {
+        Self { role, context }
+    }
+
+    /// The (role, context) pair of the current version of an object space's content.
+    pub fn default_content() -> Self {
+        Self::new(Self::DEFAULT_CONTENT_ROLE, ExGuid::fallback())
+    }
+}
+
 // See [MS-ONE 2.1.8](https://learn.microsoft.com/en-us/openspecs/office_file_formats/ms-one/037e31c0-4484-4a14-819a-0ddece2cacbc)
 #[derive(Eq, PartialEq, Hash, Debug)]
 pub enum RootRole {
@@ -50,9 +82,12 @@ impl TryFrom<u32> for RootRole {
 }
 
 impl Revision {
+    /// Parses a revision. `revisions` should contain the revisions that were parsed before this
+    /// one and is used to look up the dependency revision.
     pub fn try_parse(
         iterator: &mut FileNodeDataIterator,
         context: &ParseContext,
+        revisions: &HashMap<ExGuid, Rc<Revision>>,
     ) -> Result<Option<Self>> {
         let next = iterator.peek();
 
@@ -61,7 +96,7 @@ impl Revision {
                 FileNodeData::RevisionManifestStart4FND(_)
                 | FileNodeData::RevisionManifestStart6FND(_)
                 | FileNodeData::RevisionManifestStart7FND(_),
-            ) => Ok(Some(Self::parse(iterator, context)?)),
+            ) => Ok(Some(Self::parse(iterator, context, revisions)?)),
             _ => Ok(None),
         }
     }
@@ -74,14 +109,34 @@ impl Revision {
         self.root_objects.get(&RootRole::MetadataRoot).copied()
     }
 
-    fn parse(iterator: &mut FileNodeDataIterator, context: &ParseContext) -> Result<Self> {
+    /// The global ID table used to resolve `CompactId`s that refer to this revision.
+    pub fn global_id_table(&self) -> Option<&GlobalIdTable> {
+        self.id_table.as_ref()
+    }
+
+    fn parse(
+        iterator: &mut FileNodeDataIterator,
+        context: &ParseContext,
+        revisions: &HashMap<ExGuid, Rc<Revision>>,
+    ) -> Result<Self> {
         let start = iterator.next();
-        let (id, parent_id) = match start {
-            Some(FileNodeData::RevisionManifestStart4FND(data)) => (data.rid, data.rid_dependent),
-            Some(FileNodeData::RevisionManifestStart6FND(data)) => (data.rid, data.rid_dependent),
-            Some(FileNodeData::RevisionManifestStart7FND(data)) => {
-                (data.base.rid, data.base.rid_dependent)
-            }
+        // Revisions without an explicit context use the default context.
+        let (id, dependency_id, role_and_context) = match start {
+            Some(FileNodeData::RevisionManifestStart4FND(data)) => (
+                data.rid,
+                data.rid_dependent,
+                RevisionRoleAndContext::new(data.revision_role, ExGuid::fallback()),
+            ),
+            Some(FileNodeData::RevisionManifestStart6FND(data)) => (
+                data.rid,
+                data.rid_dependent,
+                RevisionRoleAndContext::new(data.revision_role, ExGuid::fallback()),
+            ),
+            Some(FileNodeData::RevisionManifestStart7FND(data)) => (
+                data.base.rid,
+                data.base.rid_dependent,
+                RevisionRoleAndContext::new(data.base.revision_role, data.gctxid),
+            ),
             _ => {
                 return Err(
                     onestore_parse_error!("Invalid start node for revision: {:?}", start).into(),
@@ -89,8 +144,18 @@ impl Revision {
             }
         };
 
+        let dependency_id = dependency_id.as_option();
+        let dependency = dependency_id.and_then(|dependency_id| {
+            let dependency = revisions.get(&dependency_id);
+            if dependency.is_none() {
+                log_warn!("Revision {:?} depends on a missing revision", id);
+            }
+            dependency
+        });
+        let dependency_id_table = dependency.and_then(|revision| revision.global_id_table());
+
         let mut object_groups = Vec::new();
-        let mut global_id_tables = Vec::new();
+        let mut id_table = dependency_id_table.cloned();
         let mut root_objects: HashMap<RootRole, ExGuid> = HashMap::new();
 
         let mut last_index = iterator.get_index();
@@ -106,14 +171,17 @@ impl Revision {
                 );
 
                 object_groups.push(object_group_list);
-            } else if let Some(global_id_table) = GlobalIdTable::try_parse(iterator)? {
+            } else if let Some(global_id_table) =
+                GlobalIdTable::try_parse(iterator, dependency_id_table)?
+            {
                 // In .onetoc2 files, objects can directly follow GlobalIdTables:
                 let mut objects = Vec::new();
+                let object_context = context.with_id_table(&global_id_table);
                 iterator_skip_if_matching!(
                     iterator,
                     Some(FileNodeData::DataSignatureGroupDefinitionFND(_))
                 );
-                while let Some(object) = Object::try_parse(iterator, context)? {
+                while let Some(object) = Object::try_parse(iterator, &object_context)? {
                     objects.push(Rc::new(object));
 
                     // Skip the reference counting object, if present
@@ -130,7 +198,7 @@ impl Revision {
                         global_id_table.clone(),
                     ));
                 }
-                global_id_tables.push(global_id_table);
+                id_table = Some(global_id_table);
             } else if let FileNodeData::RootObjectReference3FND(object_reference) = current {
                 iterator.next(); // Consume the reference
 
@@ -143,7 +211,7 @@ impl Revision {
             } else if let FileNodeData::RootObjectReference2FNDX(object_reference) = current {
                 // .onetoc2
                 iterator.next();
-                let oid_root = global_id_tables.last().ok_or_else(
+                let oid_root = id_table.as_ref().ok_or_else(
                     || onestore_parse_error!("Unable to resolve RootObjectReference2FNDX ID -- no global ID table found")
                 )?.resolve_id(&object_reference.oid_root)?;
                 root_objects.insert(object_reference.root_role.try_into()?, oid_root);
@@ -169,9 +237,10 @@ impl Revision {
 
         Ok(Revision {
             id,
-            _parent_id: parent_id,
+            dependency_id,
+            role_and_context,
             object_groups,
-            global_id_tables,
+            id_table,
             root_objects,
         })
     }
@@ -1,10 +1,16 @@
-use std::{collections::HashMap, rc::Rc};
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
 
 use crate::{
     local_onestore::{
         file_node::{FileNodeData, file_node::RevisionManifestListStartFND},
         file_structure::FileNodeDataIterator,
-        objects::{parse_context::ParseContext, revision::Revision},
+        objects::{
+            parse_context::ParseContext,
+            revision::{Revision, RevisionRoleAndContext},
+        },
     },
     shared::exguid::ExGuid,
 };
@@ -13,9 +19,17 @@ use parser_utils::{
     log_warn,
 };
 
+/// The revisions of an object space, in file order.
+///
+/// Each (revision role, context) pair is assigned to at most one revision. Newer revisions
+/// and role declarations take the pair over from older revisions.
+///
+/// See MS-ONESTORE 2.1.12 ("Revision Role and Context").
 #[derive(Debug)]
 pub struct RevisionManifestList {
     pub revisions: Vec<Rc<Revision>>,
+    /// Maps each (role, context) pair to the ID of the revision currently assigned to it.
+    pub role_assignments: HashMap<RevisionRoleAndContext, ExGuid>,
 }
 
 impl RevisionManifestList {
@@ -42,6 +56,7 @@ impl RevisionManifestList {
         let mut revisions = Vec::new();
         // Also create a temporary map to simplify revision lookup while building
         let mut revisions_map: HashMap<ExGuid, _> = HashMap::new();
+        let mut role_assignments = HashMap::new();
 
         let mut last_index = iterator.get_index();
         while let Some(current) = iterator.peek() {
@@ -49,40 +64,43 @@ impl RevisionManifestList {
                 FileNodeData::RevisionManifestEndFND => {
                     break;
                 }
-                FileNodeData::RevisionRoleDeclarationFND(_data) => {
-                    // Ignore. If present, should always have revision_role = 0x1?
+                FileNodeData::RevisionRoleDeclarationFND(data) => {
+                    // Assigns a role (in the default context) to some prior revision in the list.
+                    if !revisions_map.contains_key(&data.rid) {
+                        return Err(ErrorKind::MalformedOneStoreData(
+                            "RevisionRoleDeclarationFND points to a non-existent revision".into(),
+                        )
+                        .into());
+                    }
                     iterator.next();
+
+                    let role = RevisionRoleAndContext::new(data.revision_role, ExGuid::fallback());
+                    role_assignments.insert(role, data.rid);
                 }
                 FileNodeData::RevisionRoleAndContextDeclarationFND(data) => {
                     // Adds an additional (revision role, context) pair to some prior revision
                     // in the list.
                     // See [MS-ONESTORE 2.5.18](https://learn.microsoft.com/en-us/openspecs/office_file_formats/ms-onestore/4863b0e8-fe14-49bb-a634-558c747bf0b8).
-                    let revision = revisions_map.get(&data.base.rid);
-                    if let Some(_revision) = revision {
-                        iterator.next();
-
-                        // According to MS-ONESTORE 2.1.12, revision_role *should* always be 0x1
-                        if data.base.revision_role != 0x1 {
-                            // TODO: Find a test .one file that uses this and implement:
-                            log_warn!(
-                                "TO-DO: Apply the new role and context to the revision (role {:x})",
-                                data.base.revision_role
-                            );
-                        }
-                    } else {
+                    if !revisions_map.contains_key(&data.base.rid) {
                         return Err(
                             ErrorKind::MalformedOneStoreData("RevisionRoleAndContextDeclarationFND points to a non-existent revision".into()).into()
                         );
                     }
+                    iterator.next();
+
+                    let role = RevisionRoleAndContext::new(data.base.revision_role, data.gctxid);
+                    role_assignments.insert(role, data.base.rid);
                 }
                 node => {
-                    let revision = Revision::try_parse(iterator, context)?.ok_or_else(|| {
-                        onestore_parse_error!(
-                            "Unexpected node encountered in RevisionManifestList: {:?}",
-                            node
-                        )
-                    })?;
+                    let revision = Revision::try_parse(iterator, context, &revisions_map)?
+                        .ok_or_else(|| {
+                            onestore_parse_error!(
+                                "Unexpected node encountered in RevisionManifestList: {:?}",
+                                node
+                            )
+                        })?;
                     let revision_ref = Rc::new(revision);
+                    role_assignments.insert(revision_ref.role_and_context, revision_ref.id);
                     revisions.push(revision_ref.clone());
                     revisions_map.insert(revision_ref.id, revision_ref);
                 }
@@ -92,6 +110,55 @@ impl RevisionManifestList {
             assert_ne!(index, last_index);
             last_index = index;
         }
-        Ok(RevisionManifestList { revisions })
+        Ok(RevisionManifestList {
+            revisions,
+            role_assignments,
+        })
+    }
+
+    pub fn get_revision(&self, id: ExGuid) -> Option<&Rc<Revision>> {
+        self.revisions.iter().find(|revision| revision.id == id)
+    }
+
+    /// Returns the revision assigned to the given (role, context) pair, if any.
+    pub fn revision_for(&self, role_and_context: RevisionRoleAndContext) -> Option<&Rc<Revision>> {
+        let id = self.role_assignments.get(&role_and_context)?;
+        self.get_revision(*id)
+    }
+
+    /// Returns the revision that holds the current content of the object space.
+    pub fn default_content_revision(&self) -> Option<&Rc<Revision>> {
+        self.revision_for(RevisionRoleAndContext::default_content())
+    }
+
+    /// Returns `revision`, followed by the revision it depends on, the revision that one
+    /// depends on, and so on.
+    pub fn dependency_chain(&self, revision: &Rc<Revision>) -> Vec<Rc<Revision>> {
+        let mut chain = vec![revision.clone()];
+        let mut visited = HashSet::from([revision.id]);
+        let mut current = revision.clone();
+        while let Some(dependency_id) = current.dependency_id {
+            if !visited.insert(dependency_id) {
+                log_warn!("Revision dependency cycle detected at {:?}", dependency_id);
+                break;
+            }
+            let Some(dependency) = self.get_revision(dependency_id) else {
+                log_warn!("Missing dependency revision {:?}", dependency_id);
+                break;
+            };
+            chain.push(dependency.clone());
+            current = dependency.clone();
+        }
+        chain
+    }
+
+    /// Returns the dependency chain of the current default content revision, newest first.
+    /// Falls back to the list's revisions, in file order, when no revision has been assigned the
+    /// default content role.
+    pub fn default_content_chain(&self) -> Vec<Rc<Revision>> {
+        match self.default_content_revision() {
+            Some(revision) => self.dependency_chain(revision),
+            None => self.revisions.clone(),
+        }
     }
 }
@@ -27,6 +27,8 @@ pub struct OneStoreFile {
     pub free_chunk_list: Vec<FreeChunkListFragment>,
     pub transaction_log: Vec<TransactionLogFragment>,
     pub hashed_chunk_list: Vec<FileNodeListFragment>,
+    /// The unprocessed root file node list. Kept for debugging purposes.
+    pub raw_file_node_list: FileNodeList,
     pub root_file_node_list: RootFileNodeList,
 }
 
@@ -136,6 +138,7 @@ impl Parse for OneStoreFile {
             free_chunk_list,
             transaction_log,
             hashed_chunk_list,
+            raw_file_node_list,
             root_file_node_list,
         })
     }
@@ -148,6 +151,10 @@ mod test {
     use parser_utils::reader::Reader;
 
     use super::OneStoreFile;
+    use crate::Parser;
+    use crate::local_onestore::objects::revision::RevisionRoleAndContext;
+    use crate::shared::exguid::ExGuid;
+    use crate::shared::guid::Guid;
 
     #[test]
     fn should_parse_onenote_2016_file() {
@@ -157,6 +164,63 @@ mod test {
         let mut reader = Reader::new(&test_data);
         let packaging = OneStoreFile::parse(&mut reader).unwrap();
         println!("Packaging {:#?}", packaging);
-        assert!(packaging.root_file_node_list.object_spaces.len() > 0);
+        assert!(!packaging.root_file_node_list.object_spaces.is_empty());
+    }
+
+    #[test]
+    fn should_resolve_current_revision_of_page_with_history() {
+        // A page that was created with math content, which was then replaced with a single
+        // paragraph. OneNote keeps the first version in the page's history.
+        let test_data = fs_driver()
+            .read_file("../test-data/Page versions.one")
+            .unwrap();
+        let mut reader = Reader::new(&test_data);
+        let file = OneStoreFile::parse(&mut reader).unwrap();
+
+        let page_space = file
+            .root_file_node_list
+            .object_spaces
+            .iter()
+            .find(|space| space.id == exguid("{C712D4A3-A793-48FA-B3AB-A8B35FAE93FE}"))
+            .expect("should have the page's object space");
+        let revision_list = &page_space.revision_list;
+        let first_version = exguid("{C98F2575-29F2-4EB3-B846-DC8058F87F56}");
+        let newest_version = exguid("{4276BC27-C39C-4122-BCA2-AA57801235A5}");
+
+        // Both versions were declared with the version metadata role. The newer revision
+        // takes the role over, and the first version is moved to a history context by a
+        // RevisionRoleAndContextDeclarationFND.
+        let declared_role = |id| revision_list.get_revision(id).unwrap().role_and_context;
+        assert_eq!(declared_role(first_version).role, 4);
+        assert_eq!(declared_role(newest_version).role, 4);
+
+        let assigned_revision =
+            |role, context| revision_list.revision_for(RevisionRoleAndContext::new(role, context));
+        assert_eq!(
+            assigned_revision(4, ExGuid::fallback()).unwrap().id,
+            newest_version
+        );
+        assert_eq!(
+            assigned_revision(1, exguid("{046ED85C-8B0A-4EB8-BC35-C70B99253C53}"))
+                .unwrap()
+                .id,
+            first_version
+        );
+        assert_eq!(
+            revision_list.default_content_revision().unwrap().id,
+            newest_version
+        );
+
+        // The parsed page should have the content of the newest version
+        let section = Parser::new()
+            .parse_section_from_data(&test_data, "Page versions.one")
+            .unwrap();
+        let text = section.page_series()[0].pages()[0].plain_text();
+        assert!(text.contains("Version 2 of the page!"), "{}", text);
+        assert!(!text.contains("Pythagorean Theorem"), "{}", text);
+    }
+
+    fn exguid(guid: &str) -> ExGuid {
+        ExGuid::from_guid(Guid::from_str(guid).unwrap(), 1)
     }
 }
@@ -56,3 +56,17 @@ impl Color {
         }))
     }
 }
+
+impl Color {
+    /// Creates a color from a `0xAARRGGBB` value.
+    pub(crate) fn from_argb(value: u32) -> Color {
+        let bytes = value.to_le_bytes();
+
+        Color {
+            alpha: 255 - bytes[3],
+            r: bytes[2],
+            g: bytes[1],
+            b: bytes[0],
+        }
+    }
+}
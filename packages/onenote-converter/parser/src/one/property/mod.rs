@@ -10,6 +10,7 @@ pub(crate) mod color;
 pub(crate) mod color_ref;
 pub(crate) mod file_type;
 pub(crate) mod ink_dimensions;
+pub(crate) mod language_id;
 pub(crate) mod layout_alignment;
 pub(crate) mod note_tag;
 pub(crate) mod note_tag_property_status;
@@ -20,10 +21,15 @@ pub(crate) mod outline_indent_distance;
 pub(crate) mod page_size;
 pub(crate) mod paragraph_alignment;
 mod references;
+pub(crate) mod rule_lines;
 pub(crate) mod simple;
 pub(crate) mod time;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+use crate::shared::property::PropertyId;
+use enum_primitive_derive::Primitive;
+use num_traits::FromPrimitive;
+
+#[derive(Debug, Copy, Clone, PartialEq, Primitive)]
 #[allow(dead_code)]
 pub(crate) enum PropertyType {
     ActionItemSchemaVersion = 0x0C003473,
@@ -175,6 +181,15 @@ pub(crate) enum PropertyType {
     NoteTag = 0x44000811,
     SectionColor = 0x14001CBE,
     CellBackgroundColor = 0x14001E26,
+    // The length of an audio or video recording, in milliseconds. Observed on the embedded file
+    // node in test-data/Audio recording.one, where it's 5168 (the recorded WAV data is 5205ms).
+    RecordingDuration = 0x14001CFD,
+    RuleLinesHorizontalVisible = 0x14001CD3,
+    RuleLinesHorizontalSpacing = 0x14001CD4,
+    RuleLinesHorizontalColor = 0x14001CD5,
+    RuleLinesVerticalVisible = 0x14001CD6,
+    RuleLinesVerticalSpacing = 0x14001CD7,
+    RuleLinesVerticalColor = 0x14001CD8,
     InkBias = 0x0C00341C,
     InkData = 0x20003415,
     InkDimensions = 0x1C00340A,
@@ -209,3 +224,12 @@ pub(crate) enum PropertyType {
     MathUnknown1 = 0x10003453, // Unknown 16-bit math-related property (operator variant?)
     MathOperator = 0x1400344f,
 }
+
+impl PropertyType {
+    pub(crate) fn from_property_id(id: PropertyId) -> Option<PropertyType> {
+        // Bit 31 is the PropertyID's boolValue field, which stores the value of boolean
+        // properties and isn't part of the ID. See [MS-ONESTORE] 2.6.6:
+        // https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-onestore/17d8c39e-6cc2-4fcd-8d10-aee950fd0ab2
+        PropertyType::from_u32(id.value() & 0x7FFFFFFF)
+    }
+}
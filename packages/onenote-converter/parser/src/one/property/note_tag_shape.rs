@@ -153,6 +153,40 @@ pub enum NoteTagShape {
 }
 
 impl NoteTagShape {
+    /// Whether the icon is a checkbox that can be checked off, like a to-do item.
+    pub fn is_checkbox(&self) -> bool {
+        matches!(
+            self,
+            NoteTagShape::GreenCheckBox
+                | NoteTagShape::YellowCheckBox
+                | NoteTagShape::BlueCheckBox
+                | NoteTagShape::GreenStarCheckBox
+                | NoteTagShape::YellowStarCheckBox
+                | NoteTagShape::BlueStarCheckBox
+                | NoteTagShape::GreenExclamationCheckBox
+                | NoteTagShape::YellowExclamationCheckBox
+                | NoteTagShape::BlueExclamationCheckBox
+                | NoteTagShape::GreenRightArrowCheckBox
+                | NoteTagShape::YellowRightArrowCheckBox
+                | NoteTagShape::BlueRightArrowCheckBox
+                | NoteTagShape::BlueCheckBox1
+                | NoteTagShape::BlueCheckBox2
+                | NoteTagShape::BlueCheckBox3
+                | NoteTagShape::GreenCheckBox1
+                | NoteTagShape::GreenCheckBox2
+                | NoteTagShape::GreenCheckBox3
+                | NoteTagShape::YellowCheckBox1
+                | NoteTagShape::YellowCheckBox2
+                | NoteTagShape::YellowCheckBox3
+                | NoteTagShape::BluePersonCheckBox
+                | NoteTagShape::YellowPersonCheckBox
+                | NoteTagShape::GreenPersonCheckBox
+                | NoteTagShape::BlueFlagCheckBox
+                | NoteTagShape::RedFlagCheckBox
+                | NoteTagShape::GreenFlagCheckBox
+        )
+    }
+
     pub(crate) fn parse(value: u16) -> NoteTagShape {
         match value {
             0 => NoteTagShape::NoIcon,
@@ -3,7 +3,7 @@ use crate::onestore::object::Object;
 use parser_utils::errors::{ErrorKind, Result};
 use parser_utils::reader::Reader;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct OutlineIndentDistance(Vec<f32>);
 
 impl OutlineIndentDistance {
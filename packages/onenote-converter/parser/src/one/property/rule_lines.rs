@@ -0,0 +1,49 @@
+use crate::one::property::color::Color;
+use crate::one::property::{PropertyType, simple};
+use crate::onestore::object::Object;
+use parser_utils::errors::Result;
+
+/// The rule lines drawn on a page in one direction.
+///
+/// These properties aren't documented in \[MS-ONE\]. Each direction is stored as a set of
+/// three properties: whether the lines are shown, the distance between them and their
+/// color. Unlike other colors, the line color is stored as a `0xAARRGGBB` value.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RuleLines {
+    spacing: f32,
+    color: Option<Color>,
+}
+
+impl RuleLines {
+    /// The distance between two lines in half-inch increments.
+    pub fn spacing(&self) -> f32 {
+        self.spacing
+    }
+
+    /// The color of the lines.
+    pub fn color(&self) -> Option<Color> {
+        self.color
+    }
+}
+
+impl RuleLines {
+    pub(crate) fn parse(
+        visible_type: PropertyType,
+        spacing_type: PropertyType,
+        color_type: PropertyType,
+        object: &Object,
+    ) -> Result<Option<RuleLines>> {
+        let visible = simple::parse_u32(visible_type, object)?.unwrap_or_default() != 0;
+        if !visible {
+            return Ok(None);
+        }
+
+        let spacing = match simple::parse_f32(spacing_type, object)? {
+            Some(spacing) if spacing.is_finite() && spacing > 0.0 => spacing,
+            _ => return Ok(None),
+        };
+        let color = simple::parse_u32(color_type, object)?.map(Color::from_argb);
+
+        Ok(Some(RuleLines { spacing, color }))
+    }
+}
@@ -36,6 +36,7 @@ pub(crate) struct Data {
     pub(crate) note_tags: Vec<NoteTagData>,
     pub(crate) offset_from_parent_horiz: Option<f32>,
     pub(crate) offset_from_parent_vert: Option<f32>,
+    pub(crate) layout_collision_priority: Option<u32>,
     pub(crate) recording_duration: Option<u32>,
 }
 
@@ -75,7 +76,9 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
     let picture_height = simple::parse_f32(PropertyType::PictureHeight, object)?;
     let offset_from_parent_horiz = simple::parse_f32(PropertyType::OffsetFromParentHoriz, object)?;
     let offset_from_parent_vert = simple::parse_f32(PropertyType::OffsetFromParentVert, object)?;
-    // let recording_duration = simple::parse_u32(PropertyType::Duration) // FIXME: Record duration property id not known
+    let layout_collision_priority =
+        simple::parse_u32(PropertyType::LayoutCollisionPriority, object)?;
+    let recording_duration = simple::parse_u32(PropertyType::RecordingDuration, object)?;
 
     let note_tags = NoteTagData::parse(object)?.unwrap_or_default();
 
@@ -98,7 +101,8 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
         note_tags,
         offset_from_parent_horiz,
         offset_from_parent_vert,
-        recording_duration: None, // FIXME: Parse this
+        layout_collision_priority,
+        recording_duration,
     };
 
     Ok(data)
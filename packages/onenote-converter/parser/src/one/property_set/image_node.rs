@@ -34,6 +34,7 @@ pub(crate) struct Data {
     pub(crate) note_tags: Vec<NoteTagData>,
     pub(crate) offset_from_parent_horiz: Option<f32>,
     pub(crate) offset_from_parent_vert: Option<f32>,
+    pub(crate) layout_collision_priority: Option<u32>,
     pub(crate) is_background: bool,
     pub(crate) iframe: Vec<ExGuid>,
 }
@@ -70,6 +71,8 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
     let hyperlink_url = simple::parse_string(PropertyType::WzHyperlinkUrl, object)?;
     let offset_from_parent_horiz = simple::parse_f32(PropertyType::OffsetFromParentHoriz, object)?;
     let offset_from_parent_vert = simple::parse_f32(PropertyType::OffsetFromParentVert, object)?;
+    let layout_collision_priority =
+        simple::parse_u32(PropertyType::LayoutCollisionPriority, object)?;
     let is_background = simple::parse_bool(PropertyType::IsBackground, object)?.unwrap_or_default();
 
     let note_tags = NoteTagData::parse(object)?.unwrap_or_default();
@@ -97,6 +100,7 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
         note_tags,
         offset_from_parent_horiz,
         offset_from_parent_vert,
+        layout_collision_priority,
         is_background,
         iframe,
     };
@@ -11,6 +11,7 @@ use parser_utils::errors::Result;
 pub(crate) struct Data {
     pub(crate) offset_from_parent_horiz: Option<f32>,
     pub(crate) offset_from_parent_vert: Option<f32>,
+    pub(crate) layout_collision_priority: Option<u32>,
     pub(crate) last_modified: Option<Time>,
     pub(crate) ink_data: Option<ExGuid>,
     pub(crate) children: Option<Vec<ExGuid>>,
@@ -26,6 +27,8 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
     let last_modified = Time::parse(PropertyType::LastModifiedTime, object)?;
     let offset_from_parent_horiz = simple::parse_f32(PropertyType::OffsetFromParentHoriz, object)?;
     let offset_from_parent_vert = simple::parse_f32(PropertyType::OffsetFromParentVert, object)?;
+    let layout_collision_priority =
+        simple::parse_u32(PropertyType::LayoutCollisionPriority, object)?;
     let ink_data = ObjectReference::parse(PropertyType::InkData, object)?;
     let children = ObjectReference::parse_vec(PropertyType::ContentChildNodes, object)?;
     let ink_scaling_x = simple::parse_f32(PropertyType::InkScalingX, object)?;
@@ -34,6 +37,7 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
     let data = Data {
         offset_from_parent_horiz,
         offset_from_parent_vert,
+        layout_collision_priority,
         last_modified,
         ink_data,
         children,
@@ -17,11 +17,11 @@ use parser_utils::errors::{ErrorKind, Result};
 ///
 /// [\[MS-ONE\] 2.2.88]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/a9938236-87f8-41b1-81f3-5f760e1247b8
 #[derive(Debug)]
-#[allow(dead_code)]
 pub(crate) struct Data {
     pub(crate) definition: Option<ExGuid>,
     pub(crate) created_at: Time,
     pub(crate) completed_at: Option<Time>,
+    pub(crate) due_at: Option<Time>,
     pub(crate) item_status: ActionItemStatus,
 }
 
@@ -57,6 +57,7 @@ impl Data {
         })?;
 
         let completed_at = Time::parse(PropertyType::NoteTagCompleted, object)?;
+        let due_at = Time::parse(PropertyType::TaskTagDueDat, object)?;
 
         let item_status = ActionItemStatus::parse(object)?.ok_or_else(|| {
             ErrorKind::MalformedOneNoteFileData("note tag container has no item status".into())
@@ -66,6 +67,7 @@ impl Data {
             definition,
             created_at,
             completed_at,
+            due_at,
             item_status,
         })
     }
@@ -110,3 +112,73 @@ impl Data {
             .collect())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::Data;
+    use crate::onestore::object::Object;
+    use crate::shared::object_prop_set::ObjectPropSet;
+    use crate::shared::prop_set::PropertySet;
+    use parser_utils::reader::Reader;
+    use time::macros::utc_datetime;
+
+    /// Creates an object with a single note tag state, with the given properties. Each
+    /// property is an ID and the bytes of its value.
+    fn note_tag_object(properties: &[(u32, &[u8])]) -> Object {
+        let mut data = Vec::new();
+        data.extend(1u16.to_le_bytes());
+        data.extend(0x40003489u32.to_le_bytes()); // NoteTags
+        data.extend(1u32.to_le_bytes());
+        data.extend(0x00120001u32.to_le_bytes());
+        data.extend((properties.len() as u16).to_le_bytes());
+        for (id, _) in properties {
+            data.extend(id.to_le_bytes());
+        }
+        for (_, value) in properties {
+            data.extend(*value);
+        }
+
+        let mut object = Object::fallback();
+        object.props = ObjectPropSet {
+            properties: PropertySet::parse(&mut Reader::new(&data)).unwrap(),
+            ..object.props
+        };
+        object
+    }
+
+    #[test]
+    fn should_parse_created_and_due_dates() {
+        let object = note_tag_object(&[
+            (0x1400346E, &3600u32.to_le_bytes()),  // NoteTagCreated
+            (0x1400346B, &86400u32.to_le_bytes()), // TaskTagDueDat
+            (0x10003470, &4u16.to_le_bytes()),     // ActionItemStatus: task tag
+        ]);
+
+        let data = Data::parse(&object).unwrap().unwrap();
+        assert_eq!(data.len(), 1);
+        assert_eq!(
+            time::UtcDateTime::from(data[0].created_at),
+            utc_datetime!(1980-01-01 1:00)
+        );
+        assert_eq!(
+            data[0].due_at.map(time::UtcDateTime::from),
+            Some(utc_datetime!(1980-01-02 0:00))
+        );
+        assert_eq!(data[0].completed_at, None);
+        assert!(data[0].item_status.task_tag());
+    }
+
+    #[test]
+    fn should_require_created_date_and_status() {
+        let object = note_tag_object(&[(0x10003470, &0u16.to_le_bytes())]);
+        assert!(Data::parse(&object).is_err());
+
+        let object = note_tag_object(&[(0x1400346E, &0u32.to_le_bytes())]);
+        assert!(Data::parse(&object).is_err());
+    }
+
+    #[test]
+    fn should_ignore_objects_without_note_tags() {
+        assert!(Data::parse(&Object::fallback()).unwrap().is_none());
+    }
+}
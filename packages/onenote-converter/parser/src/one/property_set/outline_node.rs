@@ -27,6 +27,7 @@ pub(crate) struct Data {
     pub(crate) is_layout_size_set_by_user: bool,
     pub(crate) offset_from_parent_horiz: Option<f32>,
     pub(crate) offset_from_parent_vert: Option<f32>,
+    pub(crate) layout_collision_priority: Option<u32>,
     pub(crate) list_spacing: Option<f32>,
     pub(crate) outline_indent_distance: OutlineIndentDistance,
     pub(crate) layout_alignment_in_parent: Option<LayoutAlignment>,
@@ -72,6 +73,8 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
     })?;
     let offset_from_parent_horiz = simple::parse_f32(PropertyType::OffsetFromParentHoriz, object)?;
     let offset_from_parent_vert = simple::parse_f32(PropertyType::OffsetFromParentVert, object)?;
+    let layout_collision_priority =
+        simple::parse_u32(PropertyType::LayoutCollisionPriority, object)?;
 
     let layout_alignment_in_parent =
         LayoutAlignment::parse(PropertyType::LayoutAlignmentInParent, object)?;
@@ -101,6 +104,7 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
         is_layout_size_set_by_user,
         offset_from_parent_horiz,
         offset_from_parent_vert,
+        layout_collision_priority,
         list_spacing,
         outline_indent_distance,
         layout_alignment_in_parent,
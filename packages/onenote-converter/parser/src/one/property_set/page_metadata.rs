@@ -14,7 +14,7 @@ use parser_utils::errors::{ErrorKind, Result};
 #[allow(dead_code)]
 pub(crate) struct Data {
     pub(crate) entity_guid: Guid,
-    pub(crate) cached_title: String,
+    pub(crate) cached_title: Option<String>,
     pub(crate) schema_revision_in_order_to_read: Option<u32>, // FIXME: Force this?
     pub(crate) schema_revision_in_order_to_write: Option<u32>, // FIXME: Force this?
     pub(crate) page_level: i32,
@@ -29,9 +29,7 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
 
     let entity_guid = simple::parse_guid(PropertyType::NotebookManagementEntityGuid, object)?
         .ok_or_else(|| ErrorKind::MalformedOneNoteFileData("page metadata has no guid".into()))?;
-    // The page might not have a title but we can use the first Section outline from the body as the fallback later
-    let cached_title = simple::parse_string(PropertyType::CachedTitleString, object)?
-        .unwrap_or("Untitled Page".to_string());
+    let cached_title = simple::parse_string(PropertyType::CachedTitleString, object)?;
     let schema_revision_in_order_to_read =
         simple::parse_u32(PropertyType::SchemaRevisionInOrderToRead, object)?;
     let schema_revision_in_order_to_write =
@@ -1,6 +1,7 @@
 use crate::one::property::author::Author;
 use crate::one::property::object_reference::ObjectReference;
 use crate::one::property::page_size::PageSize;
+use crate::one::property::rule_lines::RuleLines;
 use crate::one::property::time::Time;
 use crate::one::property::{PropertyType, simple};
 use crate::one::property_set::PropertySetId;
@@ -32,6 +33,9 @@ pub(crate) struct Data {
     pub(crate) page_margin_bottom: Option<f32>, // FIXME: Force this?
     pub(crate) page_size: PageSize,
     pub(crate) rtl: bool,
+    pub(crate) resolve_child_collisions: bool,
+    pub(crate) rule_lines_horizontal: Option<RuleLines>,
+    pub(crate) rule_lines_vertical: Option<RuleLines>,
 }
 
 pub(crate) fn parse(object: &Object) -> Result<Data> {
@@ -60,6 +64,20 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
     let page_margin_bottom = simple::parse_f32(PropertyType::PageMarginBottom, object)?;
     let page_size = PageSize::parse(PropertyType::PageSize, object)?.unwrap_or_default();
     let rtl = simple::parse_bool(PropertyType::EditRootRtl, object)?.unwrap_or_default();
+    let resolve_child_collisions =
+        simple::parse_bool(PropertyType::LayoutResolveChildCollisions, object)?.unwrap_or_default();
+    let rule_lines_horizontal = RuleLines::parse(
+        PropertyType::RuleLinesHorizontalVisible,
+        PropertyType::RuleLinesHorizontalSpacing,
+        PropertyType::RuleLinesHorizontalColor,
+        object,
+    )?;
+    let rule_lines_vertical = RuleLines::parse(
+        PropertyType::RuleLinesVerticalVisible,
+        PropertyType::RuleLinesVerticalSpacing,
+        PropertyType::RuleLinesVerticalColor,
+        object,
+    )?;
 
     let data = Data {
         last_modified,
@@ -78,6 +96,9 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
         page_margin_bottom,
         page_size,
         rtl,
+        resolve_child_collisions,
+        rule_lines_horizontal,
+        rule_lines_vertical,
     };
 
     Ok(data)
@@ -21,6 +21,7 @@ pub(crate) struct Data {
     pub(crate) offset_vertical: f32,
     pub(crate) layout_alignment_in_parent: Option<LayoutAlignment>,
     pub(crate) layout_alignment_self: Option<LayoutAlignment>,
+    pub(crate) layout_collision_priority: Option<u32>,
 }
 
 pub(crate) fn parse(object: &Object) -> Result<Data> {
@@ -49,6 +50,8 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
     let layout_alignment_in_parent =
         LayoutAlignment::parse(PropertyType::LayoutAlignmentInParent, object)?;
     let layout_alignment_self = LayoutAlignment::parse(PropertyType::LayoutAlignmentSelf, object)?;
+    let layout_collision_priority =
+        simple::parse_u32(PropertyType::LayoutCollisionPriority, object)?;
 
     let data = Data {
         last_modified_time,
@@ -57,6 +60,7 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
         offset_vertical,
         layout_alignment_in_parent,
         layout_alignment_self,
+        layout_collision_priority,
     };
 
     Ok(data)
@@ -1,9 +1,11 @@
 use crate::one::property_set::PropertySetId;
+use crate::onenote::diagnostics::DiagnosticsCollector;
 use crate::onenote::embedded_file::{EmbeddedFile, parse_embedded_file};
 use crate::onenote::image::{Image, parse_image};
 use crate::onenote::ink::{Ink, parse_ink};
 use crate::onenote::rich_text::{RichText, parse_rich_text};
 use crate::onenote::table::{Table, parse_table};
+use crate::onenote::unknown_content::{UnknownContent, parse_unknown_content};
 use crate::onestore::object_space::ObjectSpaceRef;
 use crate::shared::exguid::ExGuid;
 use parser_utils::errors::{ErrorKind, Result};
@@ -26,8 +28,8 @@ pub enum Content {
     /// An ink drawing
     Ink(Ink),
 
-    /// Content of unknown type.
-    Unknown,
+    /// Content of a type the parser doesn't support.
+    Unknown(UnknownContent),
 }
 
 impl Content {
@@ -75,28 +77,45 @@ impl Content {
             None
         }
     }
+
+    /// Return the object's type, position and size if it's content of an unsupported type.
+    pub fn unknown(&self) -> Option<&UnknownContent> {
+        if let Content::Unknown(content) = self {
+            Some(content)
+        } else {
+            None
+        }
+    }
 }
 
-pub(crate) fn parse_content(content_id: ExGuid, space: ObjectSpaceRef) -> Result<Content> {
-    let content_type = space
-        .get_object(content_id)
-        .ok_or_else(|| ErrorKind::MalformedOneNoteData("page content is missing".into()))?
-        .id();
-    let id = PropertySetId::from_jcid(content_type).ok_or_else(|| {
-        ErrorKind::MalformedOneNoteData(
-            format!("invalid property set id: 0x{:X}", content_type.0).into(),
-        )
-    })?;
+pub(crate) fn parse_content(
+    content_id: ExGuid,
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<Content> {
+    let object = diagnostics
+        .get_object(&space, content_id)
+        .ok_or_else(|| ErrorKind::MalformedOneNoteData("page content is missing".into()))?;
+    let content_type = object.id();
+    let Some(id) = PropertySetId::from_jcid(content_type) else {
+        diagnostics.report_unknown_object(content_id, content_type);
+        return Ok(Content::Unknown(parse_unknown_content(&object)));
+    };
 
     let content = match id {
-        PropertySetId::ImageNode => Content::Image(parse_image(content_id, space)?),
+        PropertySetId::ImageNode => Content::Image(parse_image(content_id, space, diagnostics)?),
         PropertySetId::EmbeddedFileNode => {
-            Content::EmbeddedFile(parse_embedded_file(content_id, space)?)
+            Content::EmbeddedFile(parse_embedded_file(content_id, space, diagnostics)?)
+        }
+        PropertySetId::RichTextNode => {
+            Content::RichText(parse_rich_text(content_id, space, diagnostics)?)
+        }
+        PropertySetId::TableNode => Content::Table(parse_table(content_id, space, diagnostics)?),
+        PropertySetId::InkContainer => Content::Ink(parse_ink(content_id, space, diagnostics)?),
+        _ => {
+            diagnostics.report_unknown_object(content_id, content_type);
+            Content::Unknown(parse_unknown_content(&object))
         }
-        PropertySetId::RichTextNode => Content::RichText(parse_rich_text(content_id, space)?),
-        PropertySetId::TableNode => Content::Table(parse_table(content_id, space)?),
-        PropertySetId::InkContainer => Content::Ink(parse_ink(content_id, space)?),
-        _ => Content::Unknown,
     };
 
     Ok(content)
@@ -0,0 +1,210 @@
+//! Collects information about page content that the parser couldn't interpret.
+//!
+//! Diagnostics are collected while a page is being parsed (see [`DiagnosticsCollector`]) and
+//! are stored with the parsed page.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::rc::Rc;
+
+use crate::one::property::PropertyType;
+use crate::one::property_set::PropertySetId;
+use crate::onestore::object::Object;
+use crate::onestore::object_space::ObjectSpaceRef;
+use crate::shared::exguid::ExGuid;
+use crate::shared::jcid::JcId;
+use crate::shared::prop_set::PropertySet;
+use crate::shared::property::PropertyValue;
+
+/// Content of a page that the parser could not interpret, and that is likely missing from
+/// the parsed page.
+#[derive(Clone, Debug, Default)]
+pub struct PageDiagnostics {
+    unknown_object_types: BTreeMap<u32, usize>,
+    unknown_properties: BTreeMap<u32, usize>,
+    skipped_objects: Vec<SkippedObject>,
+}
+
+impl PageDiagnostics {
+    /// Whether any problems were found.
+    pub fn is_empty(&self) -> bool {
+        self.unknown_object_types.is_empty()
+            && self.unknown_properties.is_empty()
+            && self.skipped_objects.is_empty()
+    }
+
+    /// Whether content was left out of the page.
+    pub fn has_content_loss(&self) -> bool {
+        !self.skipped_objects.is_empty()
+    }
+
+    /// The object types (JCIDs) that could not be converted to page content, with the number
+    /// of objects of each type.
+    pub fn unknown_object_types(&self) -> impl Iterator<Item = (u32, usize)> + '_ {
+        self.unknown_object_types
+            .iter()
+            .map(|(jcid, count)| (*jcid, *count))
+    }
+
+    /// Property IDs that the parser doesn't recognise, with the number of times each was found.
+    pub fn unknown_properties(&self) -> impl Iterator<Item = (u32, usize)> + '_ {
+        self.unknown_properties
+            .iter()
+            .map(|(id, count)| (*id, *count))
+    }
+
+    /// Objects that were left out of the page.
+    pub fn skipped_objects(&self) -> &[SkippedObject] {
+        &self.skipped_objects
+    }
+}
+
+/// An object that was left out of a parsed page.
+#[derive(Clone, Debug)]
+pub struct SkippedObject {
+    id: ExGuid,
+    jcid: Option<u32>,
+    reason: &'static str,
+}
+
+impl SkippedObject {
+    /// The ID of the object, in `{GUID},value` form.
+    pub fn id(&self) -> String {
+        format!("{},{}", self.id.guid, self.id.value)
+    }
+
+    /// The object's type (JCID), if the object could be found.
+    pub fn jcid(&self) -> Option<u32> {
+        self.jcid
+    }
+
+    /// The name of the object's type, if the parser knows it.
+    pub fn type_name(&self) -> Option<String> {
+        self.jcid
+            .and_then(|jcid| PropertySetId::from_jcid(JcId(jcid)))
+            .map(|id| format!("{:?}", id))
+    }
+
+    /// Why the object was skipped.
+    pub fn reason(&self) -> &str {
+        self.reason
+    }
+}
+
+impl fmt::Display for SkippedObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.reason)?;
+        match (self.type_name(), self.jcid) {
+            (Some(name), _) => write!(f, "({}, ", name)?,
+            (None, Some(jcid)) => write!(f, "(JCID 0x{:08X}, ", jcid)?,
+            (None, None) => write!(f, "(")?,
+        }
+        write!(f, "ID {})", self.id())
+    }
+}
+
+/// Collects diagnostics while a page is parsed. Created by `parse_page` and passed to the
+/// functions that parse the page's content.
+#[derive(Debug, Default)]
+pub(crate) struct DiagnosticsCollector {
+    diagnostics: RefCell<PageDiagnostics>,
+    inspected_objects: RefCell<HashSet<ExGuid>>,
+}
+
+impl DiagnosticsCollector {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads an object from `space`, recording any unknown properties it has.
+    pub(crate) fn get_object(&self, space: &ObjectSpaceRef, id: ExGuid) -> Option<Rc<Object>> {
+        let object = space.get_object(id)?;
+        if self.inspected_objects.borrow_mut().insert(id) {
+            count_unknown_properties(
+                object.props().properties(),
+                &mut self.diagnostics.borrow_mut().unknown_properties,
+            );
+        }
+
+        Some(object)
+    }
+
+    /// Records an object whose type can't be converted to page content.
+    pub(crate) fn report_unknown_object(&self, id: ExGuid, jcid: JcId) {
+        let mut diagnostics = self.diagnostics.borrow_mut();
+        *diagnostics.unknown_object_types.entry(jcid.0).or_default() += 1;
+        diagnostics.skipped_objects.push(SkippedObject {
+            id,
+            jcid: Some(jcid.0),
+            reason: "Unsupported content",
+        });
+    }
+
+    /// Records a referenced object that couldn't be found.
+    pub(crate) fn report_missing_object(&self, id: ExGuid, reason: &'static str) {
+        self.diagnostics
+            .borrow_mut()
+            .skipped_objects
+            .push(SkippedObject {
+                id,
+                jcid: None,
+                reason,
+            });
+    }
+
+    /// Returns the diagnostics collected for the page.
+    pub(crate) fn finish(self) -> PageDiagnostics {
+        self.diagnostics.into_inner()
+    }
+}
+
+fn count_unknown_properties(properties: &PropertySet, counts: &mut BTreeMap<u32, usize>) {
+    for (property_id, value) in properties.entries() {
+        if PropertyType::from_property_id(property_id).is_none() {
+            // Group boolean properties by ID, regardless of their value (see
+            // `PropertyType::from_property_id`).
+            let without_bool_value = property_id.value() & 0x7FFFFFFF;
+            *counts.entry(without_bool_value).or_default() += 1;
+        }
+
+        match value {
+            PropertyValue::PropertyValues(_, sets) => {
+                for set in sets {
+                    count_unknown_properties(set, counts);
+                }
+            }
+            PropertyValue::PropertySet(set) => count_unknown_properties(set, counts),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DiagnosticsCollector;
+    use crate::shared::exguid::ExGuid;
+    use crate::shared::jcid::JcId;
+
+    #[test]
+    fn should_collect_diagnostics_for_a_page() {
+        let collector = DiagnosticsCollector::new();
+        collector.report_unknown_object(ExGuid::fallback(), JcId(0x0006003A));
+        collector.report_unknown_object(ExGuid::fallback(), JcId(0x0006003A));
+        collector.report_missing_object(ExGuid::fallback(), "Missing ink data");
+        let diagnostics = collector.finish();
+
+        assert!(diagnostics.has_content_loss());
+        assert_eq!(
+            diagnostics.unknown_object_types().collect::<Vec<_>>(),
+            vec![(0x0006003A, 2)]
+        );
+        assert_eq!(diagnostics.skipped_objects().len(), 3);
+        assert_eq!(
+            diagnostics.skipped_objects()[2].to_string(),
+            "Missing ink data (ID {00000000-0000-0000-0000-000000000000},0)"
+        );
+
+        assert!(DiagnosticsCollector::new().finish().is_empty());
+    }
+}
@@ -1,11 +1,14 @@
 use std::io::Read;
+use std::time::Duration;
 
 use crate::one::property::file_type::FileType;
 use crate::one::property_set::{embedded_file_container, embedded_file_node};
+use crate::onenote::diagnostics::DiagnosticsCollector;
 use crate::onenote::note_tag::{NoteTag, parse_note_tags};
 use crate::onestore::object_space::ObjectSpaceRef;
 use crate::shared::exguid::ExGuid;
 use crate::shared::file_data_ref::FileBlob;
+use bytes::Bytes;
 use parser_utils::errors::{ErrorKind, Result};
 
 /// An embedded file.
@@ -24,6 +27,10 @@ pub struct EmbeddedFile {
 
     pub(crate) offset_horizontal: Option<f32>,
     pub(crate) offset_vertical: Option<f32>,
+    pub(crate) layout_collision_priority: Option<u32>,
+
+    pub(crate) recorded_at: Option<time::UtcDateTime>,
+    pub(crate) recording_duration: Option<Duration>,
 
     pub(crate) note_tags: Vec<NoteTag>,
 }
@@ -52,6 +59,14 @@ impl EmbeddedFile {
         self.data.read()
     }
 
+    /// The file's binary data, without copying it, if it is held in memory.
+    ///
+    /// Returns `None` if the data is read from the section file on demand. Use
+    /// [`EmbeddedFile::read`] in this case.
+    pub fn bytes(&self) -> Option<Bytes> {
+        self.data.bytes()
+    }
+
     /// The max width of the embedded file's icon in half-inch increments.
     ///
     /// See [\[MS-ONE\] 2.3.21].
@@ -88,19 +103,54 @@ impl EmbeddedFile {
         self.offset_vertical
     }
 
+    /// The file's priority when OneNote moves page contents so that they don't overlap
+    /// (if present).
+    pub fn layout_collision_priority(&self) -> Option<u32> {
+        self.layout_collision_priority
+    }
+
+    /// Whether the file is an audio or video recording made with OneNote.
+    pub fn is_recording(&self) -> bool {
+        self.file_type != FileType::Unknown
+    }
+
+    /// The time at which the recording was made, for recordings made with OneNote.
+    ///
+    /// This is an approximation: the file doesn't store when a recording was made, so this is
+    /// the time at which the recording was last modified.
+    pub fn recorded_at(&self) -> Option<time::UtcDateTime> {
+        self.recorded_at
+    }
+
+    /// The length of the recording, for recordings made with OneNote.
+    pub fn recording_duration(&self) -> Option<Duration> {
+        self.recording_duration
+    }
+
     /// Note tags for the embedded file.
     pub fn note_tags(&self) -> &[NoteTag] {
         &self.note_tags
     }
 }
 
-pub(crate) fn parse_embedded_file(file_id: ExGuid, space: ObjectSpaceRef) -> Result<EmbeddedFile> {
-    let node_object = space
-        .get_object(file_id)
+pub(crate) fn parse_embedded_file(
+    file_id: ExGuid,
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<EmbeddedFile> {
+    let node_object = diagnostics
+        .get_object(&space, file_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("embedded file is missing".into()))?;
     let node = embedded_file_node::parse(&node_object)?;
     let fallback_value = ExGuid::fallback();
 
+    let is_recording = node.file_type != FileType::Unknown;
+    let recorded_at = is_recording.then(|| node.last_modified.into());
+    let recording_duration = node
+        .recording_duration
+        .filter(|_| is_recording)
+        .map(|duration| Duration::from_millis(duration.into()));
+
     if node.embedded_file_name.is_none() {
         return Err(ErrorKind::MalformedOneNoteData(
             "embedded file name didn't return any value".into(),
@@ -125,14 +175,17 @@ pub(crate) fn parse_embedded_file(file_id: ExGuid, space: ObjectSpaceRef) -> Res
                 layout_max_height: node.layout_max_height,
                 offset_horizontal: node.offset_from_parent_horiz,
                 offset_vertical: node.offset_from_parent_vert,
-                note_tags: parse_note_tags(node.note_tags, space)?,
+                layout_collision_priority: node.layout_collision_priority,
+                recorded_at,
+                recording_duration,
+                note_tags: parse_note_tags(node.note_tags, space, diagnostics)?,
             }
         });
     }
 
     let container_object_id = node.embedded_file_container;
-    let container_object = space
-        .get_object(container_object_id.unwrap())
+    let container_object = diagnostics
+        .get_object(&space, container_object_id.unwrap())
         .ok_or_else(|| {
             ErrorKind::MalformedOneNoteData("embedded file container is missing".into())
         })?;
@@ -148,8 +201,58 @@ pub(crate) fn parse_embedded_file(file_id: ExGuid, space: ObjectSpaceRef) -> Res
         layout_max_height: node.layout_max_height,
         offset_horizontal: node.offset_from_parent_horiz,
         offset_vertical: node.offset_from_parent_vert,
-        note_tags: parse_note_tags(node.note_tags, space)?,
+        layout_collision_priority: node.layout_collision_priority,
+        recorded_at,
+        recording_duration,
+        note_tags: parse_note_tags(node.note_tags, space, diagnostics)?,
     };
 
     Ok(file)
 }
+
+#[cfg(test)]
+mod test {
+    use crate::Parser;
+    use crate::contents::{Content, EmbeddedFile, OutlineItem};
+    use crate::property::embedded_file::FileType;
+    use parser_utils::fs_driver;
+    use std::time::Duration;
+
+    fn find_embedded_file(items: &[OutlineItem]) -> Option<&EmbeddedFile> {
+        items.iter().find_map(|item| match item {
+            OutlineItem::Group(group) => find_embedded_file(group.outlines()),
+            OutlineItem::Element(element) => element
+                .contents()
+                .iter()
+                .find_map(|content| match content {
+                    Content::EmbeddedFile(file) => Some(file),
+                    _ => None,
+                })
+                .or_else(|| find_embedded_file(element.children())),
+        })
+    }
+
+    #[test]
+    fn should_parse_audio_recordings() {
+        let data = fs_driver()
+            .read_file("../test-data/Audio recording.one")
+            .unwrap();
+        let section = Parser::new()
+            .parse_section_from_data(&data, "Audio recording.one")
+            .unwrap();
+        let page = &section.page_series()[0].pages()[0];
+
+        let file = page
+            .contents()
+            .iter()
+            .filter_map(|content| content.outline())
+            .find_map(|outline| find_embedded_file(outline.items()))
+            .unwrap();
+
+        assert_eq!(file.filename(), "Audio Recording.wav");
+        assert_eq!(file.file_type(), &FileType::Audio);
+        assert!(file.is_recording());
+        assert!(file.recorded_at().is_some());
+        assert_eq!(file.recording_duration(), Some(Duration::from_millis(5168)));
+    }
+}
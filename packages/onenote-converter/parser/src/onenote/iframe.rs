@@ -1,4 +1,5 @@
 use crate::one::property_set::iframe_node;
+use crate::onenote::diagnostics::DiagnosticsCollector;
 use crate::onestore::object_space::ObjectSpaceRef;
 use crate::shared::exguid::ExGuid;
 use parser_utils::errors::{ErrorKind, Result};
@@ -19,9 +20,13 @@ impl IFrame {
     }
 }
 
-pub(crate) fn parse_iframe(iframe_id: ExGuid, space: ObjectSpaceRef) -> Result<IFrame> {
-    let object = space
-        .get_object(iframe_id)
+pub(crate) fn parse_iframe(
+    iframe_id: ExGuid,
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<IFrame> {
+    let object = diagnostics
+        .get_object(&space, iframe_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("image is missing".into()))?;
     let data = iframe_node::parse(&object)?;
 
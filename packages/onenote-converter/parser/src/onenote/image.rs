@@ -1,12 +1,15 @@
 use std::io::Read;
 
+use crate::one::property::language_id::language_tag;
 use crate::one::property::layout_alignment::LayoutAlignment;
 use crate::one::property_set::{image_node, picture_container};
+use crate::onenote::diagnostics::DiagnosticsCollector;
 use crate::onenote::iframe::{IFrame, parse_iframe};
 use crate::onenote::note_tag::{NoteTag, parse_note_tags};
 use crate::onestore::object_space::ObjectSpaceRef;
 use crate::shared::exguid::ExGuid;
 use crate::shared::file_data_ref::FileBlob;
+use bytes::Bytes;
 use parser_utils::errors::{ErrorKind, Result};
 
 /// An embedded image.
@@ -16,6 +19,7 @@ use parser_utils::errors::{ErrorKind, Result};
 /// [\[MS-ONE\] 2.2.24]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/b7bb4d1a-2a57-4819-9eb4-5a2ce8cf210f
 #[derive(Clone, PartialEq, Debug)]
 pub struct Image {
+    pub(crate) id: ExGuid,
     pub(crate) data: Option<FileBlob>,
     pub(crate) extension: Option<String>,
 
@@ -42,6 +46,7 @@ pub struct Image {
 
     pub(crate) offset_horizontal: Option<f32>,
     pub(crate) offset_vertical: Option<f32>,
+    pub(crate) layout_collision_priority: Option<u32>,
 
     pub(crate) is_background: bool,
 
@@ -58,6 +63,14 @@ impl Image {
         self.data.as_ref().map(|data| data.read()).transpose()
     }
 
+    /// The image's binary data, without copying it, if it is held in memory.
+    ///
+    /// Returns `None` if the image data hasn't been uploaded yet or is read from the
+    /// section file on demand. Use [`Image::read`] in this case.
+    pub fn bytes(&self) -> Option<Bytes> {
+        self.data.as_ref().and_then(|data| data.bytes())
+    }
+
     /// The image's file extension.
     pub fn extension(&self) -> Option<&str> {
         self.extension.as_deref()
@@ -138,6 +151,12 @@ impl Image {
         self.text_language_code
     }
 
+    /// The BCP 47 language tag for the text (for example, `en-US`), if the language code
+    /// is known.
+    pub fn text_language_tag(&self) -> Option<&'static str> {
+        self.text_language_code.and_then(language_tag)
+    }
+
     /// The image's width in half-inch increments.
     ///
     /// See [\[MS-ONE\] 2.3.100].
@@ -179,6 +198,12 @@ impl Image {
         self.offset_vertical
     }
 
+    /// The image's priority when OneNote moves page contents so that they don't overlap
+    /// (if present).
+    pub fn layout_collision_priority(&self) -> Option<u32> {
+        self.layout_collision_priority
+    }
+
     /// Whether the image is a background image.
     ///
     /// See [\[MS-ONE\] 2.3.61].
@@ -199,17 +224,21 @@ impl Image {
     }
 }
 
-pub(crate) fn parse_image(image_id: ExGuid, space: ObjectSpaceRef) -> Result<Image> {
-    let node_object = space
-        .get_object(image_id)
+pub(crate) fn parse_image(
+    image_id: ExGuid,
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<Image> {
+    let node_object = diagnostics
+        .get_object(&space, image_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("image is missing".into()))?;
     let node = image_node::parse(&node_object)?;
 
     let container_data = node
         .picture_container
         .map(|container_object_id| {
-            space
-                .get_object(container_object_id)
+            diagnostics
+                .get_object(&space, container_object_id)
                 .ok_or_else(|| ErrorKind::MalformedOneNoteData("image container is missing".into()))
         })
         .transpose()?
@@ -225,12 +254,11 @@ pub(crate) fn parse_image(image_id: ExGuid, space: ObjectSpaceRef) -> Result<Ima
     let embed = node
         .iframe
         .into_iter()
-        .map(|iframe_id| parse_iframe(iframe_id, space.clone()))
+        .map(|iframe_id| parse_iframe(iframe_id, space.clone(), diagnostics))
         .collect::<Result<_>>()?;
 
-    // TODO: Parse language code
-
     let image = Image {
+        id: image_id,
         data,
         extension,
         layout_max_width: node.layout_max_width,
@@ -247,8 +275,9 @@ pub(crate) fn parse_image(image_id: ExGuid, space: ObjectSpaceRef) -> Result<Ima
         hyperlink_url: node.hyperlink_url,
         offset_horizontal: node.offset_from_parent_horiz,
         offset_vertical: node.offset_from_parent_vert,
+        layout_collision_priority: node.layout_collision_priority,
         is_background: node.is_background,
-        note_tags: parse_note_tags(node.note_tags, space)?,
+        note_tags: parse_note_tags(node.note_tags, space, diagnostics)?,
         embeds: embed,
     };
 
@@ -1,6 +1,7 @@
 use crate::one::property_set::{
     ink_container, ink_data_node, ink_stroke_node, stroke_properties_node,
 };
+use crate::onenote::diagnostics::DiagnosticsCollector;
 use crate::onestore::object_space::ObjectSpaceRef;
 use crate::shared::exguid::ExGuid;
 use parser_utils::errors::{ErrorKind, Result};
@@ -14,6 +15,7 @@ pub struct Ink {
 
     pub(crate) offset_horizontal: Option<f32>,
     pub(crate) offset_vertical: Option<f32>,
+    pub(crate) layout_collision_priority: Option<u32>,
 }
 
 #[derive(Clone, Debug)]
@@ -61,6 +63,12 @@ impl Ink {
     pub fn offset_vertical(&self) -> Option<f32> {
         self.offset_vertical
     }
+
+    /// The drawing's priority when OneNote moves page contents so that they don't overlap
+    /// (if present).
+    pub fn layout_collision_priority(&self) -> Option<u32> {
+        self.layout_collision_priority
+    }
 }
 
 /// An ink stroke.
@@ -200,11 +208,20 @@ impl InkBoundingBox {
     }
 }
 
-pub(crate) fn parse_ink(ink_container_id: ExGuid, space: ObjectSpaceRef) -> Result<Ink> {
-    parse_ink_rec(ink_container_id, space, 0)
+pub(crate) fn parse_ink(
+    ink_container_id: ExGuid,
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<Ink> {
+    parse_ink_rec(ink_container_id, space, diagnostics, 0)
 }
 
-fn parse_ink_rec(ink_container_id: ExGuid, space: ObjectSpaceRef, depth: u32) -> Result<Ink> {
+fn parse_ink_rec(
+    ink_container_id: ExGuid,
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+    depth: u32,
+) -> Result<Ink> {
     // Cut maximum recursion depth at 16 to guard against cycles (e.g. if an ink node is declared
     // to recursively contain itself). An explicit error should be easier to debug than the
     // stack overflow that would otherwise occur.
@@ -214,8 +231,8 @@ fn parse_ink_rec(ink_container_id: ExGuid, space: ObjectSpaceRef, depth: u32) ->
         );
     }
 
-    let container_object = space
-        .get_object(ink_container_id)
+    let container_object = diagnostics
+        .get_object(&space, ink_container_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("ink container is missing".into()))?;
     let container = ink_container::parse(&container_object)?;
 
@@ -226,14 +243,16 @@ fn parse_ink_rec(ink_container_id: ExGuid, space: ObjectSpaceRef, depth: u32) ->
                 bounding_box: None,
                 offset_horizontal: None,
                 offset_vertical: None,
+                layout_collision_priority: None,
             });
         };
 
-        let (bbox, content) = parse_ink_group(children, space, depth)?;
+        let (bbox, content) = parse_ink_group(children, space, diagnostics, depth)?;
         return Ok(Ink {
             bounding_box: bbox,
             offset_horizontal: container.offset_from_parent_horiz,
             offset_vertical: container.offset_from_parent_vert,
+            layout_collision_priority: container.layout_collision_priority,
             content,
         });
     };
@@ -241,6 +260,7 @@ fn parse_ink_rec(ink_container_id: ExGuid, space: ObjectSpaceRef, depth: u32) ->
     let (ink_strokes, bounding_box) = parse_ink_data(
         ink_data_id,
         space,
+        diagnostics,
         container.ink_scaling_x,
         container.ink_scaling_y,
     )?;
@@ -250,19 +270,21 @@ fn parse_ink_rec(ink_container_id: ExGuid, space: ObjectSpaceRef, depth: u32) ->
         bounding_box,
         offset_horizontal: container.offset_from_parent_horiz,
         offset_vertical: container.offset_from_parent_vert,
+        layout_collision_priority: container.layout_collision_priority,
     })
 }
 
 fn parse_ink_group(
     children: Vec<ExGuid>,
     space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
     recursion_depth: u32,
 ) -> Result<(Option<InkBoundingBox>, InkContent)> {
     let mut ink_contents = vec![];
 
     let children = children
         .into_iter()
-        .map(|group_id| parse_ink_rec(group_id, space.clone(), recursion_depth + 1));
+        .map(|group_id| parse_ink_rec(group_id, space.clone(), diagnostics, recursion_depth + 1));
     let mut bbox = None;
     for child in children {
         let child = child?;
@@ -279,11 +301,13 @@ fn parse_ink_group(
 pub(crate) fn parse_ink_data(
     ink_data_id: ExGuid,
     space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
     scale_x: Option<f32>,
     scale_y: Option<f32>,
 ) -> Result<(Vec<InkStroke>, Option<InkBoundingBox>)> {
-    let Some(ink_data_object) = space.get_object(ink_data_id) else {
+    let Some(ink_data_object) = diagnostics.get_object(&space, ink_data_id) else {
         log_warn!("Ink data object {ink_data_id:?} not found! Not importing the ink.");
+        diagnostics.report_missing_object(ink_data_id, "Missing ink data");
         return Ok((vec![], None));
     };
 
@@ -293,7 +317,9 @@ pub(crate) fn parse_ink_data(
         .strokes
         .iter()
         .copied()
-        .map(|ink_stroke_id| parse_ink_stroke(ink_stroke_id, space.clone(), scale_x, scale_y))
+        .map(|ink_stroke_id| {
+            parse_ink_stroke(ink_stroke_id, space.clone(), diagnostics, scale_x, scale_y)
+        })
         .collect::<Result<_>>()?;
 
     let scale_x = scale_x.unwrap_or(1.0);
@@ -314,17 +340,20 @@ pub(crate) fn parse_ink_data(
 fn parse_ink_stroke(
     ink_stroke_id: ExGuid,
     space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
     scale_x: Option<f32>,
     scale_y: Option<f32>,
 ) -> Result<InkStroke> {
-    let object = space
-        .get_object(ink_stroke_id)
+    let object = diagnostics
+        .get_object(&space, ink_stroke_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("ink stroke node is missing".into()))?;
     let data = ink_stroke_node::parse(&object)?;
 
-    let props_object = space.get_object(data.properties).ok_or_else(|| {
-        ErrorKind::MalformedOneNoteData("ink stroke properties node is missing".into())
-    })?;
+    let props_object = diagnostics
+        .get_object(&space, data.properties)
+        .ok_or_else(|| {
+            ErrorKind::MalformedOneNoteData("ink stroke properties node is missing".into())
+        })?;
     let props = stroke_properties_node::parse(&props_object)?;
 
     let path = parse_ink_path(data.path, &props, scale_x, scale_y)?;
@@ -1,5 +1,6 @@
 use crate::one::property::color_ref::ColorRef;
 use crate::one::property_set::number_list_node;
+use crate::onenote::diagnostics::DiagnosticsCollector;
 use crate::onestore::object_space::ObjectSpaceRef;
 use crate::shared::exguid::ExGuid;
 use parser_utils::errors::{ErrorKind, Result};
@@ -50,6 +51,11 @@ impl List {
         &self.list_format
     }
 
+    /// The decoded list format specifier.
+    pub fn format(&self) -> ListFormat {
+        ListFormat::from_chars(&self.list_format)
+    }
+
     /// Whether to apply bold formatting to the list index number.
     pub fn bold(&self) -> bool {
         self.bold
@@ -84,9 +90,206 @@ impl List {
     }
 }
 
-pub(crate) fn parse_list(list_id: ExGuid, space: ObjectSpaceRef) -> Result<List> {
-    let object = space
-        .get_object(list_id)
+/// A decoded list format specifier (see [`List::list_format`]).
+///
+/// The format specifier is a template for the list item's marker: literal text (such as a
+/// bullet, or the `.` or `)` after a number) and placeholders for numbers. A placeholder is
+/// a `U+FFFD` character followed by a character with the number's style.
+///
+/// Formats with several placeholders (like `1.a.i)`) include the numbers of the parent
+/// list items: the last placeholder is the number of the item itself, the one before it
+/// the number of its parent item, and so on.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ListFormat {
+    parts: Vec<ListFormatPart>,
+}
+
+/// A part of a [`ListFormat`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ListFormatPart {
+    /// Text that is copied to the marker as-is.
+    Text(String),
+
+    /// The number of a list item.
+    Number {
+        /// The number of levels above the item that is being numbered: `0` for the item
+        /// itself, `1` for its parent item, and so on.
+        level: u8,
+        /// How the number is written.
+        style: NumberStyle,
+    },
+}
+
+/// How a list number is written.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum NumberStyle {
+    /// 1, 2, 3, …
+    Decimal,
+    /// I, II, III, …
+    UpperRoman,
+    /// i, ii, iii, …
+    LowerRoman,
+    /// A, B, C, …
+    UpperLatin,
+    /// a, b, c, …
+    LowerLatin,
+    /// A style that isn't supported. Contains the style's character code.
+    Unknown(u32),
+}
+
+const NUMBER_PLACEHOLDER: char = '\u{fffd}';
+
+impl ListFormat {
+    /// Decodes a list format specifier.
+    pub fn from_chars(format: &[char]) -> Self {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+
+        let mut chars = format.iter().copied();
+        while let Some(c) = chars.next() {
+            if c != NUMBER_PLACEHOLDER {
+                text.push(c);
+                continue;
+            }
+
+            if !text.is_empty() {
+                parts.push(ListFormatPart::Text(std::mem::take(&mut text)));
+            }
+
+            let style = match chars.next().map(u32::from) {
+                Some(0) | None => NumberStyle::Decimal,
+                Some(1) => NumberStyle::UpperRoman,
+                Some(2) => NumberStyle::LowerRoman,
+                Some(3) => NumberStyle::UpperLatin,
+                Some(4) => NumberStyle::LowerLatin,
+                Some(code) => NumberStyle::Unknown(code),
+            };
+            parts.push(ListFormatPart::Number { level: 0, style });
+        }
+
+        if !text.is_empty() {
+            parts.push(ListFormatPart::Text(text));
+        }
+
+        // Number the placeholders from the end, so that the last one is the item's own number
+        let mut level = 0;
+        for part in parts.iter_mut().rev() {
+            if let ListFormatPart::Number {
+                level: part_level, ..
+            } = part
+            {
+                *part_level = level;
+                level += 1;
+            }
+        }
+
+        ListFormat { parts }
+    }
+
+    /// The marker's text and number placeholders, in order.
+    pub fn parts(&self) -> &[ListFormatPart] {
+        &self.parts
+    }
+
+    /// Whether the list's items are numbered, rather than bulleted.
+    pub fn is_numbered(&self) -> bool {
+        self.parts
+            .iter()
+            .any(|part| matches!(part, ListFormatPart::Number { .. }))
+    }
+
+    /// The number of parent list items whose numbers are included in the marker.
+    pub fn parent_levels(&self) -> u8 {
+        self.parts
+            .iter()
+            .filter_map(|part| match part {
+                ListFormatPart::Number { level, .. } => Some(*level),
+                ListFormatPart::Text(_) => None,
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// The style of the item's own number, for numbered lists.
+    pub fn number_style(&self) -> Option<NumberStyle> {
+        self.parts.iter().find_map(|part| match part {
+            ListFormatPart::Number { level: 0, style } => Some(*style),
+            _ => None,
+        })
+    }
+}
+
+impl NumberStyle {
+    /// Formats `number` in this style. Unsupported styles are formatted as decimal numbers.
+    pub fn format(&self, number: i32) -> String {
+        match self {
+            NumberStyle::UpperRoman => to_roman(number),
+            NumberStyle::LowerRoman => to_roman(number).to_lowercase(),
+            NumberStyle::UpperLatin => to_latin(number),
+            NumberStyle::LowerLatin => to_latin(number).to_lowercase(),
+            NumberStyle::Decimal | NumberStyle::Unknown(_) => number.to_string(),
+        }
+    }
+}
+
+fn to_roman(number: i32) -> String {
+    const NUMERALS: [(i32, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+
+    // Roman numerals can't be zero or negative (or, in practice, very large)
+    if !(1..4000).contains(&number) {
+        return number.to_string();
+    }
+
+    let mut remaining = number;
+    let mut result = String::new();
+    for (value, numeral) in NUMERALS {
+        while remaining >= value {
+            result.push_str(numeral);
+            remaining -= value;
+        }
+    }
+
+    result
+}
+
+/// Formats `number` like a spreadsheet column: A-Z, then AA-AZ, and so on.
+fn to_latin(number: i32) -> String {
+    if number < 1 {
+        return number.to_string();
+    }
+
+    let mut remaining = number as u32;
+    let mut result = Vec::new();
+    while remaining > 0 {
+        remaining -= 1;
+        result.push(char::from(b'A' + (remaining % 26) as u8));
+        remaining /= 26;
+    }
+
+    result.iter().rev().collect()
+}
+
+pub(crate) fn parse_list(
+    list_id: ExGuid,
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<List> {
+    let object = diagnostics
+        .get_object(&space, list_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("rich text content is missing".into()))?;
     let data = number_list_node::parse(&object)?;
 
@@ -105,3 +308,57 @@ pub(crate) fn parse_list(list_id: ExGuid, space: ObjectSpaceRef) -> Result<List>
 
     Ok(list)
 }
+
+#[cfg(test)]
+mod test {
+    use super::{ListFormat, ListFormatPart, NumberStyle};
+
+    #[test]
+    fn should_parse_multi_level_format() {
+        let format: Vec<char> = "\u{fffd}\u{0}.\u{fffd}\u{4}.\u{fffd}\u{2})"
+            .chars()
+            .collect();
+        let format = ListFormat::from_chars(&format);
+
+        assert!(format.is_numbered());
+        assert_eq!(format.parent_levels(), 2);
+        assert_eq!(format.number_style(), Some(NumberStyle::LowerRoman));
+        assert_eq!(
+            format.parts(),
+            [
+                ListFormatPart::Number {
+                    level: 2,
+                    style: NumberStyle::Decimal
+                },
+                ListFormatPart::Text(".".into()),
+                ListFormatPart::Number {
+                    level: 1,
+                    style: NumberStyle::LowerLatin
+                },
+                ListFormatPart::Text(".".into()),
+                ListFormatPart::Number {
+                    level: 0,
+                    style: NumberStyle::LowerRoman
+                },
+                ListFormatPart::Text(")".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_parse_bullet_format() {
+        let format = ListFormat::from_chars(&['\u{2022}']);
+
+        assert!(!format.is_numbered());
+        assert_eq!(format.parts(), [ListFormatPart::Text("\u{2022}".into())]);
+    }
+
+    #[test]
+    fn should_format_numbers() {
+        assert_eq!(NumberStyle::UpperRoman.format(1994), "MCMXCIV");
+        assert_eq!(NumberStyle::LowerRoman.format(4), "iv");
+        assert_eq!(NumberStyle::LowerLatin.format(28), "ab");
+        assert_eq!(NumberStyle::UpperLatin.format(26), "Z");
+        assert_eq!(NumberStyle::Decimal.format(0), "0");
+    }
+}
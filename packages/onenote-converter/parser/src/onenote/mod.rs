@@ -1,12 +1,14 @@
 use std::rc::Rc;
 
 use crate::onenote::notebook::Notebook;
-use crate::onenote::section::{Section, SectionEntry, SectionGroup};
+use crate::onenote::section::{Section, SectionEntry, SectionFile, SectionGroup};
 use crate::onestore::{OneStore, OneStoreType, parse_onestore};
+use bytes::Bytes;
 use parser_utils::errors::{ErrorKind, Result};
-use parser_utils::{fs_driver, log, reader::Reader};
+use parser_utils::{FileHandle, fs_driver, log, reader::Reader};
 
 pub(crate) mod content;
+pub(crate) mod diagnostics;
 pub(crate) mod embedded_file;
 pub(crate) mod iframe;
 pub(crate) mod image;
@@ -14,22 +16,38 @@ pub(crate) mod ink;
 pub(crate) mod list;
 pub(crate) mod note_tag;
 pub(crate) mod notebook;
+pub(crate) mod ocr_text;
 pub(crate) mod outline;
 pub(crate) mod page;
 pub(crate) mod page_content;
 pub(crate) mod page_series;
+pub(crate) mod page_text;
+pub(crate) mod page_tree;
+pub(crate) mod printout;
 pub(crate) mod rich_text;
 pub(crate) mod section;
 pub(crate) mod table;
 pub(crate) mod text_region;
+pub(crate) mod unknown_content;
 
 /// The OneNote file parser.
-pub struct Parser;
+pub struct Parser {
+    include_recycle_bin: bool,
+}
 
 impl Parser {
     /// Create a new OneNote file parser.
     pub fn new() -> Parser {
-        Parser {}
+        Parser {
+            include_recycle_bin: false,
+        }
+    }
+
+    /// Whether to include the notebook's recycle bin (the `OneNote_RecycleBin` section
+    /// group) when parsing or listing notebooks. It is skipped by default.
+    pub fn with_recycle_bin(mut self, include_recycle_bin: bool) -> Parser {
+        self.include_recycle_bin = include_recycle_bin;
+        self
     }
 
     /// Parse a OneNote notebook.
@@ -38,6 +56,21 @@ impl Parser {
     /// table of contents of the notebook as well as all contained
     /// sections from the folder that the table of contents file is in.
     pub fn parse_notebook(&mut self, path: String) -> Result<Notebook> {
+        let entries = self
+            .list_notebook_sections(path)?
+            .iter()
+            .map(|file| self.parse_section_file(file))
+            .collect::<Result<_>>()?;
+
+        Ok(Notebook { entries })
+    }
+
+    /// List the sections of a OneNote notebook, without parsing them.
+    ///
+    /// The `path` argument must point to a `.onetoc2` file. Like [`Parser::parse_notebook`],
+    /// this includes the sections in section groups, but only the tables of contents are
+    /// parsed. This allows the sections to be parsed one at a time.
+    pub fn list_notebook_sections(&mut self, path: String) -> Result<Vec<SectionFile>> {
         log!("Parsing notebook: {:?}", path);
         let data = fs_driver().open_file(&path)?;
         let store = parse_onestore(&mut Reader::try_from(data)?)?;
@@ -47,22 +80,21 @@ impl Parser {
         }
 
         let base_dir = fs_driver().get_dir_name(&path);
-        let sections = notebook::parse_toc(store.data_root())?
+        let include_recycle_bin = self.include_recycle_bin;
+        notebook::parse_toc(store.data_root())?
             .iter()
             .map(|name| fs_driver().join(&base_dir, name))
-            .filter(|p| !p.contains("OneNote_RecycleBin"))
+            .filter(|p| include_recycle_bin || !p.contains("OneNote_RecycleBin"))
             .filter(|p| fs_driver().exists(p).unwrap_or(false))
             .map(|p| {
                 let is_dir = fs_driver().is_directory(&p)?;
                 if !is_dir {
-                    self.parse_section(&p).map(SectionEntry::Section)
+                    Ok(SectionFile::Section(p))
                 } else {
-                    self.parse_section_group(p).map(SectionEntry::SectionGroup)
+                    self.list_section_group(p)
                 }
             })
-            .collect::<Result<_>>()?;
-
-        Ok(Notebook { entries: sections })
+            .collect()
     }
 
     /// Parse a OneNote section file.
@@ -72,7 +104,7 @@ impl Parser {
     pub fn parse_section(&mut self, path: &str) -> Result<Section> {
         log!("Parsing section: {:?}", path);
         let file = fs_driver().open_file(path)?;
-        self.parse_section_from_reader(Reader::try_from(file)?, path)
+        self.parse_section_from_file(file, path)
     }
 
     /// Parses low-level OneStore data. Exported for debugging purposes.
@@ -85,10 +117,34 @@ impl Parser {
     /// Parse a OneNote section file from a byte array.
     /// The [path] is used to provide debugging information and determine
     /// the name of the section file.
+    ///
+    /// The section can outlive `data`, so images and embedded files copy their data out of
+    /// it. Use [`Parser::parse_section_from_bytes`] to share the buffer instead.
     pub fn parse_section_from_data(&mut self, data: &[u8], path: &str) -> Result<Section> {
         self.parse_section_from_reader(Reader::from(data), path)
     }
 
+    /// Parse a OneNote section file from a reference-counted buffer.
+    ///
+    /// Unlike [`Parser::parse_section_from_data`], images and embedded files share the
+    /// buffer rather than copying their data out of it.
+    pub fn parse_section_from_bytes(&mut self, data: Bytes, path: &str) -> Result<Section> {
+        self.parse_section_from_reader(Reader::from(data), path)
+    }
+
+    /// Parse a OneNote section file from an open file.
+    ///
+    /// The section's data is read from the file on demand, so the file must not be changed
+    /// or removed while the section is in use. The [path] is used to provide debugging
+    /// information and determine the name of the section file.
+    pub fn parse_section_from_file(
+        &mut self,
+        file: Box<dyn FileHandle>,
+        path: &str,
+    ) -> Result<Section> {
+        self.parse_section_from_reader(Reader::try_from(file)?, path)
+    }
+
     fn parse_section_from_reader(&mut self, mut reader: Reader, path: &str) -> Result<Section> {
         let store = parse_onestore(&mut reader)?;
 
@@ -105,7 +161,7 @@ impl Parser {
         section::parse_section(store, filename)
     }
 
-    fn parse_section_group(&mut self, path: String) -> Result<SectionGroup> {
+    fn list_section_group(&mut self, path: String) -> Result<SectionFile> {
         let display_name = fs_driver()
             .get_file_name(path.as_str())
             .expect("file without file name");
@@ -114,9 +170,11 @@ impl Parser {
             for entry in entries {
                 let ext = fs_driver().get_file_extension(&entry);
                 if ext == ".onetoc2" {
-                    return self.parse_notebook(entry).map(|group| SectionGroup {
-                        display_name,
-                        entries: group.entries,
+                    return self.list_notebook_sections(entry).map(|entries| {
+                        SectionFile::SectionGroup {
+                            display_name,
+                            entries,
+                        }
                     });
                 }
             }
@@ -124,6 +182,26 @@ impl Parser {
 
         Err(ErrorKind::TocFileMissing { dir: path }.into())
     }
+
+    fn parse_section_file(&mut self, file: &SectionFile) -> Result<SectionEntry> {
+        match file {
+            SectionFile::Section(path) => self.parse_section(path).map(SectionEntry::Section),
+            SectionFile::SectionGroup {
+                display_name,
+                entries,
+            } => {
+                let entries = entries
+                    .iter()
+                    .map(|file| self.parse_section_file(file))
+                    .collect::<Result<_>>()?;
+
+                Ok(SectionEntry::SectionGroup(SectionGroup {
+                    display_name: display_name.clone(),
+                    entries,
+                }))
+            }
+        }
+    }
 }
 
 impl Default for Parser {
@@ -5,6 +5,7 @@ use crate::one::property::note_tag_shape::NoteTagShape;
 use crate::one::property::time::Time;
 use crate::one::property_set::note_tag_container::Data;
 use crate::one::property_set::note_tag_shared_definition_container;
+use crate::onenote::diagnostics::DiagnosticsCollector;
 use crate::onestore::object_space::ObjectSpaceRef;
 use crate::shared::exguid::ExGuid;
 use parser_utils::errors::{ErrorKind, Result};
@@ -16,12 +17,26 @@ use parser_utils::errors::{ErrorKind, Result};
 /// [\[MS-ONE\] 2.2.42]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/283e1611-05cb-4468-9be1-2879a3d1c17d
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct NoteTag {
+    created_at: Time,
     completed_at: Option<Time>,
+    due_at: Option<Time>,
     item_status: ActionItemStatus,
     definition: Option<NoteTagDefinition>,
 }
 
 impl NoteTag {
+    /// When the note tag was added.
+    pub fn created_at(&self) -> time::UtcDateTime {
+        self.created_at.into()
+    }
+
+    /// When the task is due.
+    ///
+    /// Only set for tasks that have a due date (usually tasks that were flagged in Outlook).
+    pub fn due_at(&self) -> Option<time::UtcDateTime> {
+        self.due_at.map(Into::into)
+    }
+
     /// When the task has been completed.
     ///
     /// Only set for task-like note tags.
@@ -91,16 +106,24 @@ impl NoteTagDefinition {
     }
 }
 
-pub(crate) fn parse_note_tags(note_tags: Vec<Data>, space: ObjectSpaceRef) -> Result<Vec<NoteTag>> {
+pub(crate) fn parse_note_tags(
+    note_tags: Vec<Data>,
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<Vec<NoteTag>> {
     note_tags
         .into_iter()
         .map(|data| {
             Ok(NoteTag {
+                created_at: data.created_at,
                 completed_at: data.completed_at,
+                due_at: data.due_at,
                 item_status: data.item_status,
                 definition: data
                     .definition
-                    .map(|definition_id| parse_note_tag_definition(definition_id, space.clone()))
+                    .map(|definition_id| {
+                        parse_note_tag_definition(definition_id, space.clone(), diagnostics)
+                    })
                     .transpose()?,
             })
         })
@@ -110,9 +133,10 @@ pub(crate) fn parse_note_tags(note_tags: Vec<Data>, space: ObjectSpaceRef) -> Re
 pub(crate) fn parse_note_tag_definition(
     definition_id: ExGuid,
     space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
 ) -> Result<NoteTagDefinition> {
-    let object = space
-        .get_object(definition_id)
+    let object = diagnostics
+        .get_object(&space, definition_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("note tag definition is missing".into()))?;
 
     let data = note_tag_shared_definition_container::parse(&object)?;
@@ -0,0 +1,80 @@
+use crate::onenote::image::Image;
+use crate::onenote::page::Page;
+use crate::onenote::page_text::{TextContent, visit_text_content};
+
+/// Text that OneNote recognised in an image (OCR).
+///
+/// See [`Section::iter_ocr_text`](crate::section::Section::iter_ocr_text).
+#[derive(Clone, Copy, Debug)]
+pub struct OcrText<'a> {
+    page: &'a Page,
+    image: &'a Image,
+    text: &'a str,
+}
+
+impl<'a> OcrText<'a> {
+    /// The page that contains the image.
+    pub fn page(&self) -> &'a Page {
+        self.page
+    }
+
+    /// The image that the text was recognised in.
+    pub fn image(&self) -> &'a Image {
+        self.image
+    }
+
+    /// The recognised text. Lines are separated by `\n` and often end with a space.
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// The BCP 47 language tag for the text (for example, `en-US`), if it's known.
+    pub fn language_tag(&self) -> Option<&'static str> {
+        self.image.text_language_tag()
+    }
+}
+
+/// Returns the text recognised in the page's images, in the order the images appear.
+/// Images without text are skipped.
+pub(crate) fn page_ocr_text(page: &Page) -> impl Iterator<Item = OcrText<'_>> {
+    page.images().into_iter().filter_map(move |image| {
+        let text = image.text().filter(|text| !text.trim().is_empty())?;
+        Some(OcrText { page, image, text })
+    })
+}
+
+/// Returns the images in the page's title and contents, including those in outlines, tables
+/// and printouts, in order.
+pub(crate) fn collect_images(page: &Page) -> Vec<&Image> {
+    let mut images = Vec::new();
+    visit_text_content(page, &mut |content| {
+        if let TextContent::Image(image) = content {
+            images.push(image);
+        }
+    });
+    images
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Parser;
+    use parser_utils::fs_driver;
+
+    #[test]
+    fn should_list_ocr_text_in_printouts() {
+        let data = fs_driver().read_file("../test-data/Printout.one").unwrap();
+        let section = Parser::new()
+            .parse_section_from_data(&data, "Printout.one")
+            .unwrap();
+
+        let texts: Vec<_> = section.iter_ocr_text().collect();
+        assert_eq!(texts.len(), 1);
+        assert_eq!(texts[0].text(), "Test \nTest! \nmaln ");
+        assert_eq!(texts[0].language_tag(), Some("en-US"));
+        assert!(texts[0].page().contents().iter().any(|content| {
+            content
+                .printout()
+                .is_some_and(|printout| std::ptr::eq(&printout.pages()[0], texts[0].image()))
+        }));
+    }
+}
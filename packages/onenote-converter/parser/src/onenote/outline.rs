@@ -1,6 +1,7 @@
 use crate::one::property::layout_alignment::LayoutAlignment;
 use crate::one::property_set::{PropertySetId, outline_element_node, outline_group, outline_node};
 use crate::onenote::content::{Content, parse_content};
+use crate::onenote::diagnostics::DiagnosticsCollector;
 use crate::onenote::list::{List, parse_list};
 use crate::onestore::object_space::ObjectSpaceRef;
 use crate::shared::exguid::ExGuid;
@@ -26,8 +27,13 @@ pub struct Outline {
     pub(crate) layout_reserved_width: Option<f32>,
     pub(crate) layout_minimum_outline_width: Option<f32>,
     pub(crate) is_layout_size_set_by_user: bool,
+    pub(crate) tight_layout: bool,
     pub(crate) offset_horizontal: Option<f32>,
     pub(crate) offset_vertical: Option<f32>,
+    pub(crate) layout_collision_priority: Option<u32>,
+
+    pub(crate) is_title_text: bool,
+    pub(crate) is_title_date: bool,
 
     pub(crate) items: Vec<OutlineItem>,
 }
@@ -129,6 +135,12 @@ impl Outline {
         self.is_layout_size_set_by_user
     }
 
+    /// Whether the outline is only as wide as its contents need, rather than as wide as its
+    /// [`layout_max_width()`](Self::layout_max_width()).
+    pub fn layout_tight_layout(&self) -> bool {
+        self.tight_layout
+    }
+
     /// The horizontal offset from the page origin in half-inch increments.
     ///
     /// See [\[MS-ONE\] 2.3.18].
@@ -146,6 +158,22 @@ impl Outline {
     pub fn offset_vertical(&self) -> Option<f32> {
         self.offset_vertical
     }
+
+    /// The outline's priority when OneNote moves page contents so that they don't overlap
+    /// (if present).
+    pub fn layout_collision_priority(&self) -> Option<u32> {
+        self.layout_collision_priority
+    }
+
+    /// Whether this outline contains the page title's text.
+    pub fn is_title_text(&self) -> bool {
+        self.is_title_text
+    }
+
+    /// Whether this outline contains the date (and time) shown below the page title.
+    pub fn is_title_date(&self) -> bool {
+        self.is_title_date
+    }
 }
 
 /// An entry in an outline list.
@@ -256,16 +284,20 @@ impl OutlineElement {
     }
 }
 
-pub(crate) fn parse_outline(outline_id: ExGuid, space: ObjectSpaceRef) -> Result<Outline> {
-    let outline_object = space
-        .get_object(outline_id)
+pub(crate) fn parse_outline(
+    outline_id: ExGuid,
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<Outline> {
+    let outline_object = diagnostics
+        .get_object(&space, outline_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("outline node is missing".into()))?;
     let data = outline_node::parse(&outline_object)?;
 
     let items = data
         .children
         .into_iter()
-        .map(|item_id| parse_outline_item(item_id, space.clone()))
+        .map(|item_id| parse_outline_item(item_id, space.clone(), diagnostics))
         .collect::<Result<_>>()?;
 
     let outline = Outline {
@@ -280,16 +312,24 @@ pub(crate) fn parse_outline(outline_id: ExGuid, space: ObjectSpaceRef) -> Result
         layout_reserved_width: data.layout_reserved_width,
         layout_minimum_outline_width: data.layout_minimum_outline_width,
         is_layout_size_set_by_user: data.is_layout_size_set_by_user,
+        tight_layout: data.tight_layout,
         offset_horizontal: data.offset_from_parent_horiz,
         offset_vertical: data.offset_from_parent_vert,
+        layout_collision_priority: data.layout_collision_priority,
+        is_title_text: data.is_title_text,
+        is_title_date: data.is_title_date,
     };
 
     Ok(outline)
 }
 
-fn parse_outline_item(item_id: ExGuid, space: ObjectSpaceRef) -> Result<OutlineItem> {
-    let content_type = space
-        .get_object(item_id)
+fn parse_outline_item(
+    item_id: ExGuid,
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<OutlineItem> {
+    let content_type = diagnostics
+        .get_object(&space, item_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("outline item is missing".into()))?
         .id();
     let id = PropertySetId::from_jcid(content_type).ok_or_else(|| {
@@ -299,9 +339,11 @@ fn parse_outline_item(item_id: ExGuid, space: ObjectSpaceRef) -> Result<OutlineI
     })?;
 
     let item = match id {
-        PropertySetId::OutlineGroup => OutlineItem::Group(parse_outline_group(item_id, space)?),
+        PropertySetId::OutlineGroup => {
+            OutlineItem::Group(parse_outline_group(item_id, space, diagnostics)?)
+        }
         PropertySetId::OutlineElementNode => {
-            OutlineItem::Element(parse_outline_element(item_id, space)?)
+            OutlineItem::Element(parse_outline_element(item_id, space, diagnostics)?)
         }
         _ => {
             return Err(ErrorKind::MalformedOneNoteData(
@@ -314,16 +356,20 @@ fn parse_outline_item(item_id: ExGuid, space: ObjectSpaceRef) -> Result<OutlineI
     Ok(item)
 }
 
-fn parse_outline_group(group_id: ExGuid, space: ObjectSpaceRef) -> Result<OutlineGroup> {
-    let group_object = space
-        .get_object(group_id)
+fn parse_outline_group(
+    group_id: ExGuid,
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<OutlineGroup> {
+    let group_object = diagnostics
+        .get_object(&space, group_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("outline group is missing".into()))?;
     let data = outline_group::parse(&group_object)?;
 
     let outlines = data
         .children
         .into_iter()
-        .map(|item_id| parse_outline_item(item_id, space.clone()))
+        .map(|item_id| parse_outline_item(item_id, space.clone(), diagnostics))
         .collect::<Result<_>>()?;
 
     let group = OutlineGroup {
@@ -337,28 +383,29 @@ fn parse_outline_group(group_id: ExGuid, space: ObjectSpaceRef) -> Result<Outlin
 pub(crate) fn parse_outline_element(
     element_id: ExGuid,
     space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
 ) -> Result<OutlineElement> {
-    let element_object = space
-        .get_object(element_id)
+    let element_object = diagnostics
+        .get_object(&space, element_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("outline element is missing".into()))?;
     let data = outline_element_node::parse(&element_object)?;
 
     let children = data
         .children
         .into_iter()
-        .map(|item_id| parse_outline_item(item_id, space.clone()))
+        .map(|item_id| parse_outline_item(item_id, space.clone(), diagnostics))
         .collect::<Result<_>>()?;
 
     let contents = data
         .contents
         .into_iter()
-        .map(|content_id| parse_content(content_id, space.clone()))
+        .map(|content_id| parse_content(content_id, space.clone(), diagnostics))
         .collect::<Result<_>>()?;
 
     let list_contents = data
         .list_contents
         .into_iter()
-        .map(|list_id| parse_list(list_id, space.clone()))
+        .map(|list_id| parse_list(list_id, space.clone(), diagnostics))
         .collect::<Result<_>>()?;
 
     let element = OutlineElement {
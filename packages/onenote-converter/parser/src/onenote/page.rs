@@ -1,7 +1,14 @@
 use crate::one::property::layout_alignment::LayoutAlignment;
+use crate::one::property::rule_lines::RuleLines;
 use crate::one::property_set::{page_manifest_node, page_metadata, page_node, title_node};
+use crate::onenote::diagnostics::{DiagnosticsCollector, PageDiagnostics};
+use crate::onenote::image::Image;
+use crate::onenote::ocr_text::{OcrText, collect_images, page_ocr_text};
 use crate::onenote::outline::{Outline, parse_outline};
 use crate::onenote::page_content::{PageContent, parse_page_content};
+use crate::onenote::page_text::{TextChunk, page_text_chunks};
+use crate::onenote::printout::group_printouts;
+use crate::onenote::rich_text::RichText;
 use crate::onestore::object_space::ObjectSpaceRef;
 use crate::shared::exguid::ExGuid;
 use crate::shared::guid::Guid;
@@ -9,6 +16,7 @@ use parser_utils::errors::{ErrorKind, Result};
 use parser_utils::log::set_current_page;
 use parser_utils::log_warn;
 use time::macros::utc_datetime;
+use time::{Date, Month, Time};
 
 /// A page.
 ///
@@ -20,12 +28,16 @@ use time::macros::utc_datetime;
 pub struct Page {
     entity_id: Guid,
     title: Option<Title>,
+    cached_title: Option<String>,
     level: i32,
     updated_at: Option<time::UtcDateTime>,
     created_at: time::UtcDateTime,
     author: Option<String>,
     height: Option<f32>,
+    resolve_child_collisions: bool,
+    background_style: PageBackgroundStyle,
     contents: Vec<PageContent>,
+    diagnostics: PageDiagnostics,
 }
 
 impl Page {
@@ -71,20 +83,62 @@ impl Page {
         self.height
     }
 
+    /// Whether OneNote moves the page's contents so that they don't overlap, for example
+    /// when an outline above another outline grows.
+    pub fn layout_resolve_child_collisions(&self) -> bool {
+        self.resolve_child_collisions
+    }
+
+    /// The rule lines or grid drawn on the page.
+    pub fn background_style(&self) -> &PageBackgroundStyle {
+        &self.background_style
+    }
+
     /// The page contents.
     pub fn contents(&self) -> &[PageContent] {
         &self.contents
     }
 
+    /// The images on the page, including those in outlines, tables and printouts, in the
+    /// order they appear.
+    pub fn images(&self) -> Vec<&Image> {
+        collect_images(self)
+    }
+
+    /// The text that OneNote recognised in the page's images (OCR).
+    pub fn ocr_text(&self) -> impl Iterator<Item = OcrText<'_>> {
+        page_ocr_text(self)
+    }
+
+    /// The page's paragraphs and the text recognised in its images, in order, with the ID
+    /// of the object that each is from. Empty paragraphs are left out.
+    pub fn text_chunks(&self) -> Vec<TextChunk<'_>> {
+        page_text_chunks(self)
+    }
+
+    /// The page's text, without formatting. Paragraphs are separated by `\n`.
+    pub fn plain_text(&self) -> String {
+        self.text_chunks()
+            .iter()
+            .map(|chunk| chunk.text())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Content of the page that the parser couldn't interpret.
+    pub fn diagnostics(&self) -> &PageDiagnostics {
+        &self.diagnostics
+    }
+
     /// The page's title text.
     ///
-    /// This is calculated using a heuristic similar to the one OneNote uses.
+    /// This is calculated using a heuristic similar to the one OneNote uses: the title's
+    /// text, or the first paragraph of the page, or the title OneNote last cached for the
+    /// page.
     pub fn title_text(&self) -> Option<String> {
         self.title
             .as_ref()
-            .and_then(|title| title.contents.first())
-            .and_then(Self::outline_text)
-            .map(|t| Self::remove_hyperlink(t.to_owned()))
+            .and_then(Title::text)
             .or_else(|| {
                 self.contents
                     .iter()
@@ -95,6 +149,13 @@ impl Page {
                     })
                     .next()
             })
+            .or_else(|| self.cached_title.clone())
+    }
+
+    /// The title OneNote stored for the page when it was last saved. This is used in
+    /// OneNote's page list, and may be out of date.
+    pub fn cached_title(&self) -> Option<&str> {
+        self.cached_title.as_deref()
     }
 
     /// The page's GUID. May be referenced by internal links.
@@ -137,6 +198,38 @@ impl Page {
     }
 }
 
+/// The rule lines or grid drawn on a page.
+///
+/// Ruled pages only have horizontal lines, while grid pages have both horizontal and
+/// vertical lines.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PageBackgroundStyle {
+    horizontal_lines: Option<RuleLines>,
+    vertical_lines: Option<RuleLines>,
+}
+
+impl PageBackgroundStyle {
+    /// The horizontal rule lines.
+    pub fn horizontal_lines(&self) -> Option<&RuleLines> {
+        self.horizontal_lines.as_ref()
+    }
+
+    /// The vertical rule lines.
+    pub fn vertical_lines(&self) -> Option<&RuleLines> {
+        self.vertical_lines.as_ref()
+    }
+
+    /// Whether the page is a grid page.
+    pub fn is_grid(&self) -> bool {
+        self.horizontal_lines.is_some() && self.vertical_lines.is_some()
+    }
+
+    /// Whether the page is plain (has no rule lines).
+    pub fn is_plain(&self) -> bool {
+        self.horizontal_lines.is_none() && self.vertical_lines.is_none()
+    }
+}
+
 /// A page title.
 ///
 /// See [\[MS-ONE\] 2.2.29].
@@ -150,6 +243,7 @@ pub struct Title {
     pub(crate) offset_vertical: f32,
     pub(crate) layout_alignment_in_parent: Option<LayoutAlignment>,
     pub(crate) layout_alignment_self: Option<LayoutAlignment>,
+    pub(crate) layout_collision_priority: Option<u32>,
 }
 
 impl Title {
@@ -193,17 +287,115 @@ impl Title {
     pub fn layout_alignment_self(&self) -> Option<LayoutAlignment> {
         self.layout_alignment_self
     }
+
+    /// The title's priority when OneNote moves page contents so that they don't overlap
+    /// (if present). OneNote gives the title a priority of 0.
+    pub fn layout_collision_priority(&self) -> Option<u32> {
+        self.layout_collision_priority
+    }
+
+    /// The title's text, without the date and time.
+    ///
+    /// Uses the paragraphs that OneNote marks as the title text, or the title's first
+    /// other paragraph if there are none.
+    pub fn text(&self) -> Option<String> {
+        let marked: Vec<&str> = self
+            .paragraphs()
+            .filter(|(outline, text)| {
+                text.is_title_text
+                    || (outline.is_title_text && !text.is_title_date && !text.is_title_time)
+            })
+            .map(|(_, text)| text.text())
+            .filter(|text| !text.is_empty())
+            .collect();
+
+        let text = if marked.is_empty() {
+            self.paragraphs()
+                .filter(|(_, text)| !text.is_title_date && !text.is_title_time)
+                .map(|(_, text)| text.text())
+                .find(|text| !text.is_empty())?
+                .to_string()
+        } else {
+            marked.join(" ")
+        };
+
+        Some(Page::remove_hyperlink(text))
+    }
+
+    /// The date shown below the title, usually the date the page was created.
+    pub fn date(&self) -> Option<TitleField<Date>> {
+        let (_, text) = self.paragraphs().find(|(outline, text)| {
+            text.is_title_date || (outline.is_title_date && !text.is_title_time)
+        })?;
+
+        TitleField::new(text.text(), parse_title_date)
+    }
+
+    /// The time shown below the title, usually the time the page was created.
+    pub fn time(&self) -> Option<TitleField<Time>> {
+        let (_, text) = self.paragraphs().find(|(_, text)| text.is_title_time)?;
+
+        TitleField::new(text.text(), parse_title_time)
+    }
+
+    fn paragraphs(&self) -> impl Iterator<Item = (&Outline, &RichText)> {
+        self.contents.iter().flat_map(|outline| {
+            outline
+                .items
+                .iter()
+                .filter_map(|item| item.element())
+                .flat_map(|element| element.contents.iter())
+                .filter_map(|content| content.rich_text())
+                .map(move |text| (outline, text))
+        })
+    }
+}
+
+/// The date or time shown below a page's title.
+///
+/// OneNote stores these as text, formatted for the user's locale, so the value is only
+/// available if the text could be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TitleField<T> {
+    text: String,
+    value: Option<T>,
+}
+
+impl<T: Copy> TitleField<T> {
+    fn new(text: &str, parse: fn(&str) -> Option<T>) -> Option<Self> {
+        let text = text.trim();
+        if text.is_empty() {
+            return None;
+        }
+
+        Some(TitleField {
+            text: text.to_string(),
+            value: parse(text),
+        })
+    }
+
+    /// The text, as shown on the page.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The parsed value, if the text could be parsed.
+    pub fn value(&self) -> Option<T> {
+        self.value
+    }
 }
 
 pub(crate) fn parse_page(page_space: ObjectSpaceRef) -> Result<Page> {
-    let metadata = parse_metadata(page_space.clone())?;
-    let manifest = parse_manifest(page_space.clone())?;
+    let diagnostics = DiagnosticsCollector::new();
+
+    let metadata = parse_metadata(page_space.clone(), &diagnostics)?;
+    let manifest = parse_manifest(page_space.clone(), &diagnostics)?;
 
-    let data = parse_data(manifest, page_space.clone())?;
+    let data = parse_data(manifest, page_space.clone(), &diagnostics)?;
 
     let title = data
         .title
-        .map(|id| parse_title(id, page_space.clone()))
+        .map(|id| parse_title(id, page_space.clone(), &diagnostics))
         .transpose()?;
 
     let page_title = extract_text_from_title(&title);
@@ -214,8 +406,14 @@ pub(crate) fn parse_page(page_space: ObjectSpaceRef) -> Result<Page> {
     let contents = data
         .content
         .into_iter()
-        .map(|content_id| parse_page_content(content_id, page_space.clone()))
+        .map(|content_id| parse_page_content(content_id, page_space.clone(), &diagnostics))
         .collect::<Result<_>>()?;
+    let contents = group_printouts(contents)?;
+
+    let cached_title = metadata
+        .cached_title
+        .or(data.cached_title)
+        .filter(|title| !title.is_empty());
 
     Ok(Page {
         entity_id: metadata.entity_guid,
@@ -227,22 +425,33 @@ pub(crate) fn parse_page(page_space: ObjectSpaceRef) -> Result<Page> {
             utc_datetime!(1970-01-01 0:00)
         }),
         title,
+        cached_title,
         level,
         author: data.author.map(|author| author.into_value()),
         height: data.page_height,
+        resolve_child_collisions: data.resolve_child_collisions,
+        background_style: PageBackgroundStyle {
+            horizontal_lines: data.rule_lines_horizontal,
+            vertical_lines: data.rule_lines_vertical,
+        },
         contents,
+        diagnostics: diagnostics.finish(),
     })
 }
 
-fn parse_title(title_id: ExGuid, space: ObjectSpaceRef) -> Result<Title> {
-    let title_object = space
-        .get_object(title_id)
+fn parse_title(
+    title_id: ExGuid,
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<Title> {
+    let title_object = diagnostics
+        .get_object(&space, title_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("title object is missing".into()))?;
     let title = title_node::parse(&title_object)?;
     let contents = title
         .children
         .into_iter()
-        .map(|outline_id| parse_outline(outline_id, space.clone()))
+        .map(|outline_id| parse_outline(outline_id, space.clone(), diagnostics))
         .collect::<Result<_>>()?;
 
     Ok(Title {
@@ -251,38 +460,46 @@ fn parse_title(title_id: ExGuid, space: ObjectSpaceRef) -> Result<Title> {
         offset_vertical: title.offset_vertical,
         layout_alignment_in_parent: title.layout_alignment_in_parent,
         layout_alignment_self: title.layout_alignment_self,
+        layout_collision_priority: title.layout_collision_priority,
     })
 }
 
 fn parse_data(
     manifest: page_manifest_node::Data,
     space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
 ) -> Result<page_node::Data> {
     let page_id = manifest.page;
-    let page_object = space
-        .get_object(page_id)
+    let page_object = diagnostics
+        .get_object(&space, page_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("page object is missing".into()))?;
 
     page_node::parse(&page_object)
 }
 
-fn parse_manifest(space: ObjectSpaceRef) -> Result<page_manifest_node::Data> {
+fn parse_manifest(
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<page_manifest_node::Data> {
     let page_manifest_id = space
         .content_root()
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("page content id is missing".into()))?;
-    let page_manifest_object = space
-        .get_object(page_manifest_id)
+    let page_manifest_object = diagnostics
+        .get_object(&space, page_manifest_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("page object is missing".into()))?;
 
     page_manifest_node::parse(&page_manifest_object)
 }
 
-fn parse_metadata(space: ObjectSpaceRef) -> Result<page_metadata::Data> {
+fn parse_metadata(
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<page_metadata::Data> {
     let metadata_id = space
         .metadata_root()
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("page metadata id is missing".into()))?;
-    let metadata_object = space
-        .get_object(metadata_id)
+    let metadata_object = diagnostics
+        .get_object(&space, metadata_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("page metadata object is missing".into()))?;
 
     page_metadata::parse(&metadata_object)
@@ -305,3 +522,169 @@ fn extract_text_from_title(title: &Option<Title>) -> String {
     }
     result
 }
+
+/// Parses a title date, such as "Monday, 3 March 2025", "March 3, 2025" or "2025-03-03".
+///
+/// Dates that only use numbers are only parsed if it's clear which is the day and which
+/// is the month.
+fn parse_title_date(text: &str) -> Option<Date> {
+    let tokens: Vec<&str> = text
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .collect();
+    let numbers: Vec<&str> = tokens
+        .iter()
+        .filter_map(|token| {
+            let number = token.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+            let suffix = &token[number.len()..];
+            let is_number = !number.is_empty() && number.chars().all(|c| c.is_ascii_digit());
+            let is_ordinal = matches!(suffix, "" | "st" | "nd" | "rd" | "th");
+            (is_number && is_ordinal).then_some(number)
+        })
+        .collect();
+
+    let (year, month, day) = if let Some(month) = tokens.iter().find_map(|t| month_from_name(t)) {
+        let year = numbers.iter().find(|n| n.len() == 4)?;
+        let day = numbers.iter().find(|n| n.len() <= 2)?;
+        (year.parse().ok()?, month, day.parse().ok()?)
+    } else {
+        let [first, second, third] = numbers[..] else {
+            return None;
+        };
+        if first.len() == 4 {
+            (
+                first.parse().ok()?,
+                second.parse().ok()?,
+                third.parse().ok()?,
+            )
+        } else if third.len() == 4 {
+            let (a, b): (u8, u8) = (first.parse().ok()?, second.parse().ok()?);
+            let year = third.parse().ok()?;
+            match (a > 12, b > 12) {
+                (true, false) => (year, b, a),
+                (false, true) => (year, a, b),
+                _ if a == b => (year, a, b),
+                _ => return None,
+            }
+        } else {
+            return None;
+        }
+    };
+
+    Date::from_calendar_date(year, Month::try_from(month).ok()?, day).ok()
+}
+
+fn month_from_name(name: &str) -> Option<u8> {
+    const MONTHS: [&str; 12] = [
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ];
+
+    let name = name.to_ascii_lowercase();
+    if name.len() < 3 {
+        return None;
+    }
+
+    MONTHS
+        .iter()
+        .position(|month| month.starts_with(&name))
+        .map(|index| index as u8 + 1)
+}
+
+/// Parses a title time, such as "14:30", "2:30 PM" or "2:30:15 p.m.".
+fn parse_title_time(text: &str) -> Option<Time> {
+    let text = text.to_ascii_lowercase().replace('.', "");
+    let clock = text
+        .split_whitespace()
+        .map(|token| token.trim_end_matches(|c: char| !c.is_ascii_digit()))
+        .find(|token| token.contains(':'))?;
+
+    let parts = clock
+        .split(':')
+        .map(|part| part.parse::<u8>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    let (mut hour, minute, second) = match parts[..] {
+        [hour, minute] => (hour, minute, 0),
+        [hour, minute, second] => (hour, minute, second),
+        _ => return None,
+    };
+
+    if text.contains("pm") && hour < 12 {
+        hour += 12;
+    } else if text.contains("am") && hour == 12 {
+        hour = 0;
+    }
+
+    Time::from_hms(hour, minute, second).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_title_date, parse_title_time};
+    use crate::Parser;
+    use parser_utils::fs_driver;
+    use time::macros::{date, time};
+
+    #[test]
+    fn should_parse_title_dates() {
+        let expected = Some(date!(2025 - 03 - 04));
+        assert_eq!(parse_title_date("Tuesday, 4 March 2025"), expected);
+        assert_eq!(parse_title_date("Tuesday, March 4th, 2025"), expected);
+        assert_eq!(parse_title_date("Tue 4 Mar 2025"), expected);
+        assert_eq!(parse_title_date("2025-03-04"), expected);
+        assert_eq!(parse_title_date("2025年3月4日"), expected);
+        assert_eq!(parse_title_date("3/4/2025"), None);
+        assert_eq!(parse_title_date("3/24/2025"), Some(date!(2025 - 03 - 24)));
+        assert_eq!(parse_title_date("24.03.2025"), Some(date!(2025 - 03 - 24)));
+        assert_eq!(parse_title_date("Dienstag, 4. März 2025"), None);
+        assert_eq!(parse_title_date("31 February 2025"), None);
+    }
+
+    #[test]
+    fn should_parse_title_times() {
+        assert_eq!(parse_title_time("14:30"), Some(time!(14:30)));
+        assert_eq!(parse_title_time("2:30 PM"), Some(time!(14:30)));
+        assert_eq!(parse_title_time("12:05:10 a.m."), Some(time!(0:05:10)));
+        assert_eq!(parse_title_time("10:30am"), Some(time!(10:30)));
+        assert_eq!(parse_title_time("25:00"), None);
+        assert_eq!(parse_title_time("noon"), None);
+    }
+
+    #[test]
+    fn should_parse_grid_page_background() {
+        let data = fs_driver().read_file("../test-data/Grid page.one").unwrap();
+        let section = Parser::new()
+            .parse_section_from_data(&data, "Grid page.one")
+            .unwrap();
+        let page = &section.page_series()[0].pages()[0];
+
+        let style = page.background_style();
+        assert!(style.is_grid());
+
+        let horizontal = style.horizontal_lines().unwrap();
+        assert!((horizontal.spacing() - 1.0 / 3.0).abs() < 0.001);
+        assert!(horizontal.color().is_some());
+        assert_eq!(style.vertical_lines(), style.horizontal_lines());
+    }
+
+    #[test]
+    fn should_parse_plain_page_background() {
+        let data = fs_driver().read_file("../test-data/Printout.one").unwrap();
+        let section = Parser::new()
+            .parse_section_from_data(&data, "Printout.one")
+            .unwrap();
+        let page = &section.page_series()[0].pages()[0];
+
+        assert!(page.background_style().is_plain());
+    }
+}
@@ -1,8 +1,11 @@
 use crate::one::property_set::PropertySetId;
+use crate::onenote::diagnostics::DiagnosticsCollector;
 use crate::onenote::embedded_file::{EmbeddedFile, parse_embedded_file};
 use crate::onenote::image::{Image, parse_image};
 use crate::onenote::ink::{Ink, parse_ink};
 use crate::onenote::outline::{Outline, parse_outline};
+use crate::onenote::printout::Printout;
+use crate::onenote::unknown_content::{UnknownContent, parse_unknown_content};
 use crate::onestore::object_space::ObjectSpaceRef;
 use crate::shared::exguid::ExGuid;
 use parser_utils::errors::{ErrorKind, Result};
@@ -15,7 +18,8 @@ pub enum PageContent {
     Image(Image),
     EmbeddedFile(EmbeddedFile),
     Ink(Ink),
-    Unknown,
+    Printout(Printout),
+    Unknown(UnknownContent),
 }
 
 impl PageContent {
@@ -54,27 +58,55 @@ impl PageContent {
             None
         }
     }
+
+    /// Return the printout data if it's a file printout.
+    pub fn printout(&self) -> Option<&Printout> {
+        if let PageContent::Printout(printout) = self {
+            Some(printout)
+        } else {
+            None
+        }
+    }
+
+    /// Return the object's type, position and size if it's content of an unsupported type.
+    pub fn unknown(&self) -> Option<&UnknownContent> {
+        if let PageContent::Unknown(content) = self {
+            Some(content)
+        } else {
+            None
+        }
+    }
 }
 
-pub(crate) fn parse_page_content(content_id: ExGuid, space: ObjectSpaceRef) -> Result<PageContent> {
-    let content_type = space
-        .get_object(content_id)
-        .ok_or_else(|| ErrorKind::MalformedOneNoteData("page content is missing".into()))?
-        .id();
-    let id = PropertySetId::from_jcid(content_type).ok_or_else(|| {
-        ErrorKind::MalformedOneNoteData(
-            format!("invalid property set id: {:?}", content_type).into(),
-        )
-    })?;
+pub(crate) fn parse_page_content(
+    content_id: ExGuid,
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<PageContent> {
+    let object = diagnostics
+        .get_object(&space, content_id)
+        .ok_or_else(|| ErrorKind::MalformedOneNoteData("page content is missing".into()))?;
+    let content_type = object.id();
+    let Some(id) = PropertySetId::from_jcid(content_type) else {
+        diagnostics.report_unknown_object(content_id, content_type);
+        return Ok(PageContent::Unknown(parse_unknown_content(&object)));
+    };
 
     let content = match id {
-        PropertySetId::ImageNode => PageContent::Image(parse_image(content_id, space)?),
+        PropertySetId::ImageNode => {
+            PageContent::Image(parse_image(content_id, space, diagnostics)?)
+        }
         PropertySetId::EmbeddedFileNode => {
-            PageContent::EmbeddedFile(parse_embedded_file(content_id, space)?)
+            PageContent::EmbeddedFile(parse_embedded_file(content_id, space, diagnostics)?)
+        }
+        PropertySetId::OutlineNode => {
+            PageContent::Outline(parse_outline(content_id, space, diagnostics)?)
+        }
+        PropertySetId::InkContainer => PageContent::Ink(parse_ink(content_id, space, diagnostics)?),
+        _ => {
+            diagnostics.report_unknown_object(content_id, content_type);
+            PageContent::Unknown(parse_unknown_content(&object))
         }
-        PropertySetId::OutlineNode => PageContent::Outline(parse_outline(content_id, space)?),
-        PropertySetId::InkContainer => PageContent::Ink(parse_ink(content_id, space)?),
-        _ => PageContent::Unknown,
     };
 
     Ok(content)
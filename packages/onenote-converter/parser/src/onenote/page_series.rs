@@ -2,6 +2,7 @@ use std::rc::Rc;
 
 use crate::one::property_set::page_series_node;
 use crate::onenote::page::{Page, parse_page};
+use crate::onenote::page_tree::{PageNode, build_page_tree};
 use crate::onestore::OneStore;
 use crate::onestore::object_space::ObjectSpaceRef;
 use crate::shared::exguid::ExGuid;
@@ -26,6 +27,12 @@ impl PageSeries {
         &self.pages
     }
 
+    /// The pages contained in this page series, with subpages nested below their parent
+    /// pages.
+    pub fn page_tree(&self) -> Vec<PageNode<'_>> {
+        build_page_tree(&self.pages)
+    }
+
     /// Whether any pages failed to import
     pub fn has_errors(&self) -> bool {
         !self.errors.is_empty()
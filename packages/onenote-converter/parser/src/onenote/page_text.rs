@@ -0,0 +1,226 @@
+use crate::onenote::content::Content;
+use crate::onenote::image::Image;
+use crate::onenote::outline::{OutlineElement, OutlineItem};
+use crate::onenote::page::Page;
+use crate::onenote::page_content::PageContent;
+use crate::onenote::rich_text::RichText;
+use crate::shared::exguid::ExGuid;
+
+/// A paragraph of a page's text, or the text that OneNote recognised in one of its images.
+///
+/// See [`Page::text_chunks`] and [`Section::iter_text`](crate::section::Section::iter_text).
+#[derive(Clone, Debug)]
+pub struct TextChunk<'a> {
+    page: &'a Page,
+    object_id: ExGuid,
+    source: TextSource,
+    text: String,
+}
+
+impl<'a> TextChunk<'a> {
+    /// The page that contains the text.
+    pub fn page(&self) -> &'a Page {
+        self.page
+    }
+
+    /// The ID of the paragraph or image that contains the text, in `{GUID},value` form.
+    pub fn object_id(&self) -> String {
+        format!("{},{}", self.object_id.guid, self.object_id.value)
+    }
+
+    /// Where the text is from.
+    pub fn source(&self) -> TextSource {
+        self.source
+    }
+
+    /// The text. Line breaks within the paragraph or image are `\n`.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Where a [`TextChunk`] is from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextSource {
+    /// A rich text paragraph.
+    Paragraph,
+
+    /// The text that OneNote recognised in an image (OCR).
+    OcrText,
+}
+
+/// An item of page content that can contain text. See [`visit_text_content`].
+#[derive(Clone, Copy, Debug)]
+pub enum TextContent<'a> {
+    /// A rich text paragraph.
+    RichText(&'a RichText),
+
+    /// An image, which can have OCR text.
+    Image(&'a Image),
+}
+
+/// Calls `visit` with the paragraphs and images in the page's title and contents, including
+/// those in tables, lists and printouts, in order.
+pub fn visit_text_content<'a>(page: &'a Page, visit: &mut impl FnMut(TextContent<'a>)) {
+    let title_outlines = page
+        .title()
+        .map(|title| title.contents())
+        .unwrap_or_default();
+    for outline in title_outlines {
+        visit_items(outline.items(), visit);
+    }
+
+    for content in page.contents() {
+        match content {
+            PageContent::Outline(outline) => visit_items(outline.items(), visit),
+            PageContent::Image(image) => visit(TextContent::Image(image)),
+            PageContent::Printout(printout) => {
+                for image in printout.pages() {
+                    visit(TextContent::Image(image));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn visit_items<'a>(items: &'a [OutlineItem], visit: &mut impl FnMut(TextContent<'a>)) {
+    for item in items {
+        match item {
+            OutlineItem::Group(group) => visit_items(group.outlines(), visit),
+            OutlineItem::Element(element) => visit_element(element, visit),
+        }
+    }
+}
+
+fn visit_element<'a>(element: &'a OutlineElement, visit: &mut impl FnMut(TextContent<'a>)) {
+    for content in element.contents() {
+        match content {
+            Content::RichText(text) => visit(TextContent::RichText(text)),
+            Content::Image(image) => visit(TextContent::Image(image)),
+            Content::Table(table) => {
+                let cells = table.contents().iter().flat_map(|row| row.contents());
+                for cell_element in cells.flat_map(|cell| cell.contents()) {
+                    visit_element(cell_element, visit);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    visit_items(element.children(), visit);
+}
+
+/// Returns the page's paragraphs and OCR text, in order. Empty paragraphs are skipped.
+pub(crate) fn page_text_chunks(page: &Page) -> Vec<TextChunk<'_>> {
+    let mut chunks = Vec::new();
+
+    visit_text_content(page, &mut |content| {
+        let (object_id, source, text) = match content {
+            TextContent::RichText(rich_text) => (
+                rich_text.id,
+                TextSource::Paragraph,
+                paragraph_text(rich_text),
+            ),
+            TextContent::Image(image) => (
+                image.id,
+                TextSource::OcrText,
+                image.text().map(format_ocr_text).unwrap_or_default(),
+            ),
+        };
+
+        if !text.trim().is_empty() {
+            chunks.push(TextChunk {
+                page,
+                object_id,
+                source,
+                text,
+            });
+        }
+    });
+
+    chunks
+}
+
+/// Returns the visible text of a paragraph.
+///
+/// The text regions already leave out hyperlink URLs. This also removes the markers that
+/// remain around hyperlinks and math (noncharacters in the range U+FDD0 to U+FDEF) and the
+/// placeholders for embedded objects (U+FFFC).
+fn paragraph_text(rich_text: &RichText) -> String {
+    rich_text
+        .text_segments()
+        .iter()
+        .flat_map(|region| region.text().chars())
+        .filter(|c| !matches!(c, '\u{FDD0}'..='\u{FDEF}' | '\u{FFFC}'))
+        .map(|c| if c == '\u{000B}' { '\n' } else { c })
+        .collect()
+}
+
+/// Trims the lines of OCR text, which usually end with a space, and removes empty lines.
+pub fn format_ocr_text(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::TextSource;
+    use crate::Parser;
+    use parser_utils::fs_driver;
+
+    #[test]
+    fn should_extract_paragraphs_and_ocr_text() {
+        let data = fs_driver().read_file("../test-data/Printout.one").unwrap();
+        let section = Parser::new()
+            .parse_section_from_data(&data, "Printout.one")
+            .unwrap();
+
+        let chunks: Vec<_> = section.iter_text().collect();
+        assert!(chunks.iter().all(|chunk| !chunk.text().is_empty()));
+        assert!(
+            chunks
+                .iter()
+                .all(|chunk| chunk.object_id().starts_with('{'))
+        );
+
+        let ocr_chunks: Vec<_> = chunks
+            .iter()
+            .filter(|chunk| chunk.source() == TextSource::OcrText)
+            .collect();
+        assert_eq!(ocr_chunks.len(), 1);
+        assert_eq!(ocr_chunks[0].text(), "Test\nTest!\nmaln");
+
+        let page = chunks[0].page();
+        assert!(page.plain_text().contains("Test\nTest!\nmaln"));
+        assert_eq!(chunks[0].text(), page.title_text().unwrap());
+    }
+
+    #[test]
+    fn should_remove_hyperlink_and_math_markers() {
+        for file in ["ink.one", "Math.one"] {
+            let data = fs_driver()
+                .read_file(&format!("../test-data/{file}"))
+                .unwrap();
+            let section = Parser::new().parse_section_from_data(&data, file).unwrap();
+
+            for chunk in section.iter_text() {
+                let text = chunk.text();
+                assert!(!text.contains("\u{FDDF}HYPERLINK"), "{text:?}");
+                assert!(
+                    !text.contains(['\u{FDD0}', '\u{FDEE}', '\u{FDEF}']),
+                    "{text:?}"
+                );
+            }
+
+            let text = section.page_series()[0].pages()[0].plain_text();
+            match file {
+                "ink.one" => assert!(text.contains("Link to page: Page 2")),
+                _ => assert!(text.contains("Pythagorean Theorem: 𝑎2+𝑏2=𝑐2")),
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::onenote::page::Page;
+
+/// A page and its subpages.
+///
+/// OneNote stores subpages as a flat list, where each page has a [level](Page::level).
+/// A page's subpages are the pages that follow it with a greater level.
+#[derive(Clone, Debug)]
+pub struct PageNode<'a> {
+    page: &'a Page,
+    children: Vec<PageNode<'a>>,
+}
+
+impl<'a> PageNode<'a> {
+    /// The page.
+    pub fn page(&self) -> &'a Page {
+        self.page
+    }
+
+    /// The page's subpages.
+    pub fn children(&self) -> &[PageNode<'a>] {
+        &self.children
+    }
+}
+
+/// Builds the tree of pages from the page levels, keeping the pages in order.
+///
+/// A subpage that skips a level (for example, a level 3 page directly below a level 1 page)
+/// becomes a child of the closest preceding page with a lower level.
+pub(crate) fn build_page_tree<'a>(pages: impl IntoIterator<Item = &'a Page>) -> Vec<PageNode<'a>> {
+    let mut roots = Vec::new();
+    // The pages that later pages may be subpages of, from the root down
+    let mut open: Vec<PageNode<'a>> = Vec::new();
+
+    for page in pages {
+        while let Some(last) = open.last()
+            && last.page.level() >= page.level()
+        {
+            close_last(&mut open, &mut roots);
+        }
+
+        open.push(PageNode {
+            page,
+            children: Vec::new(),
+        });
+    }
+
+    while !open.is_empty() {
+        close_last(&mut open, &mut roots);
+    }
+
+    roots
+}
+
+fn close_last<'a>(open: &mut Vec<PageNode<'a>>, roots: &mut Vec<PageNode<'a>>) {
+    let Some(node) = open.pop() else {
+        return;
+    };
+
+    match open.last_mut() {
+        Some(parent) => parent.children.push(node),
+        None => roots.push(node),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PageNode;
+    use crate::Parser;
+    use parser_utils::fs_driver;
+
+    fn describe(nodes: &[PageNode]) -> String {
+        nodes
+            .iter()
+            .map(|node| {
+                let title = node.page().title_text().unwrap_or_default();
+                if node.children().is_empty() {
+                    title
+                } else {
+                    format!("{} ({})", title, describe(node.children()))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    #[test]
+    fn should_nest_subpages() {
+        let data = fs_driver().read_file("../test-data/Subpages.one").unwrap();
+        let section = Parser::new()
+            .parse_section_from_data(&data, "Subpages.one")
+            .unwrap();
+
+        assert_eq!(
+            describe(&section.page_tree()),
+            "Page 1 (Page 1-a, Page 1-b), \
+             Page 2 (Page 2-a (Page 2-a-a, Page 2-a-b), Page 2-b (Page 2-b-a))"
+        );
+        assert_eq!(
+            describe(&section.page_series()[1].page_tree()),
+            "Page 2 (Page 2-a (Page 2-a-a, Page 2-a-b), Page 2-b (Page 2-b-a))"
+        );
+    }
+}
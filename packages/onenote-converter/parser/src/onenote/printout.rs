@@ -0,0 +1,325 @@
+use std::io::Read;
+
+use crate::onenote::content::Content;
+use crate::onenote::image::Image;
+use crate::onenote::outline::{OutlineElement, OutlineItem};
+use crate::onenote::page_content::PageContent;
+use crate::shared::file_data_ref::FileBlob;
+use parser_utils::errors::Result;
+
+/// A file printout: the pages of a document (for example, a PDF or XPS file) that was
+/// inserted into a page.
+///
+/// OneNote stores each page of the printout as an image. For XPS documents, every page image
+/// references the original document. For other documents, each page image is a raster preview
+/// and the original document is usually attached to the page as an embedded file.
+#[derive(Clone, Debug)]
+pub struct Printout {
+    pub(crate) filename: String,
+    pub(crate) document: Option<FileBlob>,
+    pub(crate) document_is_embedded_file: bool,
+    pub(crate) has_previews: bool,
+    pub(crate) pages: Vec<Image>,
+}
+
+impl Printout {
+    /// The file name of the original document.
+    ///
+    /// If the document is attached to the page, this is the attachment's file name. XPS
+    /// documents are sometimes stored with the file name of the document they were printed
+    /// from (for example, `report.pdf`). In this case, the extension is replaced with `.xps`.
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    /// Reads the original document.
+    ///
+    /// Returns `None` if the document isn't stored in the file.
+    pub fn read_document(&self) -> Result<Option<Box<dyn Read>>> {
+        self.document.as_ref().map(|data| data.read()).transpose()
+    }
+
+    /// Whether the original document is also attached to the page as an [`EmbeddedFile`].
+    ///
+    /// [`EmbeddedFile`]: crate::contents::EmbeddedFile
+    pub fn document_is_embedded_file(&self) -> bool {
+        self.document_is_embedded_file
+    }
+
+    /// The number of printed pages.
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// The images that show the printed pages, in page order.
+    ///
+    /// Each image has the page's position, size and [`Image::displayed_page_number`].
+    /// Their data is only a raster preview of the page if [`Printout::has_previews`] is set.
+    pub fn pages(&self) -> &[Image] {
+        &self.pages
+    }
+
+    /// Whether the page images contain raster previews of the pages, rather than the
+    /// original document.
+    pub fn has_previews(&self) -> bool {
+        self.has_previews
+    }
+}
+
+/// Combines the page images of each printout on a page into a single [`PageContent::Printout`].
+pub(crate) fn group_printouts(contents: Vec<PageContent>) -> Result<Vec<PageContent>> {
+    let mut grouped: Vec<PageContent> = Vec::with_capacity(contents.len());
+    // The pages of each printout, with the printout's index in the final list of contents
+    let mut printouts: Vec<(usize, Vec<Image>)> = Vec::new();
+
+    for content in contents {
+        let image = match content {
+            PageContent::Image(image) if is_printout_page(&image) => image,
+            content => {
+                grouped.push(content);
+                continue;
+            }
+        };
+
+        let existing = printouts
+            .iter_mut()
+            .find(|(_, pages)| is_same_printout(pages, &image));
+        match existing {
+            Some((_, pages)) => pages.push(image),
+            None => printouts.push((grouped.len() + printouts.len(), vec![image])),
+        }
+    }
+
+    // Printouts may refer to embedded files anywhere on the page
+    let printouts = printouts
+        .into_iter()
+        .map(|(index, pages)| Ok((index, build_printout(pages, &grouped)?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    for (index, printout) in printouts {
+        grouped.insert(index, PageContent::Printout(printout));
+    }
+
+    Ok(grouped)
+}
+
+fn is_printout_page(image: &Image) -> bool {
+    image.displayed_page_number.is_some() && image.image_filename.is_some()
+}
+
+fn is_same_printout(pages: &[Image], image: &Image) -> bool {
+    let document_name = |image: &Image| image.image_filename.as_deref().map(document_name);
+
+    document_name(&pages[0]) == document_name(image)
+        && pages
+            .iter()
+            .all(|page| page.displayed_page_number != image.displayed_page_number)
+}
+
+/// Removes the `_<number>` suffix that OneNote adds to the file names of printout pages
+/// (for example, `report_1.pdf` for `report.pdf`).
+fn document_name(filename: &str) -> String {
+    let (base_name, extension) = filename
+        .rsplit_once('.')
+        .map(|(base_name, extension)| (base_name, format!(".{}", extension)))
+        .unwrap_or((filename, String::new()));
+
+    match base_name.rsplit_once('_') {
+        Some((document_base, suffix))
+            if !document_base.is_empty()
+                && !suffix.is_empty()
+                && suffix.chars().all(|c| c.is_ascii_digit()) =>
+        {
+            format!("{}{}", document_base, extension)
+        }
+        _ => filename.to_string(),
+    }
+}
+
+fn build_printout(mut pages: Vec<Image>, contents: &[PageContent]) -> Result<Printout> {
+    pages.sort_by_key(|page| page.displayed_page_number);
+    let filename = pages[0].image_filename.clone().unwrap_or_default();
+
+    let shared_data = pages[0]
+        .data
+        .as_ref()
+        .filter(|data| pages.iter().all(|page| page.data.as_ref() == Some(*data)));
+    if let Some(data) = shared_data {
+        let start = read_start(data)?;
+        let is_xps = start.starts_with(b"PK");
+
+        if is_xps || start.starts_with(b"%PDF") {
+            let filename = if is_xps && !has_xps_extension(&filename) {
+                let base_name = filename
+                    .rsplit_once('.')
+                    .map(|(base_name, _)| base_name)
+                    .unwrap_or(&filename);
+                format!("{}.xps", base_name)
+            } else {
+                filename
+            };
+
+            return Ok(Printout {
+                filename,
+                document: Some(data.clone()),
+                document_is_embedded_file: false,
+                has_previews: false,
+                pages,
+            });
+        }
+    }
+
+    let embedded_document = contents
+        .iter()
+        .filter_map(|content| content.outline())
+        .find_map(|outline| {
+            find_embedded_file(&outline.items, &filename)
+                .or_else(|| find_embedded_file(&outline.items, &document_name(&filename)))
+        });
+
+    Ok(Printout {
+        filename: embedded_document
+            .as_ref()
+            .map(|(name, _)| name.clone())
+            .unwrap_or(filename),
+        document_is_embedded_file: embedded_document.is_some(),
+        document: embedded_document.map(|(_, data)| data),
+        has_previews: true,
+        pages,
+    })
+}
+
+fn find_embedded_file(items: &[OutlineItem], filename: &str) -> Option<(String, FileBlob)> {
+    items.iter().find_map(|item| match item {
+        OutlineItem::Group(group) => find_embedded_file(&group.outlines, filename),
+        OutlineItem::Element(element) => find_embedded_file_in_element(element, filename),
+    })
+}
+
+fn find_embedded_file_in_element(
+    element: &OutlineElement,
+    filename: &str,
+) -> Option<(String, FileBlob)> {
+    element
+        .contents
+        .iter()
+        .find_map(|content| match content {
+            Content::EmbeddedFile(file) if file.filename == filename => {
+                Some((file.filename.clone(), file.data.clone()))
+            }
+            _ => None,
+        })
+        .or_else(|| find_embedded_file(&element.children, filename))
+}
+
+fn has_xps_extension(filename: &str) -> bool {
+    let lowercase = filename.to_lowercase();
+    lowercase.ends_with(".xps") || lowercase.ends_with(".oxps")
+}
+
+fn read_start(data: &FileBlob) -> Result<Vec<u8>> {
+    let mut start = Vec::with_capacity(4);
+    data.read()?.take(4).read_to_end(&mut start)?;
+    Ok(start)
+}
+
+#[cfg(test)]
+mod test {
+    use super::document_name;
+    use crate::Parser;
+    use parser_utils::fs_driver;
+    use std::io::Read;
+
+    #[test]
+    fn should_remove_page_suffix_from_document_names() {
+        assert_eq!(document_name("report_1.pdf"), "report.pdf");
+        assert_eq!(document_name("report_12"), "report");
+        assert_eq!(document_name("my_report.pdf"), "my_report.pdf");
+        assert_eq!(document_name("_1.pdf"), "_1.pdf");
+    }
+
+    #[test]
+    fn should_group_printout_pages() {
+        let data = fs_driver().read_file("../test-data/Printout.one").unwrap();
+        let section = Parser::new()
+            .parse_section_from_data(&data, "Printout.one")
+            .unwrap();
+        let page = &section.page_series()[0].pages()[0];
+
+        let printouts: Vec<_> = page
+            .contents()
+            .iter()
+            .filter_map(|content| content.printout())
+            .collect();
+        assert_eq!(printouts.len(), 1);
+        assert!(
+            page.contents()
+                .iter()
+                .all(|content| content.image().is_none())
+        );
+
+        // PDF printouts are stored as PNG previews, with the PDF attached to the page
+        let printout = printouts[0];
+        assert_eq!(printout.filename(), "test4.pdf");
+        assert!(printout.has_previews());
+        assert!(printout.document_is_embedded_file());
+        assert_eq!(printout.page_count(), printout.pages().len());
+
+        let mut document_start = [0; 4];
+        let mut document = printout.read_document().unwrap().unwrap();
+        document.read_exact(&mut document_start).unwrap();
+        assert_eq!(&document_start, b"%PDF");
+    }
+
+    #[test]
+    fn should_read_xps_printouts_from_the_page_images() {
+        // A copy of the app-cli "simple-xps.one" test file where the extension of the page
+        // image's file name was changed to .pdf
+        let data = fs_driver()
+            .read_file("../test-data/XPS printout.one")
+            .unwrap();
+        let section = Parser::new()
+            .parse_section_from_data(&data, "XPS printout.one")
+            .unwrap();
+        let page = &section.page_series()[0].pages()[0];
+
+        let printouts: Vec<_> = page
+            .contents()
+            .iter()
+            .filter_map(|content| content.printout())
+            .collect();
+        assert_eq!(printouts.len(), 1);
+
+        // XPS printouts store the document in the page images, whatever their file name
+        let printout = printouts[0];
+        assert_eq!(
+            printout.filename(),
+            "{31C8D031-E54E-4A63-B47E-463F7D57ABD7}.xps"
+        );
+        assert!(!printout.has_previews());
+        assert!(!printout.document_is_embedded_file());
+        assert_eq!(printout.page_count(), 1);
+        assert_eq!(
+            printout.pages()[0].image_filename(),
+            Some("{31C8D031-E54E-4A63-B47E-463F7D57ABD7}.pdf")
+        );
+
+        let mut document = Vec::new();
+        printout
+            .read_document()
+            .unwrap()
+            .unwrap()
+            .read_to_end(&mut document)
+            .unwrap();
+        assert!(document.starts_with(b"PK"));
+
+        let mut page_data = Vec::new();
+        printout.pages()[0]
+            .read()
+            .unwrap()
+            .unwrap()
+            .read_to_end(&mut page_data)
+            .unwrap();
+        assert_eq!(page_data, document);
+    }
+}
@@ -2,9 +2,11 @@ use std::rc::Rc;
 
 use crate::one::property::charset::Charset;
 use crate::one::property::color_ref::ColorRef;
+use crate::one::property::language_id::language_tag;
 use crate::one::property::layout_alignment::LayoutAlignment;
 use crate::one::property::paragraph_alignment::ParagraphAlignment;
 use crate::one::property_set::{embedded_ink_container, paragraph_style_object, rich_text_node};
+use crate::onenote::diagnostics::DiagnosticsCollector;
 use crate::onenote::ink::{Ink, InkBoundingBox, InkContent, parse_ink_data};
 use crate::onenote::note_tag::{NoteTag, parse_note_tags};
 use crate::onenote::text_region::TextRegion;
@@ -32,6 +34,7 @@ use parser_utils::log_warn;
 /// substring.
 #[derive(Clone, Debug)]
 pub struct RichText {
+    pub(crate) id: ExGuid,
     pub(crate) text: String,
     pub(crate) text_regions: Vec<TextRegion>,
 
@@ -49,6 +52,12 @@ pub struct RichText {
 
     pub(crate) note_tags: Vec<NoteTag>,
     pub(crate) embedded_objects: Vec<EmbeddedObject>,
+
+    pub(crate) language_code: Option<u32>,
+
+    pub(crate) is_title_text: bool,
+    pub(crate) is_title_date: bool,
+    pub(crate) is_title_time: bool,
 }
 
 impl RichText {
@@ -148,6 +157,32 @@ impl RichText {
     pub fn embedded_objects(&self) -> &[EmbeddedObject] {
         &self.embedded_objects
     }
+
+    /// The LCID language code for the paragraph.
+    pub fn language_code(&self) -> Option<u32> {
+        self.language_code
+    }
+
+    /// The BCP 47 language tag for the paragraph (for example, `en-US`), if the language
+    /// code is known.
+    pub fn language_tag(&self) -> Option<&'static str> {
+        self.language_code.and_then(language_tag)
+    }
+
+    /// Whether this paragraph is (part of) the page title's text.
+    pub fn is_title_text(&self) -> bool {
+        self.is_title_text
+    }
+
+    /// Whether this paragraph is the date shown below the page title.
+    pub fn is_title_date(&self) -> bool {
+        self.is_title_date
+    }
+
+    /// Whether this paragraph is the time shown below the page title.
+    pub fn is_title_time(&self) -> bool {
+        self.is_title_time
+    }
 }
 
 /// An object embedded in a rich text paragraph.
@@ -358,6 +393,12 @@ impl ParagraphStyling {
         self.language_code
     }
 
+    /// The BCP 47 language tag for the text (for example, `en-US`), if the language code
+    /// is known.
+    pub fn language_tag(&self) -> Option<&'static str> {
+        self.language_code.and_then(language_tag)
+    }
+
     /// Whether the text is formatted as a math expression
     pub fn math_formatting(&self) -> bool {
         self.math_formatting
@@ -373,17 +414,23 @@ impl ParagraphStyling {
 const INK_SPACE_BLOB: u32 = 0x00020026;
 const INK_END_OF_LINE_BLOB: u32 = 0x00020027;
 
-pub(crate) fn parse_rich_text(content_id: ExGuid, space: ObjectSpaceRef) -> Result<RichText> {
-    let object = space
-        .get_object(content_id)
+pub(crate) fn parse_rich_text(
+    content_id: ExGuid,
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<RichText> {
+    let object = diagnostics
+        .get_object(&space, content_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("rich text content is missing".into()))?;
     let data = rich_text_node::parse(&object)?;
 
     // Parse the base paragraph style
-    let paragraph_style_object = space.get_object(data.paragraph_style).unwrap_or_else(|| {
-        log_warn!("paragraph styling is missing");
-        Rc::new(Object::fallback())
-    });
+    let paragraph_style_object = diagnostics
+        .get_object(&space, data.paragraph_style)
+        .unwrap_or_else(|| {
+            log_warn!("paragraph styling is missing");
+            Rc::new(Object::fallback())
+        });
     let paragraph_style_data = paragraph_style_object::parse(&paragraph_style_object)?;
     let paragraph_style = parse_style(paragraph_style_data);
 
@@ -393,10 +440,16 @@ pub(crate) fn parse_rich_text(content_id: ExGuid, space: ObjectSpaceRef) -> Resu
         .text_run_formatting
         .iter()
         .filter_map(|style_id| {
-            space.get_object(*style_id).or_else(|| {
+            diagnostics.get_object(&space, *style_id).or_else(|| {
                 // Handle the case where styles are missing gracefully. It seems that style objects
                 // are sometimes missing, or can't be found:
                 // https://discourse.joplinapp.org/t/onenote-zip-file-import-not-working/47499/12
+                //
+                // Resolving IDs against the global ID tables of dependency revisions fixes
+                // lookups that failed because of the wrong table, but the objects can still be
+                // absent from the file (for example, in truncated files or object groups that
+                // were never written). Losing the run's formatting is better than failing the
+                // whole page.
                 log_warn!(
                     "Paragraph styling not found: Unable to locate object with ID {:?}.",
                     style_id
@@ -428,7 +481,7 @@ pub(crate) fn parse_rich_text(content_id: ExGuid, space: ObjectSpaceRef) -> Resu
 
             let object_ref = data.text_run_data_object.get(i);
             let is_valid_ref = object_ref
-                .map(|object_ref| space.get_object(*object_ref).is_some())
+                .map(|object_ref| diagnostics.get_object(&space, *object_ref).is_some())
                 .unwrap_or(true);
 
             // Based on sample .one files, spaces and EOL blobs either:
@@ -451,8 +504,13 @@ pub(crate) fn parse_rich_text(content_id: ExGuid, space: ObjectSpaceRef) -> Resu
                     .map(|space| Some(EmbeddedObject::InkSpace(space))),
                 None => {
                     if let Some(object_ref) = object_ref {
-                        return parse_embedded_ink_data(*object_ref, space.clone(), embedded_data)
-                            .map(|container| Some(EmbeddedObject::Ink(container)));
+                        return parse_embedded_ink_data(
+                            *object_ref,
+                            space.clone(),
+                            diagnostics,
+                            embedded_data,
+                        )
+                        .map(|container| Some(EmbeddedObject::Ink(container)));
                     }
 
                     Ok(None)
@@ -471,9 +529,6 @@ pub(crate) fn parse_rich_text(content_id: ExGuid, space: ObjectSpaceRef) -> Resu
     // Parse the styles text runs (part 2)
     let styles = styles_data.into_iter().map(parse_style).collect_vec();
 
-    // TODO: Parse lang code into iso code
-    // dia-i18n = "0.8.0"
-
     let text = if !embedded_objects.is_empty() {
         "".to_string()
     } else {
@@ -488,6 +543,7 @@ pub(crate) fn parse_rich_text(content_id: ExGuid, space: ObjectSpaceRef) -> Resu
             &data.text_run_data_values,
         )?,
 
+        id: content_id,
         text,
         embedded_objects,
         text_run_formatting: styles,
@@ -499,7 +555,11 @@ pub(crate) fn parse_rich_text(content_id: ExGuid, space: ObjectSpaceRef) -> Resu
         paragraph_alignment: data.paragraph_alignment,
         layout_alignment_in_parent: data.layout_alignment_in_parent,
         layout_alignment_self: data.layout_alignment_self,
-        note_tags: parse_note_tags(data.note_tags, space)?,
+        note_tags: parse_note_tags(data.note_tags, space, diagnostics)?,
+        language_code: data.language_code,
+        is_title_text: data.is_title_text,
+        is_title_date: data.is_title_date,
+        is_title_time: data.is_title_time,
     };
 
     Ok(text)
@@ -508,9 +568,10 @@ pub(crate) fn parse_rich_text(content_id: ExGuid, space: ObjectSpaceRef) -> Resu
 fn parse_embedded_ink_data(
     embedded_id: ExGuid,
     space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
     data: embedded_ink_container::Data,
 ) -> Result<EmbeddedInkContainer> {
-    let (strokes, bb) = parse_ink_data(embedded_id, space, None, None)?;
+    let (strokes, bb) = parse_ink_data(embedded_id, space, diagnostics, None, None)?;
 
     let display_bb = data
         .start_x
@@ -530,6 +591,7 @@ fn parse_embedded_ink_data(
             bounding_box: bb,
             offset_horizontal: data.offset_horiz,
             offset_vertical: data.offset_vert,
+            layout_collision_priority: None,
         },
         bounding_box: display_bb,
     };
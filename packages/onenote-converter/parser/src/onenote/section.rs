@@ -2,7 +2,10 @@ use std::rc::Rc;
 
 use crate::one::property::color::Color;
 use crate::one::property_set::{section_metadata_node, section_node};
+use crate::onenote::ocr_text::OcrText;
 use crate::onenote::page_series::{PageSeries, parse_page_series};
+use crate::onenote::page_text::TextChunk;
+use crate::onenote::page_tree::{PageNode, build_page_tree};
 use crate::onestore::OneStore;
 use crate::onestore::object_space::ObjectSpaceRef;
 use parser_utils::errors::{ErrorKind, Result};
@@ -15,6 +18,23 @@ pub enum SectionEntry {
     SectionGroup(SectionGroup),
 }
 
+/// An entry in a notebook's section list, before the sections are parsed.
+///
+/// See [`Parser::list_notebook_sections`](crate::Parser::list_notebook_sections).
+#[derive(Clone, Debug)]
+pub enum SectionFile {
+    /// The path to a section (`.one`) file.
+    Section(String),
+
+    /// A section group, with the sections it contains.
+    SectionGroup {
+        /// The group name.
+        display_name: String,
+        /// The sections contained within the group.
+        entries: Vec<SectionFile>,
+    },
+}
+
 /// A OneNote section.
 ///
 /// See [\[MS-ONE\] 1.3.1] and [\[MS-ONE\] 2.2.17].
@@ -39,6 +59,30 @@ impl Section {
         &self.page_series
     }
 
+    /// The pages in the section, with subpages nested below their parent pages.
+    pub fn page_tree(&self) -> Vec<PageNode<'_>> {
+        build_page_tree(self.page_series.iter().flat_map(|series| series.pages()))
+    }
+
+    /// The text that OneNote recognised in the images on the section's pages (OCR), in page
+    /// order.
+    pub fn iter_ocr_text(&self) -> impl Iterator<Item = OcrText<'_>> {
+        self.page_series
+            .iter()
+            .flat_map(|series| series.pages())
+            .flat_map(|page| page.ocr_text())
+    }
+
+    /// The text on the section's pages, in page order (see [`Page::text_chunks`]).
+    ///
+    /// [`Page::text_chunks`]: crate::page::Page::text_chunks
+    pub fn iter_text(&self) -> impl Iterator<Item = TextChunk<'_>> {
+        self.page_series
+            .iter()
+            .flat_map(|series| series.pages())
+            .flat_map(|page| page.text_chunks())
+    }
+
     /// The color of the section.
     pub fn color(&self) -> Option<Color> {
         self.color
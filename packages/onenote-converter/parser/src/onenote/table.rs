@@ -2,11 +2,13 @@ use crate::one::property::color::Color;
 use crate::one::property::layout_alignment::LayoutAlignment;
 use crate::one::property::outline_indent_distance::OutlineIndentDistance;
 use crate::one::property_set::{table_cell_node, table_node, table_row_node};
+use crate::onenote::diagnostics::DiagnosticsCollector;
 use crate::onenote::note_tag::{NoteTag, parse_note_tags};
 use crate::onenote::outline::{OutlineElement, parse_outline_element};
 use crate::onestore::object_space::ObjectSpaceRef;
 use crate::shared::exguid::ExGuid;
 use parser_utils::errors::{ErrorKind, Result};
+use parser_utils::log_warn;
 
 /// A table.
 ///
@@ -46,13 +48,9 @@ impl Table {
         &self.contents
     }
 
-    /// Which columns have a locked width.
+    /// Which columns have a locked width, as a bit array with one bit per column.
     ///
-    /// To determine if column `c` has a locked with, calculate:
-    ///
-    /// ```ignore
-    /// table.cols_locked()[c / 8] & (1 << (c % 8)) == 1;
-    /// ```
+    /// Use [`Table::is_col_locked`] to check a single column.
     ///
     /// See [\[MS-ONE\] 2.3.70].
     ///
@@ -61,6 +59,15 @@ impl Table {
         &self.cols_locked
     }
 
+    /// Whether column `col` (starting at 0) has a locked width.
+    ///
+    /// Columns that aren't listed in [`Table::cols_locked`] aren't locked.
+    pub fn is_col_locked(&self, col: usize) -> bool {
+        self.cols_locked
+            .get(col / 8)
+            .is_some_and(|byte| byte & (1 << (col % 8)) != 0)
+    }
+
     /// The column widths in half-inch increments.
     ///
     /// See [\[MS-ONE\] 2.3.66].
@@ -105,6 +112,10 @@ impl Table {
 
 /// A table row.
 ///
+/// Table rows only store their last modified time and their cells. There's no row height,
+/// header row designation or note tags for a single row: rows are as tall as their contents,
+/// and note tags are stored on the [table](Table::note_tags) or the cells' contents.
+///
 /// See [\[MS-ONE\] 2.2.27].
 ///
 /// [\[MS-ONE\] 2.2.27]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/d22af1aa-5e0b-40ed-b914-f6397979d6b0
@@ -115,6 +126,10 @@ pub struct TableRow {
 
 impl TableRow {
     /// The cells in the table row.
+    ///
+    /// Usually there's one cell for each of the table's [columns](Table::cols). Rows of
+    /// partially corrupt tables can have fewer or more cells. Cells that couldn't be found
+    /// are empty.
     pub fn contents(&self) -> &[TableCell] {
         &self.contents
     }
@@ -122,6 +137,10 @@ impl TableRow {
 
 /// A table cell.
 ///
+/// Besides their contents, table cells only store a max width, an indentation size and a
+/// background color. There's no cell alignment: the alignment of the cell's contents is
+/// stored on its [paragraphs](crate::contents::RichText::paragraph_alignment).
+///
 /// See [\[MS-ONE\] 2.2.28].
 ///
 /// [\[MS-ONE\] 2.2.28]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/e5660d6b-72c3-4d9f-bad0-435c00f42183
@@ -135,6 +154,15 @@ pub struct TableCell {
 }
 
 impl TableCell {
+    fn empty() -> Self {
+        TableCell {
+            contents: Vec::new(),
+            background_color: None,
+            layout_max_width: None,
+            outline_indent_distance: OutlineIndentDistance::default(),
+        }
+    }
+
     /// The contents of the table cell.
     pub fn contents(&self) -> &[OutlineElement] {
         &self.contents
@@ -164,16 +192,21 @@ impl TableCell {
     }
 }
 
-pub(crate) fn parse_table(table_id: ExGuid, space: ObjectSpaceRef) -> Result<Table> {
-    let table_object = space
-        .get_object(table_id)
+pub(crate) fn parse_table(
+    table_id: ExGuid,
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<Table> {
+    let table_object = diagnostics
+        .get_object(&space, table_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("table object is missing".into()))?;
     let data = table_node::parse(&table_object)?;
 
     let contents = data
         .rows
         .into_iter()
-        .map(|row_id| parse_row(row_id, space.clone()))
+        .map(|row_id| parse_row(row_id, space.clone(), diagnostics))
+        .filter_map(Result::transpose)
         .collect::<Result<_>>()?;
 
     let table = Table {
@@ -185,39 +218,60 @@ pub(crate) fn parse_table(table_id: ExGuid, space: ObjectSpaceRef) -> Result<Tab
         borders_visible: data.borders_visible,
         layout_alignment_in_parent: data.layout_alignment_in_parent,
         layout_alignment_self: data.layout_alignment_self,
-        note_tags: parse_note_tags(data.note_tags, space.clone())?,
+        note_tags: parse_note_tags(data.note_tags, space.clone(), diagnostics)?,
     };
 
     Ok(table)
 }
 
-fn parse_row(row_id: ExGuid, space: ObjectSpaceRef) -> Result<TableRow> {
-    let row_object = space
-        .get_object(row_id)
-        .ok_or_else(|| ErrorKind::MalformedOneNoteData("row object is missing".into()))?;
+fn parse_row(
+    row_id: ExGuid,
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<Option<TableRow>> {
+    let Some(row_object) = diagnostics.get_object(&space, row_id) else {
+        log_warn!("Table row {row_id:?} not found. Skipping the row.");
+        diagnostics.report_missing_object(row_id, "Missing table row");
+        return Ok(None);
+    };
     let data = table_row_node::parse(&row_object)?;
 
     let contents = data
         .cells
         .into_iter()
-        .map(|cell_id| parse_cell(cell_id, space.clone()))
+        .map(|cell_id| parse_cell(cell_id, space.clone(), diagnostics))
         .collect::<Result<_>>()?;
 
     let row = TableRow { contents };
 
-    Ok(row)
+    Ok(Some(row))
 }
 
-fn parse_cell(cell_id: ExGuid, space: ObjectSpaceRef) -> Result<TableCell> {
-    let cell_object = space
-        .get_object(cell_id)
-        .ok_or_else(|| ErrorKind::MalformedOneNoteData("cell object is missing".into()))?;
-    let data = table_cell_node::parse(&cell_object)?;
+fn parse_cell(
+    cell_id: ExGuid,
+    space: ObjectSpaceRef,
+    diagnostics: &DiagnosticsCollector,
+) -> Result<TableCell> {
+    // Keep an empty cell in place of a missing or invalid cell, so that the cells that follow
+    // it stay in their columns
+    let Some(cell_object) = diagnostics.get_object(&space, cell_id) else {
+        log_warn!("Table cell {cell_id:?} not found. Leaving the cell empty.");
+        diagnostics.report_missing_object(cell_id, "Missing table cell");
+        return Ok(TableCell::empty());
+    };
+    let data = match table_cell_node::parse(&cell_object) {
+        Ok(data) => data,
+        Err(error) => {
+            log_warn!("Table cell {cell_id:?} is invalid ({error}). Leaving the cell empty.");
+            diagnostics.report_missing_object(cell_id, "Invalid table cell");
+            return Ok(TableCell::empty());
+        }
+    };
 
     let contents = data
         .contents
         .into_iter()
-        .map(|element_id| parse_outline_element(element_id, space.clone()))
+        .map(|element_id| parse_outline_element(element_id, space.clone(), diagnostics))
         .collect::<Result<_>>()?;
 
     let cell = TableCell {
@@ -229,3 +283,57 @@ fn parse_cell(cell_id: ExGuid, space: ObjectSpaceRef) -> Result<TableCell> {
 
     Ok(cell)
 }
+
+#[cfg(test)]
+mod test {
+    use crate::Parser;
+    use crate::contents::Table;
+    use parser_utils::fs_driver;
+
+    fn find_table(file: &str) -> Table {
+        let data = fs_driver()
+            .read_file(&format!("../test-data/{file}"))
+            .unwrap();
+        let section = Parser::new().parse_section_from_data(&data, file).unwrap();
+
+        let page = &section.page_series()[0].pages()[0];
+        let outline = page.contents().iter().find_map(|content| content.outline());
+        let elements = outline.into_iter().flat_map(|outline| outline.items());
+        elements
+            .filter_map(|item| item.element())
+            .flat_map(|element| element.contents())
+            .find_map(|content| content.table())
+            .cloned()
+            .expect("should contain a table")
+    }
+
+    #[test]
+    fn should_detect_locked_columns() {
+        // A copy of the app-cli "onenote_desktop.one" test file where the table has a third
+        // column (repeating the cells of the first), and the first and third columns are locked
+        let table = find_table("Locked columns.one");
+
+        assert_eq!(table.cols(), 3);
+        assert_eq!(table.cols_locked(), [0b101]);
+        assert!(table.is_col_locked(0));
+        assert!(!table.is_col_locked(1));
+        assert!(table.is_col_locked(2));
+        // Columns beyond the bit array aren't locked
+        assert!(!table.is_col_locked(8));
+    }
+
+    #[test]
+    fn should_keep_short_rows() {
+        // A copy of the app-cli "onenote_desktop.one" test file where the second row of the
+        // table only lists its first cell
+        let table = find_table("Ragged table.one");
+
+        assert_eq!(table.cols(), 2);
+        let row_lengths: Vec<_> = table
+            .contents()
+            .iter()
+            .map(|row| row.contents().len())
+            .collect();
+        assert_eq!(row_lengths, [2, 1]);
+    }
+}
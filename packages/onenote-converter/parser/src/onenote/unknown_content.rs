@@ -0,0 +1,102 @@
+use crate::one::property::{PropertyType, simple};
+use crate::one::property_set::PropertySetId;
+use crate::onestore::object::Object;
+use crate::shared::jcid::JcId;
+
+/// Page content that the parser doesn't support.
+///
+/// Only the object's type and, where the object provides them, its position and size are known.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnknownContent {
+    pub(crate) jcid: u32,
+
+    pub(crate) offset_horizontal: Option<f32>,
+    pub(crate) offset_vertical: Option<f32>,
+    pub(crate) layout_collision_priority: Option<u32>,
+
+    pub(crate) layout_max_width: Option<f32>,
+    pub(crate) layout_max_height: Option<f32>,
+}
+
+impl UnknownContent {
+    /// The object's type (JCID).
+    pub fn jcid(&self) -> u32 {
+        self.jcid
+    }
+
+    /// The name of the object's type, if the parser knows it.
+    pub fn type_name(&self) -> Option<String> {
+        PropertySetId::from_jcid(JcId(self.jcid)).map(|id| format!("{:?}", id))
+    }
+
+    /// The horizontal offset from the parent in half-inch increments.
+    pub fn offset_horizontal(&self) -> Option<f32> {
+        self.offset_horizontal
+    }
+
+    /// The vertical offset from the parent in half-inch increments.
+    pub fn offset_vertical(&self) -> Option<f32> {
+        self.offset_vertical
+    }
+
+    /// The content's priority when OneNote moves page contents so that they don't overlap
+    /// (if present).
+    pub fn layout_collision_priority(&self) -> Option<u32> {
+        self.layout_collision_priority
+    }
+
+    /// The maximum width of the content in half-inch increments.
+    pub fn layout_max_width(&self) -> Option<f32> {
+        self.layout_max_width
+    }
+
+    /// The maximum height of the content in half-inch increments.
+    pub fn layout_max_height(&self) -> Option<f32> {
+        self.layout_max_height
+    }
+}
+
+pub(crate) fn parse_unknown_content(object: &Object) -> UnknownContent {
+    // The object's property types aren't known, so ignore values that don't have the
+    // expected type rather than failing.
+    let parse_f32 = |prop_type| simple::parse_f32(prop_type, object).ok().flatten();
+
+    UnknownContent {
+        jcid: object.id().0,
+        offset_horizontal: parse_f32(PropertyType::OffsetFromParentHoriz),
+        offset_vertical: parse_f32(PropertyType::OffsetFromParentVert),
+        layout_collision_priority: simple::parse_u32(PropertyType::LayoutCollisionPriority, object)
+            .ok()
+            .flatten(),
+        layout_max_width: parse_f32(PropertyType::LayoutMaxWidth),
+        layout_max_height: parse_f32(PropertyType::LayoutMaxHeight),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Parser;
+    use parser_utils::fs_driver;
+
+    #[test]
+    fn should_parse_unknown_page_content() {
+        // A copy of bold_and_italic.one where the JCID of the page's outline was changed to
+        // that of an iframe, which isn't supported as page content.
+        let data = fs_driver()
+            .read_file("../test-data/Unsupported content.one")
+            .unwrap();
+        let section = Parser::new()
+            .parse_section_from_data(&data, "Unsupported content.one")
+            .unwrap();
+        let page = &section.page_series()[0].pages()[0];
+        let content = page.contents()[0].unknown().unwrap();
+
+        assert_eq!(content.jcid(), 0x00060058);
+        assert_eq!(content.type_name().as_deref(), Some("IFrameNode"));
+        assert_eq!(content.offset_horizontal(), Some(1.0));
+        assert_eq!(content.offset_vertical(), Some(2.4));
+        assert_eq!(content.layout_max_width(), Some(15.0));
+        assert_eq!(content.layout_max_height(), Some(0.6));
+        assert_eq!(content.layout_collision_priority(), None);
+    }
+}
@@ -33,6 +33,19 @@ impl ExGuid {
         if self.is_nil() { None } else { Some(*self) }
     }
 
+    /// Parses an `ExGuid` from its text form, `{GUID},value` (for example,
+    /// `{A6F8D7F1-4B2E-4C3B-9A34-F5A9C1D0B1E2},3`).
+    pub(crate) fn from_str(text: &str) -> Result<ExGuid> {
+        let (guid, value) = text.trim().rsplit_once(',').ok_or_else(|| {
+            ErrorKind::MalformedData(format!("Expected {{GUID}},value, got {}", text).into())
+        })?;
+        let value = value.trim().parse::<u32>().map_err(|_| {
+            ErrorKind::MalformedData(format!("Invalid ExGuid value: {}", value).into())
+        })?;
+
+        Ok(ExGuid::from_guid(Guid::from_str(guid.trim())?, value))
+    }
+
     pub(crate) fn from_guid(guid: Guid, value: u32) -> ExGuid {
         ExGuid { guid, value }
     }
@@ -1,7 +1,8 @@
 use std::fmt::Debug;
-use std::io::{Cursor, Read};
+use std::io::Read;
 use std::rc::Rc;
 
+use bytes::{Buf, Bytes};
 use parser_utils::Result;
 use parser_utils::reader::ReaderDataRef;
 
@@ -21,11 +22,21 @@ impl PartialEq for FileBlob {
 
 pub trait FileDataLoader {
     fn read(&self) -> Result<Box<dyn Read>>;
+
+    /// The data, if it is held in memory.
+    fn bytes(&self) -> Option<Bytes> {
+        None
+    }
 }
 
-impl FileDataLoader for Vec<u8> {
+impl FileDataLoader for Bytes {
     fn read(&self) -> Result<Box<dyn Read>> {
-        Ok(Box::new(Cursor::new(self.clone())))
+        // Cloning `Bytes` only increments a reference count
+        Ok(Box::new(self.clone().reader()))
+    }
+
+    fn bytes(&self) -> Option<Bytes> {
+        Some(self.clone())
     }
 }
 
@@ -33,12 +44,16 @@ impl FileDataLoader for ReaderDataRef {
     fn read(&self) -> Result<Box<dyn Read>> {
         Ok(ReaderDataRef::read(self))
     }
+
+    fn bytes(&self) -> Option<Bytes> {
+        ReaderDataRef::bytes(self)
+    }
 }
 
 impl Default for FileBlob {
     fn default() -> Self {
         Self {
-            loader: Rc::new(vec![]),
+            loader: Rc::new(Bytes::new()),
             size: 0,
         }
     }
@@ -65,4 +80,12 @@ impl FileBlob {
     pub fn read(&self) -> Result<Box<dyn Read>> {
         self.loader.read()
     }
+
+    /// The data, without copying it, if it is held in memory.
+    ///
+    /// Returns `None` if the data is read from a file on demand. Use [`FileBlob::read`] to
+    /// read it in this case.
+    pub fn bytes(&self) -> Option<Bytes> {
+        self.loader.bytes()
+    }
 }
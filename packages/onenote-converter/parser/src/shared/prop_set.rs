@@ -23,6 +23,8 @@ pub(crate) struct PropertySet {
     /// assert_eq!(propset.get(PropertyType::Bold), None);
     /// ```
     values: HashMap<u32, (usize, PropertyValue)>,
+    /// The full IDs (including type information) of the properties, in file order.
+    ids: Vec<PropertyId>,
 }
 
 impl Debug for PropertySet {
@@ -32,18 +34,7 @@ impl Debug for PropertySet {
                 PropertyValue::Vec(vec) => {
                     // Vec() property values are used to represent strings. Try creating a string representation for
                     // debugging purposes:
-                    let s = vec
-                        .as_slice()
-                        // OneNote file strings are usually UTF-16
-                        .utf16_to_string()
-                        .unwrap_or("".to_string());
-
-                    // Heuristic: If the text contains at least one ASCII letter/space character, it's probably a string.
-                    // This will miss some non-ASCII strings and incorrectly print some non-string vecs.
-                    let is_probably_string = !s.is_empty()
-                        && s.chars()
-                            .any(|c| c.is_ascii_whitespace() || c.is_ascii_alphanumeric());
-                    if is_probably_string {
+                    if let Some(s) = probable_string(vec) {
                         format!("{:?} ({:?})", s, vec)
                     } else {
                         format!("{:?}", vec)
@@ -67,10 +58,24 @@ impl Debug for PropertySet {
     }
 }
 
+/// Decodes `data` as a UTF-16 string, if it looks like one. Used for debug output.
+pub(crate) fn probable_string(data: &[u8]) -> Option<String> {
+    // OneNote file strings are usually UTF-16
+    let s = data.utf16_to_string().unwrap_or("".to_string());
+
+    // Heuristic: If the text contains at least one ASCII letter/space character, it's probably a string.
+    // This will miss some non-ASCII strings and incorrectly print some non-string vecs.
+    let is_probably_string = !s.is_empty()
+        && s.chars()
+            .any(|c| c.is_ascii_whitespace() || c.is_ascii_alphanumeric());
+    if is_probably_string { Some(s) } else { None }
+}
+
 impl PropertySet {
     pub fn fallback() -> PropertySet {
         PropertySet {
             values: HashMap::from([]),
+            ids: Vec::new(),
         }
     }
 
@@ -82,12 +87,16 @@ impl PropertySet {
             .collect::<Result<_>>()?;
 
         let values = property_ids
-            .into_iter()
+            .iter()
+            .copied()
             .enumerate()
             .map(|(idx, id)| Ok((id.id(), (idx, PropertyValue::parse(id, reader)?))))
             .collect::<Result<_>>()?;
 
-        Ok(PropertySet { values })
+        Ok(PropertySet {
+            values,
+            ids: property_ids,
+        })
     }
 
     pub(crate) fn get(&self, id: PropertyId) -> Option<&PropertyValue> {
@@ -106,6 +115,13 @@ impl PropertySet {
         self.values.values().map(|(_, value)| value)
     }
 
+    /// Iterates over the properties in the order they were declared.
+    pub(crate) fn entries(&self) -> impl Iterator<Item = (PropertyId, &PropertyValue)> {
+        self.ids
+            .iter()
+            .filter_map(|id| self.get(*id).map(|value| (*id, value)))
+    }
+
     pub(crate) fn values_with_index(&self) -> impl Iterator<Item = &(usize, PropertyValue)> {
         self.values.values()
     }
@@ -50,8 +50,8 @@ pub enum ErrorKind {
     #[error("IO failure: {0}")]
     IoError(std::io::Error),
 
-    #[error("onepkg import failure: {0}")]
-    OnePkgImportFailure(String),
+    #[error("Conversion cancelled")]
+    Cancelled,
 
     #[error("Failure: {0}")]
     OtherError(ColorError),
@@ -1,62 +1,168 @@
 use color_eyre::eyre::{Result, eyre};
 pub use parser::Parser;
-use std::{io::Read, panic};
-use wasm_bindgen::{JsError, prelude::wasm_bindgen};
+use std::panic;
+use std::sync::Arc;
+use wasm_bindgen::{JsError, JsValue, prelude::wasm_bindgen};
 
-use parser_utils::{FileHandle, fs_driver, log};
+use parser_utils::{FileHandle, fs_driver, log, log_warn};
 
-use crate::errors::ErrorKind;
+pub use crate::options::{
+    AssetLayout, ConvertOptions, ErrorPolicy, InkMode, MathMode, OcrTextMode, OutputFormat,
+    PageLayout, PageNaming, SubpageLayout,
+};
+pub use crate::progress::{CancellationToken, Progress, ProgressListener};
+use crate::progress::{JsProgressListener, ProgressTracker};
+pub use crate::report::{ConversionReport, ItemCounts, PageReport, SectionReport};
 
 mod errors;
 mod notebook;
+mod options;
 mod page;
+mod parallel;
+mod progress;
+mod report;
 mod section;
+mod tasks;
 mod templates;
 mod utils;
 
+/// Converts the file at `input`. `options` is an optional object with the conversion
+/// options (see [`ConvertOptions`]).
+///
+/// Returns the [`ConversionReport`], as JSON.
 #[wasm_bindgen]
 #[allow(non_snake_case)]
-pub fn oneNoteConverter(input: &str, output: &str, base_path: &str) -> Result<(), JsError> {
+pub fn oneNoteConverter(
+    input: &str,
+    output: &str,
+    base_path: &str,
+    options: JsValue,
+) -> Result<String, JsError> {
+    let options = options_from_js(options)?;
+    run_from_js(|| _main(input, output, base_path, &options))
+}
+
+/// Like `oneNoteConverter`, but calls `onProgress` as the conversion progresses (see
+/// [`Progress`]) and stops between pages once `cancellation` is cancelled.
+#[wasm_bindgen]
+#[allow(non_snake_case)]
+pub fn oneNoteConverterWithProgress(
+    input: &str,
+    output: &str,
+    base_path: &str,
+    options: JsValue,
+    onProgress: js_sys::Function,
+    cancellation: &CancellationToken,
+) -> Result<String, JsError> {
+    let options = options_from_js(options)?;
+    let cancellation = cancellation.clone();
+    run_from_js(|| {
+        log!("Starting parsing of the file: {:?}", input);
+        JsProgressListener(onProgress).run(|listener| {
+            convert_with_progress(input, output, base_path, &options, listener, cancellation)?
+                .to_json()
+        })
+    })
+}
+
+fn options_from_js(options: JsValue) -> Result<ConvertOptions, JsError> {
+    if options.is_undefined() || options.is_null() {
+        return Ok(ConvertOptions::default());
+    }
+
+    serde_wasm_bindgen::from_value(options)
+        .map_err(|error| JsError::new(&format!("Invalid conversion options: {}", error)))
+}
+
+fn run_from_js<T>(convert: impl FnOnce() -> Result<T>) -> Result<T, JsError> {
     panic::set_hook(Box::new(console_error_panic_hook::hook));
     log::set_current_page("[None]".into());
 
-    if let Err(e) = _main(input, output, base_path) {
+    convert().map_err(|e| {
         let message = format!("Error: {:?} (near page {})", e, log::get_current_page());
-        Err(JsError::new(&message))
-    } else {
-        Ok(())
-    }
+        JsError::new(&message)
+    })
 }
 
-fn _main(input_path: &str, output_dir: &str, base_path: &str) -> Result<()> {
+fn _main(
+    input_path: &str,
+    output_dir: &str,
+    base_path: &str,
+    options: &ConvertOptions,
+) -> Result<String> {
     log!("Starting parsing of the file: {:?}", input_path);
-    convert(input_path, output_dir, base_path)?;
+    convert_with_options(input_path, output_dir, base_path, options)?.to_json()
+}
+
+/// Converts the `.one`, `.onetoc2` or `.onepkg` file at `path` to HTML in `output_dir`.
+///
+/// Sections and pages that fail to convert are listed in the returned [`ConversionReport`].
+/// An error is only returned if the conversion couldn't continue (or, with
+/// [`ErrorPolicy::Abort`], at the first failure).
+pub fn convert(path: &str, output_dir: &str, base_path: &str) -> Result<ConversionReport> {
+    convert_with_options(path, output_dir, base_path, &ConvertOptions::default())
+}
 
-    Ok(())
+/// Converts the given file, like [`convert`], but with custom rendering options.
+pub fn convert_with_options(
+    path: &str,
+    output_dir: &str,
+    base_path: &str,
+    options: &ConvertOptions,
+) -> Result<ConversionReport> {
+    convert_with_tracker(path, output_dir, base_path, options, Default::default())
 }
 
-pub fn convert(path: &str, output_dir: &str, base_path: &str) -> Result<()> {
+/// Converts the given file, like [`convert_with_options`], while reporting progress to
+/// `listener`.
+///
+/// Once `cancellation` is cancelled, the conversion stops before the next page and fails
+/// with a "Conversion cancelled" error. Pages that were already converted are left in
+/// `output_dir`.
+pub fn convert_with_progress(
+    path: &str,
+    output_dir: &str,
+    base_path: &str,
+    options: &ConvertOptions,
+    listener: Arc<dyn ProgressListener>,
+    cancellation: CancellationToken,
+) -> Result<ConversionReport> {
+    let progress = ProgressTracker::new(listener, cancellation);
+    convert_with_tracker(path, output_dir, base_path, options, Arc::new(progress))
+}
+
+fn convert_with_tracker(
+    path: &str,
+    output_dir: &str,
+    base_path: &str,
+    options: &ConvertOptions,
+    progress: Arc<ProgressTracker>,
+) -> Result<ConversionReport> {
     let extension: String = fs_driver().get_file_extension(path);
 
-    match extension.as_str() {
+    let sections = match extension.as_str() {
         ".one" => {
             let _name: String = fs_driver().get_file_name(path).expect("Missing file name");
             log!("Parsing .one file: {}", _name);
 
-            if path.contains("OneNote_RecycleBin") {
-                return Ok(());
+            if !options.includes_section_file(path) {
+                return Ok(ConversionReport::default());
             }
 
-            let section = Parser::new().parse_section(path)?;
-
+            let file = fs_driver().open_file(path)?;
             let section_output_dir = fs_driver().get_output_path(base_path, output_dir, path);
-            section::Renderer::new().render(&section, section_output_dir.to_owned())?;
+            progress.add_sections(1, file.byte_length());
+            let rendered =
+                section::render_file(file, path, section_output_dir, options, &progress)?;
+            vec![rendered.report]
         }
         ".onetoc2" => {
             let _name: String = fs_driver().get_file_name(path).expect("Missing file name");
             log!("Parsing .onetoc2 file: {}", _name);
 
-            let notebook = Parser::new().parse_notebook(path.to_owned())?;
+            let sections = Parser::new()
+                .with_recycle_bin(options.include_recycle_bin)
+                .list_notebook_sections(path.to_owned())?;
 
             let notebook_name = fs_driver()
                 .get_parent_dir(path)
@@ -69,19 +175,28 @@ pub fn convert(path: &str, output_dir: &str, base_path: &str) -> Result<()> {
             let notebook_output_dir = fs_driver().get_output_path(base_path, output_dir, path);
             log!("Notebook directory: {:?}", notebook_output_dir);
 
-            notebook::Renderer::new().render(&notebook, &notebook_name, &notebook_output_dir)?;
+            notebook::Renderer::new(options.clone(), progress).render(
+                &sections,
+                &notebook_name,
+                &notebook_output_dir,
+            )?
         }
         ".onepkg" => {
             let file_data = fs_driver().open_file(path)?;
-            convert_onepkg(file_data, output_dir)?;
+            convert_onepkg(file_data, output_dir, options, &progress)?
         }
         ext => return Err(eyre!("Invalid file extension: {}, file: {}", ext, path)),
-    }
+    };
 
-    Ok(())
+    Ok(ConversionReport { sections })
 }
 
-fn convert_onepkg(file_data: Box<dyn FileHandle>, output_dir: &str) -> Result<()> {
+fn convert_onepkg(
+    file_data: Box<dyn FileHandle>,
+    output_dir: &str,
+    options: &ConvertOptions,
+    progress: &Arc<ProgressTracker>,
+) -> Result<Vec<SectionReport>> {
     // .onepkg files are cabinet files
     let mut cabinet = cab::Cabinet::new(file_data)?;
 
@@ -93,6 +208,14 @@ fn convert_onepkg(file_data: Box<dyn FileHandle>, output_dir: &str) -> Result<()
 
     log!("Found {} files in onepkg archive.", file_paths.len());
 
+    let section_sizes = cabinet
+        .folder_entries()
+        .flat_map(|folder| folder.file_entries())
+        .filter(|entry| entry.name().ends_with(".one"))
+        .filter(|entry| options.includes_section_file(entry.name()))
+        .map(|entry| u64::from(entry.uncompressed_size()));
+    progress.add_sections(section_sizes.clone().count() as u32, section_sizes.sum());
+
     let build_output_dir = |file_path_in_archive: &str| -> Result<(String, String)> {
         let mut output_path = String::from(output_dir);
 
@@ -111,49 +234,140 @@ fn convert_onepkg(file_data: Box<dyn FileHandle>, output_dir: &str) -> Result<()
         Ok((output_path, fs_driver().sanitize_file_name(file_name)))
     };
 
-    let mut parser = Parser::new();
-    let results = file_paths
-        .iter()
-        .map(|file_path| -> Result<()> {
-            log!("File path {file_path}");
+    // Sections are extracted to temporary files, rather than read into memory, so that
+    // images and attachments can be read from them on demand. The files are kept out of the
+    // output directory, so that they're never mistaken for output.
+    let temp_dir = fs_driver().make_temp_dir()?;
+    let mut extract_section = |index: usize, file_path: &str| -> Result<Option<ExtractedSection>> {
+        log!("File path {file_path}");
 
-            if !file_path.ends_with(".one") {
-                log!("Skipping non-section file {file_path}");
-                return Ok(());
-            }
+        if !file_path.ends_with(".one") {
+            log!("Skipping non-section file {file_path}");
+            return Ok(None);
+        }
 
-            log!("Rendering {file_path}");
+        if !options.includes_section_file(file_path) {
+            log!("Skipping excluded section {file_path}");
+            return Ok(None);
+        }
 
-            let data = {
-                let mut file_data = cabinet.read_file(file_path)?;
-                let mut data = Vec::new();
-                file_data.read_to_end(&mut data)?;
-                data
-            };
+        progress.check_cancelled()?;
 
-            let (output_path, file_name) = build_output_dir(file_path)?;
-            let section = parser.parse_section_from_data(&data, &file_name)?;
-            section::Renderer::new().render(&section, output_path)?;
-            Ok(())
-        })
-        .zip(&file_paths);
+        let (output_path, file_name) = build_output_dir(file_path)?;
+        // Sections in different folders of the archive can have the same name
+        let temp_path = fs_driver().join(&temp_dir, &format!("{index}.one"));
 
-    let mut error_messages = vec![];
-    for (result, file_path) in results {
+        let mut file_data = cabinet.read_file(file_path)?;
+        let section = ExtractedSection {
+            temp_path,
+            output_path,
+            file_name,
+        };
+        let result = fs_driver().stream_to_file_with_buffer_size(
+            &section.temp_path,
+            &mut file_data,
+            options.stream_buffer_size,
+        );
         if let Err(error) = result {
-            let file_name = fs_driver().get_file_name(file_path).unwrap_or_default();
-            error_messages.push(format!("Error on file {file_name}: {error}"));
+            section.remove();
+            return Err(error.into());
         }
-    }
 
-    if !error_messages.is_empty() {
-        Err(ErrorKind::OnePkgImportFailure(format!(
-            "{} section(s) failed to import: {}",
-            error_messages.len(),
-            error_messages.join("\n")
-        ))
-        .into())
+        Ok(Some(section))
+    };
+
+    let render_section = |section: Option<ExtractedSection>| -> Result<Option<SectionReport>> {
+        let Some(section) = section else {
+            return Ok(None);
+        };
+        log!("Rendering {}", section.file_name);
+
+        let render = || -> Result<SectionReport> {
+            let file = fs_driver().open_file(&section.temp_path)?;
+            let rendered = section::render_file(
+                file,
+                &section.file_name,
+                section.output_path.clone(),
+                options,
+                progress,
+            )?;
+            Ok(rendered.report)
+        };
+        // The section holds a handle to the temporary file, so it must be dropped
+        // before the file can be removed
+        let result = render();
+        section.remove();
+
+        result.map(Some)
+    };
+
+    let rendered: Vec<Result<Option<SectionReport>>> = if parallel::ENABLED {
+        // The cabinet can only be read from one place at a time, so all sections are
+        // extracted before any are rendered
+        let extracted: Vec<_> = file_paths
+            .iter()
+            .enumerate()
+            .map(|(index, file_path)| extract_section(index, file_path))
+            .collect();
+        parallel::map(extracted, |extracted| render_section(extracted?))
     } else {
-        Ok(())
+        // Otherwise, each section is removed before the next is extracted, so that there's
+        // only one temporary file at a time
+        file_paths
+            .iter()
+            .enumerate()
+            .map(|(index, file_path)| render_section(extract_section(index, file_path)?))
+            .collect()
+    };
+
+    if let Err(error) = fs_driver().remove_dir(&temp_dir) {
+        log_warn!("Failed to remove temporary directory {temp_dir}: {error}");
+    }
+
+    // Report cancellation as such, rather than as a failure of each remaining section
+    progress.check_cancelled()?;
+
+    let mut reports = Vec::new();
+    for (rendered, file_path) in rendered.into_iter().zip(&file_paths) {
+        let error = match rendered {
+            Ok(None) => continue,
+            Ok(Some(mut report)) => {
+                report.source_path = file_path.clone();
+                reports.push(report);
+                continue;
+            }
+            Err(error) => error,
+        };
+
+        if options.error_policy == ErrorPolicy::Abort {
+            return Err(error);
+        }
+
+        let file_name = file_path.rsplit(['/', '\\']).next().unwrap_or(file_path);
+        let (name, _) = fs_driver().split_file_name(file_name);
+        reports.push(SectionReport {
+            error: Some(format!("{error:#}")),
+            ..SectionReport::new(&name, file_path)
+        });
+    }
+
+    Ok(reports)
+}
+
+/// A section that has been extracted from a .onepkg archive to a temporary file.
+struct ExtractedSection {
+    temp_path: String,
+    output_path: String,
+    file_name: String,
+}
+
+impl ExtractedSection {
+    fn remove(&self) {
+        let temp_path = &self.temp_path;
+        if fs_driver().exists(temp_path).unwrap_or(false)
+            && let Err(error) = fs_driver().remove_file(temp_path)
+        {
+            log_warn!("Failed to remove temporary file {temp_path}: {error}");
+        }
     }
 }
@@ -1,23 +1,52 @@
+use crate::options::ConvertOptions;
+use crate::progress::ProgressTracker;
+use crate::report::SectionReport;
+use crate::section::RenderedSection;
+use crate::tasks::{self, Task};
 use crate::templates::notebook::Toc;
-use crate::{section, templates};
+use crate::templates::tag_summary::TaggedPage;
+use crate::{parallel, section, templates};
 use color_eyre::eyre::Result;
 use palette::rgb::Rgb;
 use palette::{Alpha, ConvertFrom, Hsl, Saturate, Shade, Srgb};
-use parser::notebook::Notebook;
 use parser::property::common::Color;
-use parser::section::{Section, SectionEntry};
+use parser::section::SectionFile;
 use parser_utils::{fs_driver, log};
+use std::collections::HashSet;
+use std::sync::Arc;
 
 pub(crate) type RgbColor = Alpha<Rgb<palette::encoding::Srgb, u8>, f32>;
 
-pub(crate) struct Renderer;
+pub(crate) struct Renderer {
+    options: ConvertOptions,
+    progress: Arc<ProgressTracker>,
+    tasks: Vec<Task>,
+    tagged_pages: Vec<TaggedPage>,
+    reports: Vec<SectionReport>,
+    /// The paths of the files written for the notebook's sections.
+    files: HashSet<String>,
+}
 
 impl Renderer {
-    pub fn new() -> Self {
-        Renderer
+    pub fn new(options: ConvertOptions, progress: Arc<ProgressTracker>) -> Self {
+        Renderer {
+            options,
+            progress,
+            tasks: Vec::new(),
+            tagged_pages: Vec::new(),
+            reports: Vec::new(),
+            files: HashSet::new(),
+        }
     }
 
-    pub fn render(&mut self, notebook: &Notebook, name: &str, output_dir: &str) -> Result<()> {
+    /// Renders the notebook's sections, and returns a report for each of them. Sections that
+    /// can't be parsed are left out of the notebook's table of contents.
+    pub fn render(
+        &mut self,
+        sections: &[SectionFile],
+        name: &str,
+        output_dir: &str,
+    ) -> Result<Vec<SectionReport>> {
         log!("Notebook name: {:?} {:?}", name, output_dir);
         fs_driver().make_dir(output_dir)?;
 
@@ -26,40 +55,72 @@ impl Renderer {
 
         fs_driver().make_dir(&notebook_dir)?;
 
-        let mut toc = Vec::new();
+        let sections = &included_sections(sections, &self.options);
 
-        for entry in notebook.entries() {
+        // Collect the sections to render, along with the directories they should be
+        // rendered into. Each section is then parsed and rendered independently.
+        let mut jobs = Vec::new();
+        for entry in sections {
             match entry {
-                SectionEntry::Section(section) => {
-                    toc.push(Toc::Section(self.render_section(
-                        section,
-                        notebook_dir.clone(),
-                        output_dir.into(),
-                    )?));
-                }
-                SectionEntry::SectionGroup(group) => {
-                    let dir_name = fs_driver().sanitize_file_name(group.display_name());
+                SectionFile::Section(path) => jobs.push((path.as_str(), notebook_dir.clone())),
+                SectionFile::SectionGroup {
+                    display_name,
+                    entries,
+                } => {
+                    let dir_name = fs_driver().sanitize_file_name(display_name);
                     let section_group_dir =
                         fs_driver().join(notebook_dir.as_str(), dir_name.as_str());
 
                     log!("Section group directory: {:?}", section_group_dir);
                     fs_driver().make_dir(section_group_dir.as_str())?;
 
-                    let mut entries = Vec::new();
+                    for entry in entries {
+                        if let SectionFile::Section(path) = entry {
+                            jobs.push((path.as_str(), section_group_dir.clone()));
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut bytes_total = 0;
+        for (path, _) in &jobs {
+            bytes_total += fs_driver().open_file(path)?.byte_length();
+        }
+        self.progress.add_sections(jobs.len() as u32, bytes_total);
 
-                    for entry in group.entries() {
-                        if let SectionEntry::Section(section) = entry {
-                            entries.push(self.render_section(
-                                section,
-                                section_group_dir.clone(),
-                                output_dir.to_owned(),
-                            )?);
+        let options = &self.options;
+        let progress = &self.progress;
+        let mut rendered = parallel::map(jobs, |(path, section_dir)| {
+            render_section(options, progress, path, section_dir, output_dir)
+        })
+        .into_iter();
+
+        let mut toc = Vec::new();
+        for entry in sections {
+            match entry {
+                SectionFile::Section(_) => {
+                    if let Some(section) = self.collect_section(rendered.next())? {
+                        toc.push(Toc::Section(section));
+                    }
+                }
+                SectionFile::SectionGroup {
+                    display_name,
+                    entries,
+                } => {
+                    let mut group_entries = Vec::new();
+
+                    for entry in entries {
+                        if let SectionFile::Section(_) = entry
+                            && let Some(section) = self.collect_section(rendered.next())?
+                        {
+                            group_entries.push(section);
                         }
                     }
 
                     toc.push(templates::notebook::Toc::SectionGroup(
-                        group.display_name().to_string(),
-                        entries,
+                        display_name.to_string(),
+                        group_entries,
                     ))
                 }
             }
@@ -67,28 +128,125 @@ impl Renderer {
 
         templates::notebook::render(name, &toc)?;
 
+        if !self.tasks.is_empty() {
+            self.write_task_report(name, output_dir)?;
+        }
+
+        if !self.tagged_pages.is_empty() {
+            let html = templates::tag_summary::render(name, &self.tagged_pages)?;
+            let title = format!("{} tags", name);
+            let path = self.write_file(output_dir, &title, ".html", html.as_bytes())?;
+            log!("Tag summary: {}", path);
+        }
+
+        Ok(std::mem::take(&mut self.reports))
+    }
+
+    fn collect_section(
+        &mut self,
+        rendered: Option<Result<(Option<templates::notebook::Section>, RenderedSection)>>,
+    ) -> Result<Option<templates::notebook::Section>> {
+        let (section, mut rendered_section) =
+            rendered.expect("every listed section should have been rendered")?;
+        self.tasks.append(&mut rendered_section.tasks);
+        self.tagged_pages.append(&mut rendered_section.tagged_pages);
+        self.files.extend(rendered_section.files);
+        self.reports.push(rendered_section.report);
+
+        Ok(section)
+    }
+
+    fn write_task_report(&mut self, name: &str, output_dir: &str) -> Result<()> {
+        let title = format!("{} tasks", name);
+        let json = tasks::to_json(&self.tasks)?;
+        let json_path = self.write_file(output_dir, &title, ".json", json.as_bytes())?;
+        let ics = tasks::to_icalendar(&self.tasks);
+        let ics_path = self.write_file(output_dir, &title, ".ics", ics.as_bytes())?;
+        log!("Notebook tasks: {}, {}", json_path, ics_path);
+
         Ok(())
     }
 
-    fn render_section(
+    /// Writes a file to `parent_dir`, without replacing any of the files written for the
+    /// notebook's sections.
+    fn write_file(
         &mut self,
-        section: &Section,
-        notebook_dir: String,
-        base_dir: String,
-    ) -> Result<templates::notebook::Section> {
-        let mut renderer = section::Renderer::new();
-        let rendered_section = renderer.render(section, notebook_dir)?;
-        let section_path = &rendered_section.section_dir;
-        log!("section_path: {:?}", section_path);
-
-        let path_from_base_dir = String::from(fs_driver().remove_prefix(section_path, &base_dir));
-        log!("path_from_base_dir: {:?}", path_from_base_dir);
-        Ok(templates::notebook::Section {
-            name: section.display_name().to_string(),
-            path: path_from_base_dir,
-            color: section.color().map(prepare_color),
+        parent_dir: &str,
+        title: &str,
+        extension: &str,
+        data: &[u8],
+    ) -> Result<String> {
+        let filename = section::unique_safe_filename(&mut self.files, parent_dir, title, extension);
+        let path = fs_driver().join(parent_dir, &filename);
+        fs_driver().write_file(&path, data)?;
+        Ok(path)
+    }
+}
+
+/// Removes the sections (and section groups that only contain such sections) that are
+/// excluded by the options.
+fn included_sections(sections: &[SectionFile], options: &ConvertOptions) -> Vec<SectionFile> {
+    sections
+        .iter()
+        .filter_map(|entry| match entry {
+            SectionFile::Section(path) => {
+                options.includes_section_file(path).then(|| entry.clone())
+            }
+            SectionFile::SectionGroup {
+                display_name,
+                entries,
+            } => {
+                let included_entries = included_sections(entries, options);
+                (entries.is_empty() || !included_entries.is_empty()).then(|| {
+                    SectionFile::SectionGroup {
+                        display_name: display_name.clone(),
+                        entries: included_entries,
+                    }
+                })
+            }
         })
+        .collect()
+}
+
+fn render_section(
+    options: &ConvertOptions,
+    progress: &Arc<ProgressTracker>,
+    path: &str,
+    notebook_dir: String,
+    base_dir: &str,
+) -> Result<(Option<templates::notebook::Section>, RenderedSection)> {
+    // Page paths are relative to the directory the section is rendered into, which is a
+    // subdirectory for sections in section groups. Make them relative to the notebook's output
+    // directory, where the notebook's reports are written.
+    let section_parent_dir = fs_driver()
+        .remove_prefix(&notebook_dir, base_dir)
+        .to_string();
+
+    let file = fs_driver().open_file(path)?;
+    let mut rendered_section = section::render_file(file, path, notebook_dir, options, progress)?;
+    if rendered_section.report.error.is_some() {
+        return Ok((None, rendered_section));
     }
+
+    for task in &mut rendered_section.tasks {
+        task.prefix_page_path(&section_parent_dir);
+    }
+    for page in &mut rendered_section.tagged_pages {
+        page.path = format!("{}{}", section_parent_dir, page.path);
+    }
+
+    let section_path = &rendered_section.section_dir;
+    log!("section_path: {:?}", section_path);
+
+    let path_from_base_dir = String::from(fs_driver().remove_prefix(section_path, base_dir));
+    log!("path_from_base_dir: {:?}", path_from_base_dir);
+    let toc_entry = templates::notebook::Section {
+        name: rendered_section.report.name.clone(),
+        path: path_from_base_dir,
+        color: rendered_section.color.map(prepare_color),
+    };
+
+    Ok((Some(toc_entry), rendered_section))
 }
 
 fn prepare_color(color: Color) -> RgbColor {
@@ -0,0 +1,284 @@
+use parser_utils::fs_driver;
+use serde::Deserialize;
+
+/// Options that control how sections and notebooks are converted.
+///
+/// From JavaScript, the options are passed as an object with camel-cased keys (for example,
+/// `{ inkMode: 'svgFile', includeSections: ['Meetings'] }`). Missing keys use their default
+/// values.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ConvertOptions {
+    /// How pages are written.
+    pub output_format: OutputFormat,
+
+    /// Where images, attachments and other page assets are written.
+    pub asset_layout: AssetLayout,
+
+    /// Whether to convert sections in the notebook's recycle bin (`OneNote_RecycleBin`).
+    pub include_recycle_bin: bool,
+
+    /// The names of the sections to convert (without the `.one` extension). When empty,
+    /// all sections are converted.
+    pub include_sections: Vec<String>,
+
+    /// How ink (drawings and handwriting) is rendered.
+    pub ink_mode: InkMode,
+
+    /// How math equations are rendered.
+    pub math_mode: MathMode,
+
+    /// How the text that OneNote recognised in images (OCR) is included.
+    pub ocr_text: OcrTextMode,
+
+    /// What to do when a page fails to parse or render.
+    pub error_policy: ErrorPolicy,
+
+    /// How the files for pages are named.
+    pub page_naming: PageNaming,
+
+    /// How subpages are arranged in the output.
+    pub subpage_layout: SubpageLayout,
+
+    /// How the contents of each page are positioned.
+    pub page_layout: PageLayout,
+
+    /// The number of pixels per half inch, which is the unit OneNote measures positions and
+    /// sizes on the page in. Scales the positions and sizes of the page's contents.
+    pub px_per_half_inch: f32,
+
+    /// Whether to show a placeholder for page content that couldn't be converted
+    /// (for example, an unsupported video or shape).
+    pub content_placeholders: bool,
+
+    /// Whether to write a list of the tasks (to-do checkboxes and Outlook tasks) in each
+    /// section and notebook, as JSON (`<name> tasks.json`) and iCalendar (`<name> tasks.ics`)
+    /// files.
+    pub task_report: bool,
+
+    /// Whether to write a page that lists the tagged paragraphs in each notebook, grouped
+    /// by tag (`<name> tags.html`).
+    pub tag_summary: bool,
+
+    /// Whether to render audio and video attachments as `<audio>` and `<video>` elements,
+    /// rather than as links to the files.
+    pub media_elements: bool,
+
+    /// The maximum number of bytes to hold in memory while extracting a section from a
+    /// `.onepkg` file. Sections are extracted to a temporary file and read from it on demand,
+    /// so that large sections don't need to fit in memory.
+    pub stream_buffer_size: usize,
+}
+
+impl Default for ConvertOptions {
+    fn default() -> Self {
+        Self {
+            output_format: OutputFormat::default(),
+            asset_layout: AssetLayout::default(),
+            include_recycle_bin: false,
+            include_sections: Vec::new(),
+            ink_mode: InkMode::default(),
+            math_mode: MathMode::default(),
+            ocr_text: OcrTextMode::default(),
+            error_policy: ErrorPolicy::default(),
+            page_naming: PageNaming::default(),
+            subpage_layout: SubpageLayout::default(),
+            page_layout: PageLayout::default(),
+            px_per_half_inch: 48.0,
+            content_placeholders: true,
+            task_report: false,
+            tag_summary: false,
+            media_elements: false,
+            stream_buffer_size: 1024 * 1024,
+        }
+    }
+}
+
+impl ConvertOptions {
+    /// Returns whether the section file at `path` should be converted.
+    pub(crate) fn includes_section_file(&self, path: &str) -> bool {
+        if !self.include_recycle_bin && path.contains("OneNote_RecycleBin") {
+            return false;
+        }
+
+        // Sections in .onepkg files have Windows-style paths, so split on both separators
+        let file_name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+        let (name, _) = fs_driver().split_file_name(file_name);
+
+        self.include_sections.is_empty()
+            || self
+                .include_sections
+                .iter()
+                .any(|included| included.eq_ignore_ascii_case(&name))
+    }
+}
+
+/// How pages are written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OutputFormat {
+    /// A complete HTML document for each page.
+    #[default]
+    Html,
+
+    /// An HTML fragment for each page: a single `<div>` that contains the page's styles
+    /// and content, for embedding in another document.
+    HtmlFragment,
+}
+
+/// Where images, attachments and other page assets are written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AssetLayout {
+    /// In the section's folder, next to the pages.
+    #[default]
+    Flat,
+
+    /// In an `assets` folder within the section's folder.
+    Subfolder,
+}
+
+/// How ink (drawings and handwriting) is rendered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InkMode {
+    /// As `<svg>` elements within the page.
+    #[default]
+    InlineSvg,
+
+    /// As `.svg` files, referenced by `<img>` elements.
+    SvgFile,
+
+    /// Not at all.
+    Omit,
+}
+
+/// How math equations are rendered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MathMode {
+    /// As a Joplin-editable KaTeX block.
+    #[default]
+    Katex,
+
+    /// As plain LaTeX source, between `$` delimiters.
+    Latex,
+}
+
+/// How the text that OneNote recognised in images (OCR) is included.
+///
+/// Unless the mode is [`OcrTextMode::Omit`], the text is also listed in each page's
+/// [`PageReport`](crate::PageReport).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OcrTextMode {
+    /// In a `data-onenote-ocr-text` attribute on the image.
+    #[default]
+    Attribute,
+
+    /// In a hidden block after the image, so that it's found when searching the page's
+    /// text.
+    HiddenBlock,
+
+    /// Not at all.
+    Omit,
+}
+
+/// What to do when a section or page fails to parse or render.
+///
+/// Unless the policy is [`ErrorPolicy::Abort`], failures are recorded in the
+/// [`ConversionReport`](crate::ConversionReport) and the conversion continues.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ErrorPolicy {
+    /// Convert the remaining pages, and list the errors on an extra page in the section.
+    #[default]
+    ErrorPage,
+
+    /// Convert the remaining pages, without listing the errors in the output.
+    Skip,
+
+    /// Stop and fail at the first section or page that fails.
+    Abort,
+}
+
+/// How the files for pages are named. Pages without a title are named "Untitled Page N".
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PageNaming {
+    /// After the page's title.
+    #[default]
+    Title,
+
+    /// After the page's position in the section and its title (for example,
+    /// `003 Meeting notes.html`).
+    IndexAndTitle,
+
+    /// After the page's ID, which stays the same if the page is renamed.
+    Id,
+}
+
+/// How subpages are arranged in the output.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SubpageLayout {
+    /// Next to their parent pages, and indented in the section's table of contents.
+    #[default]
+    Flat,
+
+    /// Next to their parent pages, and in a nested list below their parent pages in the
+    /// section's table of contents.
+    NestedList,
+
+    /// In a folder named after their parent page, and in a nested list below their parent
+    /// pages in the section's table of contents.
+    Folders,
+}
+
+impl SubpageLayout {
+    /// Whether the section's table of contents nests subpages below their parent pages.
+    pub(crate) fn is_nested(self) -> bool {
+        self != SubpageLayout::Flat
+    }
+}
+
+/// How the contents of each page are positioned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PageLayout {
+    /// At their positions on OneNote's page canvas. Outlines that OneNote would have moved
+    /// out of each other's way (for example, because the text above them got longer) can
+    /// overlap.
+    #[default]
+    Absolute,
+
+    /// One below the other, top to bottom and then left to right, keeping their horizontal
+    /// offsets. On pages where OneNote moves contents out of each other's way, contents with
+    /// a collision priority (usually just the title) come first.
+    Flow,
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ConvertOptions, ErrorPolicy, InkMode, OutputFormat};
+
+    #[test]
+    fn should_deserialize_partial_options() {
+        let options: ConvertOptions = serde_json::from_str(
+            r#"{ "inkMode": "svgFile", "errorPolicy": "skip", "includeSections": ["Notes"] }"#,
+        )
+        .unwrap();
+
+        assert_eq!(options.ink_mode, InkMode::SvgFile);
+        assert_eq!(options.error_policy, ErrorPolicy::Skip);
+        assert!(options.includes_section_file("/notebook/notes.one"));
+        assert!(options.includes_section_file("Section group\\Notes.one"));
+        assert!(!options.includes_section_file("/notebook/Other.one"));
+        assert!(!options.includes_section_file("/notebook/OneNote_RecycleBin/Notes.one"));
+
+        // Other options should keep their defaults
+        assert_eq!(options.output_format, OutputFormat::Html);
+        assert!(options.content_placeholders);
+        assert_eq!(options.stream_buffer_size, 1024 * 1024);
+    }
+}
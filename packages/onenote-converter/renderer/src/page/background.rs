@@ -0,0 +1,52 @@
+use crate::options::OutputFormat;
+use crate::page::Renderer;
+use crate::utils::StyleSet;
+use parser::page::{PageBackgroundStyle, RuleLines};
+
+/// The color OneNote uses for rule lines when the page doesn't specify one.
+const DEFAULT_RULE_LINE_COLOR: &str = "rgb(202, 235, 253)";
+
+impl<'a> Renderer<'a> {
+    /// Draws the page's rule lines or grid as a background of the page.
+    pub(crate) fn render_page_background(&mut self, style: &PageBackgroundStyle) {
+        let mut images = vec![];
+        let mut sizes = vec![];
+
+        if let Some(lines) = style.horizontal_lines() {
+            images.push(line_gradient("to bottom", lines));
+            sizes.push(format!("100% {}", self.px(lines.spacing())));
+        }
+
+        if let Some(lines) = style.vertical_lines() {
+            images.push(line_gradient("to right", lines));
+            sizes.push(format!("{} 100%", self.px(lines.spacing())));
+        }
+
+        if images.is_empty() {
+            return;
+        }
+
+        let mut styles = StyleSet::new();
+        styles.set("background-image", images.join(", "));
+        styles.set("background-size", sizes.join(", "));
+
+        // Page fragments nest their styles within the page's container
+        let selector = match self.section.options.output_format {
+            OutputFormat::Html => "body",
+            OutputFormat::HtmlFragment => "&",
+        };
+        self.global_styles.insert(selector.to_string(), styles);
+    }
+}
+
+fn line_gradient(direction: &str, lines: &RuleLines) -> String {
+    let color = lines
+        .color()
+        .map(|color| format!("rgb({}, {}, {})", color.r(), color.g(), color.b()))
+        .unwrap_or_else(|| DEFAULT_RULE_LINE_COLOR.to_string());
+
+    format!(
+        "linear-gradient({}, {} 1px, transparent 1px)",
+        direction, color
+    )
+}
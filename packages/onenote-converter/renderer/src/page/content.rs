@@ -7,11 +7,11 @@ use parser::contents::Content;
 impl<'a> Renderer<'a> {
     pub(crate) fn render_contents(&mut self, contents: &[Content]) -> Result<String> {
         let mut result = vec![];
-        let mut ink_builder = InkBuilder::new(true);
+        let mut ink_builder = InkBuilder::new(true, self.section.options.px_per_half_inch);
 
         for content in contents {
             if !matches!(content, Content::Ink(_)) {
-                result.push(ink_builder.finish());
+                result.push(self.render_ink(ink_builder.finish())?);
             }
 
             match content {
@@ -30,13 +30,14 @@ impl<'a> Renderer<'a> {
                 Content::Ink(ink) => {
                     ink_builder.push(ink, None);
                 }
-                Content::Unknown => {
+                Content::Unknown(content) => {
                     warn!("Page with unknown content");
+                    result.push(self.render_unknown_content(content));
                 }
             }
         }
 
-        result.push(ink_builder.finish());
+        result.push(self.render_ink(ink_builder.finish())?);
         Ok(result.join(""))
     }
 }
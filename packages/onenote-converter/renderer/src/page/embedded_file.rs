@@ -1,6 +1,6 @@
 use crate::{
     page::Renderer,
-    utils::{StyleSet, html_entities},
+    utils::{AttributeSet, StyleSet, html_entities},
 };
 use color_eyre::Result;
 use parser::contents::EmbeddedFile;
@@ -9,10 +9,13 @@ use parser_utils::{fs_driver, log};
 
 impl<'a> Renderer<'a> {
     pub(crate) fn render_embedded_file(&mut self, file: &EmbeddedFile) -> Result<String> {
+        self.counts.embedded_files += 1;
+
+        let asset_dir = self.asset_dir()?;
         let filename = self
             .section
-            .to_unique_safe_filename(&self.output, file.filename())?;
-        let path = fs_driver().join(&self.output, &filename);
+            .to_unique_safe_filename(&asset_dir, file.filename())?;
+        let path = fs_driver().join(&asset_dir, &filename);
 
         log!("Rendering embedded file: {:?}", path);
         let mut reader = file.read()?;
@@ -22,26 +25,59 @@ impl<'a> Renderer<'a> {
         if let Some(offset_x_half_inches) = file.offset_horizontal() {
             styles.set("margin-left", format!("{}in", offset_x_half_inches / 2.));
         }
-        if let Some(offset_y_half_inches) = file.offset_vertical() {
+        if let Some(offset_y_half_inches) = file.offset_vertical()
+            && !self.flow_layout
+        {
             styles.set("margin-top", format!("{}in", offset_y_half_inches / 2.));
         }
 
+        let mut attrs = AttributeSet::new();
+        if let Some(recorded_at) = file.recorded_at() {
+            attrs.set(
+                "data-onenote-recorded-at",
+                recorded_at.unix_timestamp().to_string(),
+            );
+        }
+        if let Some(duration) = file.recording_duration() {
+            attrs.set("data-onenote-duration", duration.as_millis().to_string());
+        }
+
         let file_type = Self::guess_type(file);
+        let escaped_filename = html_entities(&filename);
+        let escaped_url = html_entities(&self.asset_url(&filename));
         let content = match file_type {
-            // TODO: As of 01-06-2026, Joplin has limited or no support for <video> and <audio> elements in HTML notes.
+            // As of 01-06-2026, Joplin has limited or no support for <video> and <audio> elements in HTML notes.
             // For example, <video> elements can only reference web URLs and <audio> elements aren't
-            // supported at all.
+            // supported at all. Media elements are only rendered when enabled in the options.
             //
             // See also: https://github.com/laurent22/joplin/issues/11939.
-            // FileType::Audio => content = format!("<audio class=\"media-player media-audio\"controls><source src=\"{}\" type=\"audio/x-wav\"></source></audio>", filename),
-            // FileType::Video => content = format!("<video controls src=\"{}\" {}></video>", filename, styles.to_html_attr()),
+            FileType::Audio | FileType::Video if self.section.options.media_elements => {
+                let (element, class) = match file_type {
+                    FileType::Video => ("video", "media-player media-video"),
+                    _ => ("audio", "media-player media-audio"),
+                };
+
+                attrs.set("class", class.to_string());
+                if styles.len() > 0 {
+                    attrs.set("style", styles.to_string());
+                }
+
+                let mut source_attrs = AttributeSet::new();
+                source_attrs.set("src", self.asset_url(&filename));
+                if let Some(mime) = mime_guess::from_path(&filename).first() {
+                    source_attrs.set("type", mime.essence_str().to_string());
+                }
+
+                format!(
+                    "<{element} controls {attrs}><source {source_attrs} /><a href=\"{escaped_url}\">{escaped_filename}</a></{element}>"
+                )
+            }
             FileType::Unknown | FileType::Audio | FileType::Video => {
                 styles.set("font-size", "11pt".into());
                 styles.set("line-height", "17px".into());
-                let style_attr = styles.to_html_attr();
+                attrs.set("style", styles.to_string());
 
-                let escaped_filename = html_entities(&filename);
-                format!("<p {style_attr}><a href=\"{escaped_filename}\">{escaped_filename}</a></p>")
+                format!("<p {attrs}><a href=\"{escaped_url}\">{escaped_filename}</a></p>")
             }
         };
 
@@ -1,68 +1,66 @@
-use std::io::{Cursor, Read};
-
+use crate::options::OcrTextMode;
 use crate::page::Renderer;
-use crate::utils::{detect_png, px, AttributeSet, StyleSet};
+use crate::utils::{AttributeSet, StyleSet, html_entities};
 use color_eyre::Result;
 use parser::contents::Image;
+use parser::page::format_ocr_text;
 use parser_utils::{fs_driver, log, log_warn};
 
 impl<'a> Renderer<'a> {
     pub(crate) fn render_image(&mut self, image: &Image) -> Result<String> {
+        self.render_image_as(image, image.image_filename())
+    }
+
+    /// Renders `image`, writing its data to an asset named after `filename` if given.
+    pub(crate) fn render_image_as(
+        &mut self,
+        image: &Image,
+        filename: Option<&str>,
+    ) -> Result<String> {
+        self.counts.images += 1;
+
         let mut content = String::new();
 
         if let Some(mut reader) = image.read()? {
-            // Read up to the first kilobyte so that determine_image_filename can do
-            // file type detection
-            let image_start_bytes = read_file_start(&mut reader)?;
-            let is_xps_printout_with_pdf_extension_detected =
-                is_xps_printout_with_pdf_extension(image, &image_start_bytes);
-
-            let (filename, should_write) =
-                self.determine_image_filename(image, &image_start_bytes)?;
-            let path = fs_driver().join(&self.output, &filename);
+            let filename = self.determine_image_filename(image, filename)?;
+            let path = fs_driver().join(&self.asset_dir()?, &filename);
 
-            if should_write {
-                log!("Rendering image: {:?}", path);
-
-                let mut reader = Cursor::new(image_start_bytes).chain(reader);
-                fs_driver().stream_to_file(&path, &mut reader)?;
-            } else {
-                log!("Reusing image: {:?}", path);
-            }
+            log!("Rendering image: {:?}", path);
+            fs_driver().stream_to_file(&path, &mut reader)?;
 
             let mut attrs = AttributeSet::new();
             let mut styles = StyleSet::new();
 
-            attrs.set("src", filename);
-
-            if is_xps_printout(image) || is_xps_printout_with_pdf_extension_detected {
-                if let Some(page_number) = image.displayed_page_number() {
-                    attrs.set("data-onenote-page-number", page_number.to_string());
-                }
-            }
+            attrs.set("src", self.asset_url(&filename));
 
             if let Some(text) = image.alt_text() {
                 attrs.set("alt", text.to_string());
             }
 
+            let ocr_block =
+                self.render_ocr_text(image.text(), image.text_language_tag(), &mut attrs);
+
             if let Some(width) = image.layout_max_width() {
-                styles.set("max-width", px(width));
+                styles.set("max-width", self.px(width));
             }
 
             if let Some(height) = image.layout_max_height() {
-                styles.set("max-height", px(height));
-            }
-
-            if image.offset_horizontal().is_some() || image.offset_vertical().is_some() {
-                styles.set("position", "absolute".to_string());
+                styles.set("max-height", self.px(height));
             }
 
-            if let Some(offset) = image.offset_horizontal() {
-                styles.set("left", px(offset));
-            }
+            let (left, top) = (image.offset_horizontal(), image.offset_vertical());
+            if image.is_background() {
+                // Background images stay behind the other content with the flow layout too
+                styles.extend(self.absolute_position_styles(left, top));
 
-            if let Some(offset) = image.offset_vertical() {
-                styles.set("top", px(offset));
+                // z-index only applies to positioned elements
+                if left.is_none() && top.is_none() {
+                    styles.set("position", "relative".to_string());
+                }
+                styles.set("z-index", "-1".to_string());
+                attrs.set("data-onenote-background", "true".to_string());
+            } else {
+                styles.extend(self.position_styles(left, top));
             }
 
             if styles.len() > 0 {
@@ -70,46 +68,49 @@ impl<'a> Renderer<'a> {
             }
 
             content.push_str(&format!("<img {} />", attrs));
+            content.push_str(&ocr_block);
         }
 
         Ok(self.render_with_note_tags(image.note_tags(), content))
     }
 
-    fn determine_image_filename(
-        &mut self,
-        image: &Image,
-        initial_bytes: &[u8],
-    ) -> Result<(String, bool)> {
-        if let Some(name) = image.image_filename() {
-            if is_reusable_image_filename(name) {
-                let filename = fs_driver().sanitize_file_name(name);
-                let path = fs_driver().join(&self.output, &filename);
-                return Ok((filename, !fs_driver().exists(&path)?));
+    /// Adds the text that OneNote recognised in an image (OCR) to the image's `attrs`, or
+    /// returns a hidden block that contains it, depending on the [`OcrTextMode`].
+    pub(crate) fn render_ocr_text(
+        &self,
+        text: Option<&str>,
+        language: Option<&'static str>,
+        attrs: &mut AttributeSet,
+    ) -> String {
+        let Some(text) = text.map(format_ocr_text).filter(|text| !text.is_empty()) else {
+            return String::new();
+        };
+
+        match self.section.options.ocr_text {
+            OcrTextMode::Attribute => {
+                attrs.set("data-onenote-ocr-text", text);
+                String::new()
             }
+            OcrTextMode::HiddenBlock => {
+                let mut block_attrs = AttributeSet::new();
+                block_attrs.set("class", "ocr-text".to_string());
+                if let Some(language) = language
+                    && Some(language) != self.language
+                {
+                    block_attrs.set("lang", language.to_string());
+                }
 
-            if is_xps_printout_with_pdf_extension(image, initial_bytes) {
-                let (base_name, _) = fs_driver().split_file_name(name);
-                let name = format!("{base_name}.xps");
-                let filename = fs_driver().sanitize_file_name(&name);
-                let path = fs_driver().join(&self.output, &filename);
-                return Ok((filename, !fs_driver().exists(&path)?));
+                format!("<div {} hidden>{}</div>", block_attrs, html_entities(&text))
             }
+            OcrTextMode::Omit => String::new(),
+        }
+    }
 
-            // Workaround: PDF printout pages are PNG images, but have an image_filename with extension .PDF.
-            // Add a PNG extension to these files so that they are imported properly:
-            let name = {
-                let is_pdf = fs_driver()
-                    .get_file_extension(name)
-                    .eq_ignore_ascii_case(".pdf");
-                if is_pdf && detect_png(initial_bytes) {
-                    format!("{name}.png")
-                } else {
-                    name.to_string()
-                }
-            };
+    fn determine_image_filename(&mut self, image: &Image, name: Option<&str>) -> Result<String> {
+        let asset_dir = self.asset_dir()?;
 
-            let filename = self.section.to_unique_safe_filename(&self.output, &name)?;
-            return Ok((filename, true));
+        if let Some(name) = name {
+            return Ok(self.section.to_unique_safe_filename(&asset_dir, name)?);
         }
 
         let ext = image.extension().unwrap_or_else(|| {
@@ -118,46 +119,7 @@ impl<'a> Renderer<'a> {
         });
         let filename = self
             .section
-            .to_unique_safe_filename(&self.output, &format!("image{}", ext))?;
-        Ok((filename, true))
+            .to_unique_safe_filename(&asset_dir, &format!("image{}", ext))?;
+        Ok(filename)
     }
 }
-
-fn is_reusable_image_filename(filename: &str) -> bool {
-    let extension = fs_driver().get_file_extension(filename);
-    extension.eq_ignore_ascii_case(".xps") || extension.eq_ignore_ascii_case(".oxps")
-}
-
-fn is_xps_printout(image: &Image) -> bool {
-    image
-        .image_filename()
-        .map(is_reusable_image_filename)
-        .unwrap_or(false)
-}
-
-fn is_xps_printout_with_pdf_extension(image: &Image, initial_bytes: &[u8]) -> bool {
-    // Get the file name from the OneNote image.
-    let Some(filename) = image.image_filename() else {
-        // If there is no file name, this cannot be an XPS printout.
-        return false;
-    };
-    // Get the file extension, for example ".pdf".
-    let extension = fs_driver().get_file_extension(filename);
-
-    // The image must point to one page of a multi-page printout.
-    image.displayed_page_number().is_some()
-        // The file name must say that this is a PDF.
-        && extension.eq_ignore_ascii_case(".pdf")
-        // The real file starts like a ZIP package, which XPS files use.
-        && initial_bytes.starts_with(b"PK")
-        // A real PDF starts with "%PDF", so do not treat real PDFs as XPS.
-        && !initial_bytes.starts_with(b"%PDF")
-}
-
-fn read_file_start(reader: &mut Box<dyn Read>) -> Result<Vec<u8>> {
-    let size: usize = 1024;
-    let mut sub_reader = reader.by_ref().take(size as u64);
-    let mut bytes = Vec::with_capacity(size);
-    sub_reader.read_to_end(&mut bytes)?;
-    Ok(bytes)
-}
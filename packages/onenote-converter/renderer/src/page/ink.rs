@@ -1,8 +1,12 @@
 use core::f32;
 
+use crate::options::InkMode;
+use crate::page::Renderer;
 use crate::utils::{AttributeSet, StyleSet};
+use color_eyre::Result;
 use itertools::Itertools;
 use parser::contents::{Ink, InkBoundingBox, InkPoint, InkStroke};
+use parser_utils::{fs_driver, log};
 
 type Vec2 = (f32, f32);
 
@@ -17,21 +21,118 @@ struct InkPart {
     offset_px: Vec2,
 }
 
+/// Ink that has been laid out by an [`InkBuilder`].
+pub(crate) struct BuiltInk {
+    path: String,
+    view_box: String,
+    size: Vec2,
+    /// Styles that position the drawing
+    styles: StyleSet,
+    /// Styles for the element that wraps inline (embedded) ink
+    container_styles: Option<StyleSet>,
+}
+
+impl BuiltInk {
+    /// Renders the ink as an `<svg>` element.
+    pub(crate) fn to_inline_svg(&self) -> String {
+        let mut attrs = AttributeSet::new();
+        attrs.set("viewBox", self.view_box.clone());
+        attrs.set("style", self.styles.to_string());
+
+        self.wrap(format!("<svg {}>{}</svg>", attrs, self.path))
+    }
+
+    /// Renders the ink as a standalone SVG file.
+    pub(crate) fn to_svg_file(&self) -> String {
+        let mut attrs = AttributeSet::new();
+        attrs.set("xmlns", "http://www.w3.org/2000/svg".into());
+        attrs.set("viewBox", self.view_box.clone());
+        attrs.set("width", self.size.0.to_string());
+        attrs.set("height", self.size.1.to_string());
+
+        format!("<svg {}>{}</svg>", attrs, self.path)
+    }
+
+    /// Renders the ink as an `<img>` element that shows the SVG file at `src`.
+    pub(crate) fn to_img(&self, src: String) -> String {
+        let mut attrs = AttributeSet::new();
+        attrs.set("src", src);
+        attrs.set("alt", "".into());
+        attrs.set("style", self.styles.to_string());
+
+        self.wrap(format!("<img {}>", attrs))
+    }
+
+    fn wrap(&self, content: String) -> String {
+        if let Some(container_styles) = &self.container_styles {
+            format!(
+                "<span style=\"{}\" class=\"ink-text\">{}</span>",
+                container_styles, content
+            )
+        } else {
+            content
+        }
+    }
+}
+
+impl Renderer<'_> {
+    /// Renders ink laid out by an [`InkBuilder`], as configured by the ink mode.
+    pub(crate) fn render_ink(&mut self, ink: Option<BuiltInk>) -> Result<String> {
+        let Some(ink) = ink else {
+            return Ok(String::new());
+        };
+        self.counts.ink_drawings += 1;
+
+        match self.section.options.ink_mode {
+            InkMode::InlineSvg => Ok(ink.to_inline_svg()),
+            InkMode::SvgFile => {
+                let asset_dir = self.asset_dir()?;
+                let filename = self
+                    .section
+                    .to_unique_safe_filename(&asset_dir, "ink.svg")?;
+                let path = fs_driver().join(&asset_dir, &filename);
+
+                log!("Rendering ink: {:?}", path);
+                fs_driver().write_file(&path, ink.to_svg_file().as_bytes())?;
+
+                Ok(ink.to_img(self.asset_url(&filename)))
+            }
+            InkMode::Omit => Ok(String::new()),
+        }
+    }
+}
+
 pub(crate) struct InkBuilder {
     parts: Vec<InkPart>,
     embedded: bool,
+    /// Whether the drawing follows the previous content, rather than being positioned
+    /// absolutely
+    in_flow: bool,
+    px_per_half_inch: f32,
 }
 
 impl InkBuilder {
-    const SVG_SCALING_FACTOR: f32 = 2540.0 / 96.0;
-
-    pub(crate) fn new(embedded: bool) -> Self {
+    pub(crate) fn new(embedded: bool, px_per_half_inch: f32) -> Self {
         Self {
             parts: vec![],
             embedded,
+            in_flow: false,
+            px_per_half_inch,
         }
     }
 
+    /// The number of ink units (1/1000 cm, or 1/2540 in) per pixel.
+    fn svg_scaling_factor(&self) -> f32 {
+        1270.0 / self.px_per_half_inch
+    }
+
+    /// Lays out page-level ink for the flow layout, which keeps the drawing's horizontal
+    /// offset but places it below the previous content.
+    pub(crate) fn in_flow(mut self, in_flow: bool) -> Self {
+        self.in_flow = in_flow && !self.embedded;
+        self
+    }
+
     fn reset(&mut self) {
         self.parts.clear();
     }
@@ -60,7 +161,7 @@ impl InkBuilder {
 
         let display_bounding_box = ink
             .bounding_box()
-            .or_else(|| display_bounding_box.map(|bb| bb.scale(Self::SVG_SCALING_FACTOR)))
+            .or_else(|| display_bounding_box.map(|bb| bb.scale(self.svg_scaling_factor())))
             .filter(|_| self.embedded);
 
         let (x_min, width) = get_boundary(strokes, |p| p.x());
@@ -69,18 +170,18 @@ impl InkBuilder {
         let x_min = x_min - stroke_strength / 2.0;
         let y_min = y_min - stroke_strength / 2.0;
 
-        let width = width + stroke_strength + Self::SVG_SCALING_FACTOR;
-        let height = height + stroke_strength + Self::SVG_SCALING_FACTOR;
+        let width = width + stroke_strength + self.svg_scaling_factor();
+        let height = height + stroke_strength + self.svg_scaling_factor();
 
-        let height_px = (height / (Self::SVG_SCALING_FACTOR)).ceil();
-        let width_px = (width / (Self::SVG_SCALING_FACTOR)).ceil();
+        let height_px = (height / (self.svg_scaling_factor())).ceil();
+        let width_px = (width / (self.svg_scaling_factor())).ceil();
 
         // Use the ink's bounding box for width/height if larger. Inline ink relies on the preceding
         // ink having a specific size for correct positioning. Using the width_px/height_px of the actual content
         // often results in an incorrect bounding box and incorrectly-positioned ink.
         let display_size_px = if let Some(ink_bbox) = display_bounding_box {
-            let display_width = ink_bbox.width() / Self::SVG_SCALING_FACTOR;
-            let display_height = ink_bbox.height() / Self::SVG_SCALING_FACTOR;
+            let display_width = ink_bbox.width() / self.svg_scaling_factor();
+            let display_height = ink_bbox.height() / self.svg_scaling_factor();
             (display_width, display_height)
         } else {
             (width_px, height_px)
@@ -89,14 +190,16 @@ impl InkBuilder {
         let display_y_min = display_bounding_box.map(|bb| bb.y()).unwrap_or_default();
         let display_x_min = display_bounding_box.map(|bb| bb.x()).unwrap_or_default();
 
-        let top_px = (y_min - display_y_min) / Self::SVG_SCALING_FACTOR + offset_vertical * 48.0;
-        let left_px = (x_min - display_x_min) / Self::SVG_SCALING_FACTOR + offset_horizontal * 48.0;
+        let top_px = (y_min - display_y_min) / self.svg_scaling_factor()
+            + offset_vertical * self.px_per_half_inch;
+        let left_px = (x_min - display_x_min) / self.svg_scaling_factor()
+            + offset_horizontal * self.px_per_half_inch;
 
         let translate = (
-            left_px * Self::SVG_SCALING_FACTOR - x_min,
-            top_px * Self::SVG_SCALING_FACTOR - y_min,
+            left_px * self.svg_scaling_factor() - x_min,
+            top_px * self.svg_scaling_factor() - y_min,
         );
-        let scale = 1. / Self::SVG_SCALING_FACTOR;
+        let scale = 1. / self.svg_scaling_factor();
         let path = self.render_ink_path(strokes, scale, translate);
         self.parts.push(InkPart {
             content: path,
@@ -106,16 +209,17 @@ impl InkBuilder {
         })
     }
 
-    pub(crate) fn finish(&mut self) -> String {
+    /// Returns the ink pushed since the last call, laid out as a single drawing.
+    pub(crate) fn finish(&mut self) -> Option<BuiltInk> {
         let result = self.build();
         self.reset();
 
         result
     }
 
-    fn build(&self) -> String {
+    fn build(&self) -> Option<BuiltInk> {
         if self.parts.is_empty() {
-            return "".into();
+            return None;
         }
 
         let path = self.parts.iter().map(|part| &part.content).join("");
@@ -146,45 +250,46 @@ impl InkBuilder {
         let content_size = round_svg_vec(content_size);
         let display_size = round_svg_vec(display_size);
 
-        let mut attrs = AttributeSet::new();
-        attrs.set(
-            "viewBox",
-            format!(
-                "{} {} {} {}",
-                offset.0,
-                offset.1,
-                // Use content_size for the width/height to ensure that the full content
-                // is visible.
-                content_size.0,
-                content_size.1
-            ),
+        let view_box = format!(
+            "{} {} {} {}",
+            offset.0,
+            offset.1,
+            // Use content_size for the width/height to ensure that the full content
+            // is visible.
+            content_size.0,
+            content_size.1
         );
 
         let mut styles = StyleSet::new();
-        styles.set("position", "absolute".into());
-        styles.set("left", format!("{}px", offset.0));
-        styles.set("top", format!("{}px", offset.1));
+        if self.in_flow {
+            styles.set("display", "block".into());
+            styles.set("margin-left", format!("{}px", offset.0));
+        } else {
+            styles.set("position", "absolute".into());
+            styles.set("left", format!("{}px", offset.0));
+            styles.set("top", format!("{}px", offset.1));
+        }
         styles.set("width", format!("{}px", content_size.0));
         styles.set("height", format!("{}px", content_size.1));
         // Allow selecting text behind the ink:
         styles.set("pointer-events", "none".into());
 
-        attrs.set("style", styles.to_string());
-
-        if self.embedded {
+        let container_styles = self.embedded.then(|| {
             let mut span_styles = StyleSet::new();
             // Use display_size instead of content_size to size the container. This ensures that
             // embedded ink that comes after this ink has the correct position.
             span_styles.set("width", format!("{}px", display_size.0));
             span_styles.set("height", format!("{}px", display_size.1));
-
-            format!(
-                "<span style=\"{}\" class=\"ink-text\"><svg {}>{}</svg></span>",
-                span_styles, attrs, path
-            )
-        } else {
-            format!("<svg {}>{}</svg>", attrs, path)
-        }
+            span_styles
+        });
+
+        Some(BuiltInk {
+            path,
+            view_box,
+            size: content_size,
+            styles,
+            container_styles,
+        })
     }
 
     fn render_ink_path(&self, strokes: &[InkStroke], scale: f32, translate: Vec2) -> String {
@@ -0,0 +1,211 @@
+use crate::page::Renderer;
+use crate::utils::StyleSet;
+use parser::page::{PageContent, Title};
+
+/// How far apart (in half-inch increments) the tops of two items can be for the flow
+/// layout to treat them as side by side, and order them left to right.
+const ROW_TOLERANCE: f32 = 0.25;
+
+/// An item on a page: the title or one of the page's contents.
+#[derive(Clone, Copy)]
+pub(crate) enum PageItem<'a> {
+    Title(&'a Title),
+    Content(&'a PageContent),
+}
+
+impl PageItem<'_> {
+    /// Where the item is on OneNote's page canvas.
+    fn placement(&self) -> Placement {
+        let (left, top, collision_priority) = match self {
+            PageItem::Title(title) => (
+                Some(title.offset_horizontal()),
+                Some(title.offset_vertical()),
+                title.layout_collision_priority(),
+            ),
+            PageItem::Content(PageContent::Outline(outline)) => (
+                outline.offset_horizontal(),
+                outline.offset_vertical(),
+                outline.layout_collision_priority(),
+            ),
+            PageItem::Content(PageContent::Image(image)) => (
+                image.offset_horizontal(),
+                image.offset_vertical(),
+                image.layout_collision_priority(),
+            ),
+            PageItem::Content(PageContent::EmbeddedFile(file)) => (
+                file.offset_horizontal(),
+                file.offset_vertical(),
+                file.layout_collision_priority(),
+            ),
+            PageItem::Content(PageContent::Ink(ink)) => (
+                ink.offset_horizontal(),
+                ink.offset_vertical(),
+                ink.layout_collision_priority(),
+            ),
+            PageItem::Content(PageContent::Printout(printout)) => match printout.pages().first() {
+                Some(page) => (
+                    page.offset_horizontal(),
+                    page.offset_vertical(),
+                    page.layout_collision_priority(),
+                ),
+                None => (None, None, None),
+            },
+            PageItem::Content(PageContent::Unknown(content)) => (
+                content.offset_horizontal(),
+                content.offset_vertical(),
+                content.layout_collision_priority(),
+            ),
+        };
+
+        Placement {
+            left: left.unwrap_or_default(),
+            top: top.unwrap_or_default(),
+            collision_priority,
+        }
+    }
+}
+
+/// Where an item is on OneNote's page canvas.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Placement {
+    /// The horizontal offset from the page origin in half-inch increments.
+    left: f32,
+    /// The vertical offset from the page origin in half-inch increments.
+    top: f32,
+    /// The item's priority when OneNote moves items so that they don't overlap (if present).
+    collision_priority: Option<u32>,
+}
+
+/// Orders the page's items for the flow layout.
+///
+/// If OneNote moves the page's items so that they don't overlap (`resolve_collisions`),
+/// items with a collision priority (usually just the title) come first, lowest value first.
+/// The other items are split into rows of items whose tops are at about the same height,
+/// and ordered top to bottom and then left to right.
+pub(crate) fn flow_order<'a>(
+    items: impl IntoIterator<Item = PageItem<'a>>,
+    resolve_collisions: bool,
+) -> Vec<PageItem<'a>> {
+    let items: Vec<_> = items
+        .into_iter()
+        .map(|item| (item.placement(), item))
+        .collect();
+    flow_order_by_placement(items, resolve_collisions)
+}
+
+fn flow_order_by_placement<T>(items: Vec<(Placement, T)>, resolve_collisions: bool) -> Vec<T> {
+    // Collision priorities only matter when OneNote resolves collisions
+    let (mut prioritized, mut others): (Vec<_>, Vec<_>) = items
+        .into_iter()
+        .partition(|(placement, _)| resolve_collisions && placement.collision_priority.is_some());
+
+    prioritized.sort_by_key(|(placement, _)| placement.collision_priority);
+    others.sort_by(|(a, _), (b, _)| a.top.total_cmp(&b.top));
+
+    let mut rows: Vec<Vec<(Placement, T)>> = Vec::new();
+    for (placement, item) in others {
+        match rows.last_mut() {
+            Some(row) if placement.top - row[0].0.top <= ROW_TOLERANCE => {
+                row.push((placement, item))
+            }
+            _ => rows.push(vec![(placement, item)]),
+        }
+    }
+    for row in &mut rows {
+        row.sort_by(|(a, _), (b, _)| a.left.total_cmp(&b.left));
+    }
+
+    prioritized
+        .into_iter()
+        .chain(rows.into_iter().flatten())
+        .map(|(_, item)| item)
+        .collect()
+}
+
+impl<'a> Renderer<'a> {
+    /// Returns the styles that place an item at its offsets from the page origin (in
+    /// half-inch increments).
+    ///
+    /// With the flow layout, the item is a block below the previous item, and only keeps
+    /// its horizontal offset.
+    pub(crate) fn position_styles(&self, left: Option<f32>, top: Option<f32>) -> StyleSet {
+        if !self.flow_layout {
+            return self.absolute_position_styles(left, top);
+        }
+
+        let mut styles = StyleSet::new();
+
+        if left.is_some() || top.is_some() {
+            styles.set("display", "block".to_string());
+        }
+
+        if let Some(offset) = left {
+            styles.set("margin-left", self.px(offset));
+        }
+
+        styles
+    }
+
+    /// Returns the styles that place an item at its offsets from the page origin (in
+    /// half-inch increments), regardless of the page layout.
+    pub(crate) fn absolute_position_styles(&self, left: Option<f32>, top: Option<f32>) -> StyleSet {
+        let mut styles = StyleSet::new();
+
+        if left.is_some() || top.is_some() {
+            styles.set("position", "absolute".to_string());
+        }
+
+        if let Some(offset) = left {
+            styles.set("left", self.px(offset));
+        }
+
+        if let Some(offset) = top {
+            styles.set("top", self.px(offset));
+        }
+
+        styles
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Placement, flow_order_by_placement};
+
+    fn placement(left: f32, top: f32, collision_priority: Option<u32>) -> Placement {
+        Placement {
+            left,
+            top,
+            collision_priority,
+        }
+    }
+
+    #[test]
+    fn should_order_items_in_rows() {
+        let items = vec![
+            (placement(10.0, 8.0, None), "bottom"),
+            (placement(12.0, 2.1, None), "right"),
+            (placement(1.0, 2.0, None), "left"),
+            (placement(1.0, 0.5, Some(0)), "title"),
+            (placement(1.0, 0.0, None), "top"),
+        ];
+
+        assert_eq!(
+            flow_order_by_placement(items, true),
+            ["title", "top", "left", "right", "bottom"]
+        );
+    }
+
+    #[test]
+    fn should_ignore_collision_priorities_without_collision_resolution() {
+        let items = vec![
+            (placement(1.0, 2.0, None), "body"),
+            (placement(1.0, 0.5, Some(0)), "title"),
+            (placement(1.0, 0.0, None), "top"),
+        ];
+
+        assert_eq!(
+            flow_order_by_placement(items, false),
+            ["top", "title", "body"]
+        );
+    }
+}
@@ -1,12 +1,11 @@
 use crate::page::Renderer;
-use crate::utils::{AttributeSet, StyleSet, px};
+use crate::page::symbol_font::SymbolFont;
+use crate::utils::{AttributeSet, StyleSet};
 use color_eyre::Result;
-use parser::contents::{List, OutlineElement};
+use parser::contents::{List, ListFormat, ListFormatPart, NumberStyle, OutlineElement};
 use parser::property::common::ColorRef;
 use parser_utils::log_warn;
 
-const FORMAT_NUMBERED_LIST: char = '\u{fffd}';
-
 impl<'a> Renderer<'a> {
     pub(crate) fn render_list<'b>(
         &mut self,
@@ -16,9 +15,11 @@ impl<'a> Renderer<'a> {
         let mut contents = String::new();
         let mut in_list = false;
         let mut list_end = None;
+        let mut number = 0;
 
         for (element, parent_level, current_level) in elements {
-            if !in_list && self.is_onenote_list(element) {
+            let starts_list = !in_list && self.is_onenote_list(element);
+            if starts_list {
                 let tags = self.list_tags(element);
                 let list_start = tags.0;
                 list_end = Some(tags.1);
@@ -36,12 +37,33 @@ impl<'a> Renderer<'a> {
                 in_list = false;
             }
 
+            // Keep track of the item's number, for the markers of nested lists that include
+            // their parent items' numbers
+            let list = element.list_contents().first();
+            if let Some(list) = list {
+                number = match list.list_restart() {
+                    Some(restart) => {
+                        if !starts_list {
+                            self.list_item_value = Some(restart);
+                        }
+                        restart
+                    }
+                    None if starts_list => 1,
+                    None => number + 1,
+                };
+                self.list_numbers.push(number);
+            }
+
             contents.push_str(&self.render_outline_element(
                 element,
                 parent_level,
                 current_level,
                 indents,
             )?);
+
+            if list.is_some() {
+                self.list_numbers.pop();
+            }
         }
 
         if in_list {
@@ -57,7 +79,7 @@ impl<'a> Renderer<'a> {
             .first()
             .expect("no list contents defined");
 
-        let tag = if self.is_numbered_list(list) {
+        let tag = if list.format().is_numbered() {
             "ol"
         } else {
             "ul"
@@ -72,40 +94,38 @@ impl<'a> Renderer<'a> {
         let mut container_style = StyleSet::new();
         let mut item_style = StyleSet::new();
         let mut marker_style = StyleSet::new();
+        let mut number_style = StyleSet::new();
 
         let mut list_font = list.list_font();
-        let mut list_format = list.list_format();
+        let mut list_format = list.list_format().to_vec();
         let mut font_size = list.font_size();
 
-        self.fix_wingdings(&mut list_font, &mut list_format, &mut font_size);
+        self.translate_bullet(&mut list_font, &mut list_format, &mut font_size);
 
-        match list_format {
-            [FORMAT_NUMBERED_LIST, '\u{0}', ..] => {}
-            [FORMAT_NUMBERED_LIST, '\u{1}', ..] => {
-                container_style.set("list-style-type", "upper-roman".to_string())
+        let format = list.format();
+        match format.number_style() {
+            Some(style) if is_plain_number_format(&format) => {
+                if style != NumberStyle::Decimal {
+                    container_style.set("list-style-type", list_style_type(style).to_string());
+                }
             }
-            [FORMAT_NUMBERED_LIST, '\u{2}', ..] => {
-                container_style.set("list-style-type", "lower-roman".to_string())
+            Some(_) => number_style.set(
+                "content",
+                number_marker_content(&format, &self.list_numbers),
+            ),
+            None => {
+                if let [c] = list_format[..] {
+                    marker_style.set("content", format!("'{}'", c))
+                }
             }
-            [FORMAT_NUMBERED_LIST, '\u{3}', ..] => {
-                container_style.set("list-style-type", "upper-latin".to_string())
-            }
-            [FORMAT_NUMBERED_LIST, '\u{4}', ..] => {
-                container_style.set("list-style-type", "lower-latin".to_string())
-            }
-            [FORMAT_NUMBERED_LIST, c, ..] => {
-                log_warn!("Not implemented: Numbered list style: {}", c);
-            }
-            [c] => marker_style.set("content", format!("'{}'", c)),
-            _ => {}
         }
 
         let bullet_spacing = spacing.unwrap_or(0.2);
 
-        item_style.set("padding-left", px(bullet_spacing));
+        item_style.set("padding-left", self.px(bullet_spacing));
 
         container_style.set("position", "relative".to_string());
-        container_style.set("left", px(-bullet_spacing));
+        container_style.set("left", self.px(-bullet_spacing));
 
         if let Some(font) = list_font {
             marker_style.set("font-family", font.to_string());
@@ -133,53 +153,51 @@ impl<'a> Renderer<'a> {
 
         let class = self.gen_class("list");
 
-        if marker_style.len() > 0 {
+        if marker_style.len() > 0 || number_style.len() > 0 {
             attrs.set("class", class.clone());
+        }
 
+        if marker_style.len() > 0 {
             self.global_styles
                 .insert(format!(".{} li::marker", class), marker_style);
         }
 
+        // The numbers shouldn't apply to the items of nested lists
+        if number_style.len() > 0 {
+            self.global_styles
+                .insert(format!(".{} > li::marker", class), number_style);
+        }
+
         self.global_styles
             .insert(format!(".{} li", class), item_style);
 
         attrs
     }
 
-    fn fix_wingdings(
+    /// Translates bullets in symbol fonts (like Wingdings) to Unicode, so that they
+    /// display without the font.
+    fn translate_bullet(
         &self,
         list_font: &mut Option<&str>,
-        list_format: &mut &[char],
+        list_format: &mut Vec<char>,
         font_size: &mut Option<u16>,
     ) {
-        match list_font.zip(list_format.first()) {
-            // See http://www.alanwood.net/demos/wingdings.html
-            Some(("Wingdings", '\u{a7}')) => *list_format = &['\u{25aa}'],
-            Some(("Wingdings", '\u{a8}')) => *list_format = &['\u{25fb}'],
-            Some(("Wingdings", '\u{77}')) => *list_format = &['\u{2b25}'],
-
-            // See http://www.alanwood.net/demos/wingdings-2.html
-            Some(("Wingdings 2", '\u{ae}')) => *list_format = &['\u{25c6}'],
-
-            // See http://www.alanwood.net/demos/wingdings-3.html
-            Some(("Wingdings 3", '\u{7d}')) => {
-                *list_format = &['\u{25b6}'];
-                *font_size = Some(18);
-            }
+        let Some(symbol_font) = list_font.and_then(SymbolFont::from_name) else {
+            return;
+        };
+        let Some(bullet) = symbol_font.translate(&String::from_iter(list_format.iter())) else {
+            return;
+        };
 
-            _ => return,
+        // The Unicode triangle is larger than the Wingdings one
+        if symbol_font == SymbolFont::Wingdings3 && list_format[..] == ['\u{7d}'] {
+            *font_size = Some(18);
         }
 
+        *list_format = bullet.chars().collect();
         *list_font = Some("Calibri");
     }
 
-    fn is_numbered_list(&self, list: &List) -> bool {
-        list.list_format()
-            .first()
-            .map(|c| *c == FORMAT_NUMBERED_LIST)
-            .unwrap_or_default()
-    }
-
     fn is_onenote_list(&self, element: &OutlineElement) -> bool {
         !element.list_contents().is_empty()
     }
@@ -192,3 +210,77 @@ impl<'a> Renderer<'a> {
         self.is_onenote_list(element) || self.is_tag_list(element)
     }
 }
+
+/// Whether the list's markers can be written using the default marker of the list's
+/// `list-style-type`: a number followed by a period.
+fn is_plain_number_format(format: &ListFormat) -> bool {
+    matches!(
+        format.parts(),
+        [ListFormatPart::Number { .. }, ListFormatPart::Text(suffix)] if suffix == "."
+    )
+}
+
+/// Returns the CSS `content` of the markers of a numbered list, given the numbers of the
+/// list items that contain the list. These don't change within the list, so they're
+/// included as text.
+fn number_marker_content(format: &ListFormat, parent_numbers: &[i32]) -> String {
+    let mut content = Vec::new();
+
+    for part in format.parts() {
+        match part {
+            ListFormatPart::Text(text) => content.push(css_string(text)),
+            ListFormatPart::Number { level: 0, style } => {
+                content.push(format!("counter(list-item, {})", list_style_type(*style)))
+            }
+            ListFormatPart::Number { level, style } => {
+                let parent_number = parent_numbers
+                    .len()
+                    .checked_sub(*level as usize)
+                    .and_then(|index| parent_numbers.get(index));
+                if let Some(number) = parent_number {
+                    content.push(css_string(&style.format(*number)));
+                }
+            }
+        }
+    }
+    content.push(css_string(" "));
+
+    content.join(" ")
+}
+
+fn list_style_type(style: NumberStyle) -> &'static str {
+    match style {
+        NumberStyle::Decimal => "decimal",
+        NumberStyle::UpperRoman => "upper-roman",
+        NumberStyle::LowerRoman => "lower-roman",
+        NumberStyle::UpperLatin => "upper-latin",
+        NumberStyle::LowerLatin => "lower-latin",
+        NumberStyle::Unknown(code) => {
+            log_warn!("Not implemented: Numbered list style: {}", code);
+            "decimal"
+        }
+    }
+}
+
+fn css_string(text: &str) -> String {
+    format!("'{}'", text.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::number_marker_content;
+    use parser::contents::ListFormat;
+
+    #[test]
+    fn should_include_parent_numbers_in_marker() {
+        let format: Vec<char> = "\u{fffd}\u{0}.\u{fffd}\u{4}.\u{fffd}\u{2})"
+            .chars()
+            .collect();
+        let format = ListFormat::from_chars(&format);
+
+        assert_eq!(
+            number_marker_content(&format, &[5, 2, 3]),
+            "'2' '.' 'c' '.' counter(list-item, lower-roman) ')' ' '"
+        );
+    }
+}
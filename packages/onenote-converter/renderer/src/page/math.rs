@@ -1,3 +1,4 @@
+use crate::options::MathMode;
 use crate::page::Renderer;
 use crate::utils::{StyleSet, html_entities};
 use color_eyre::Result;
@@ -14,6 +15,14 @@ impl<'a> Renderer<'a> {
 
         let source = format!("{}{}", self.render_tex_macros(&tex), tex,);
 
+        if self.section.options.math_mode == MathMode::Latex {
+            return Ok(format!(
+                "<span {}>${}$</span>",
+                style.to_html_attr(),
+                html_entities(source.trim()),
+            ));
+        }
+
         let opening_html = format!("<span class=\"joplin-editable\" {}>", style.to_html_attr(),);
         let source_html = format!(
             "<span class=\"joplin-source\" data-joplin-language=\"katex\" data-joplin-source-open=\"$\" data-joplin-source-close=\"$\" style=\"display: none;\">{}</span>",
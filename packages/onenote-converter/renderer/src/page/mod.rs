@@ -1,28 +1,53 @@
+use crate::options::{AssetLayout, PageLayout};
+use crate::page::layout::{PageItem, flow_order};
+use crate::report::ItemCounts;
 use crate::templates::page::PageTimestamps;
-use crate::utils::StyleSet;
+use crate::templates::tag_summary::TaggedParagraph;
+use crate::utils::{StyleSet, px};
 use crate::{page::ink::InkBuilder, section};
 use color_eyre::Result;
-use parser::page::{Page, PageContent};
+use parser::contents::RichText;
+use parser::page::{Page, PageContent, TextContent, Title, visit_text_content};
+use parser_utils::fs_driver;
 use std::collections::{HashMap, HashSet};
 
+pub(crate) mod background;
 pub(crate) mod content;
 pub(crate) mod embedded_file;
 pub(crate) mod image;
 pub(crate) mod ink;
+pub(crate) mod layout;
 pub(crate) mod list;
 pub(crate) mod math;
 pub(crate) mod note_tag;
 pub(crate) mod outline;
+pub(crate) mod placeholder;
+pub(crate) mod printout;
 pub(crate) mod rich_text;
+pub(crate) mod symbol_font;
 pub(crate) mod table;
 
+/// The folder that assets are written to with [`AssetLayout::Subfolder`].
+const ASSETS_DIR_NAME: &str = "assets";
+
 pub(crate) struct Renderer<'a> {
     output: String,
     section: &'a mut section::Renderer,
 
     in_list: bool,
+    /// Whether the page's contents use the flow layout, rather than being positioned
+    /// absolutely.
+    flow_layout: bool,
+    /// The language of the content being rendered, if known.
+    language: Option<&'static str>,
+    /// The numbers of the list items that contain the content being rendered.
+    list_numbers: Vec<i32>,
+    /// The number of the next list item, if its numbering restarts.
+    list_item_value: Option<i32>,
     global_styles: HashMap<String, StyleSet>,
     global_classes: HashSet<String>,
+    pub(crate) tagged_paragraphs: Vec<TaggedParagraph>,
+    pub(crate) counts: ItemCounts,
 }
 
 impl<'a> Renderer<'a> {
@@ -31,43 +56,28 @@ impl<'a> Renderer<'a> {
             output,
             section,
             in_list: false,
+            flow_layout: false,
+            language: None,
+            list_numbers: Vec::new(),
+            list_item_value: None,
             global_styles: HashMap::new(),
             global_classes: HashSet::new(),
+            tagged_paragraphs: Vec::new(),
+            counts: ItemCounts::default(),
         }
     }
 
     pub(crate) fn render_page(&mut self, page: &Page, page_order_index: u32) -> Result<String> {
         let title_text = page.title_text().unwrap_or("Untitled Page".to_string());
+        self.language = page_language(page);
+        self.flow_layout = self.section.options.page_layout == PageLayout::Flow;
 
-        let mut content = String::new();
-
-        if let Some(title) = page.title() {
-            let mut styles = StyleSet::new();
-            styles.set("position", "absolute".to_string());
-            styles.set(
-                "top",
-                format!("{}px", (title.offset_vertical() * 48.0 + 24.0).round()),
-            );
-            styles.set(
-                "left",
-                format!("{}px", (title.offset_horizontal() * 48.0 + 48.0).round()),
-            );
-
-            let mut title_field = format!("<div class=\"title\" style=\"{}\">", styles);
-
-            for outline in title.contents() {
-                title_field.push_str(&self.render_outline(outline)?)
-            }
-
-            title_field.push_str("</div>");
+        self.render_page_background(page.background_style());
 
-            content.push_str(&title_field);
-        }
-
-        let page_content = self.render_page_contents(page.contents())?;
-        content.push_str(&page_content);
+        let content = self.render_page_contents(page)?;
 
         crate::templates::page::render(
+            self.section.options.output_format,
             &page.link_target_id(),
             page_order_index,
             &PageTimestamps {
@@ -75,11 +85,39 @@ impl<'a> Renderer<'a> {
                 updated_time: page.updated_time().unix_timestamp(),
             },
             &title_text,
+            self.language,
             &content,
             &self.global_styles,
         )
     }
 
+    /// Returns the directory that the page's images, attachments and other assets are
+    /// written to, creating it if necessary.
+    pub(crate) fn asset_dir(&self) -> Result<String> {
+        match self.section.options.asset_layout {
+            AssetLayout::Flat => Ok(self.output.clone()),
+            AssetLayout::Subfolder => {
+                let dir = fs_driver().join(&self.output, ASSETS_DIR_NAME);
+                fs_driver().make_dir(&dir)?;
+                Ok(dir)
+            }
+        }
+    }
+
+    /// Returns the URL of a file in [`Renderer::asset_dir`], relative to the page.
+    pub(crate) fn asset_url(&self, filename: &str) -> String {
+        match self.section.options.asset_layout {
+            AssetLayout::Flat => filename.to_string(),
+            AssetLayout::Subfolder => format!("{}/{}", ASSETS_DIR_NAME, filename),
+        }
+    }
+
+    /// Converts a length in half-inch increments to pixels, at the scale set by
+    /// [`ConvertOptions::px_per_half_inch`](crate::ConvertOptions::px_per_half_inch).
+    pub(crate) fn px(&self, half_inches: f32) -> String {
+        px(half_inches, self.section.options.px_per_half_inch)
+    }
+
     pub(crate) fn gen_class(&mut self, prefix: &str) -> String {
         let mut i = 0;
 
@@ -95,15 +133,76 @@ impl<'a> Renderer<'a> {
         }
     }
 
-    fn render_page_contents(&mut self, contents: &[PageContent]) -> Result<String> {
+    fn render_title(&mut self, title: &Title) -> Result<String> {
+        let left = self.px(title.offset_horizontal() + 1.0);
+
+        let mut styles = StyleSet::new();
+        if self.flow_layout {
+            styles.set("margin-left", left);
+        } else {
+            styles.set("position", "absolute".to_string());
+            styles.set("top", self.px(title.offset_vertical() + 0.5));
+            styles.set("left", left);
+        }
+
+        let mut title_field = format!("<div class=\"title\" style=\"{}\">", styles);
+
+        for outline in title.contents() {
+            title_field.push_str(&self.render_outline(outline)?)
+        }
+
+        title_field.push_str("</div>");
+
+        Ok(title_field)
+    }
+
+    fn render_page_contents(&mut self, page: &Page) -> Result<String> {
+        let (title, contents) = (page.title(), page.contents());
         let mut result = vec![];
-        let mut ink_builder = InkBuilder::new(false);
+        let mut ink_builder =
+            InkBuilder::new(false, self.section.options.px_per_half_inch).in_flow(self.flow_layout);
+
+        // Background images are drawn first, so that they stay behind other content
+        let (backgrounds, contents): (Vec<_>, Vec<_>) = contents.iter().partition(|content| {
+            content
+                .image()
+                .map(|image| image.is_background())
+                .unwrap_or(false)
+        });
+
+        let title = title.map(PageItem::Title);
+        let backgrounds = backgrounds.into_iter().map(PageItem::Content);
+        let contents = contents.into_iter().map(PageItem::Content);
+        let items: Vec<_> = if self.flow_layout {
+            // Background images stay behind the other content, rather than taking part in
+            // the flow
+            backgrounds
+                .chain(flow_order(
+                    title.into_iter().chain(contents),
+                    page.layout_resolve_child_collisions(),
+                ))
+                .collect()
+        } else {
+            title
+                .into_iter()
+                .chain(backgrounds)
+                .chain(contents)
+                .collect()
+        };
 
-        for content in contents {
-            if !matches!(content, PageContent::Ink(_)) {
-                result.push(ink_builder.finish());
+        for item in items {
+            if !matches!(item, PageItem::Content(PageContent::Ink(_))) {
+                result.push(self.render_ink(ink_builder.finish())?);
             }
 
+            let content = match item {
+                PageItem::Title(title) => {
+                    result.push(self.render_title(title)?);
+                    continue;
+                }
+                PageItem::Content(content) => content,
+            };
+
             match content {
                 PageContent::Outline(outline) => {
                     result.push(self.render_outline(outline)?);
@@ -117,11 +216,59 @@ impl<'a> Renderer<'a> {
                 PageContent::Ink(ink) => {
                     ink_builder.push(ink, None);
                 }
-                PageContent::Unknown => {}
+                PageContent::Printout(printout) => {
+                    result.push(self.render_printout(printout)?);
+                }
+                PageContent::Unknown(content) => {
+                    result.push(self.render_unknown_content(content));
+                }
             }
         }
-        result.push(ink_builder.finish());
+        result.push(self.render_ink(ink_builder.finish())?);
 
         Ok(result.join(""))
     }
 }
+
+/// Lists the paragraphs on the page (including its title and the paragraphs in tables), in
+/// order.
+pub(crate) fn paragraphs(page: &Page) -> Vec<&RichText> {
+    let mut paragraphs = Vec::new();
+
+    visit_text_content(page, &mut |content| {
+        if let TextContent::RichText(text) = content {
+            paragraphs.push(text);
+        }
+    });
+
+    paragraphs
+}
+
+/// Returns the language that most of the page's text is in.
+fn page_language(page: &Page) -> Option<&'static str> {
+    let mut lengths: Vec<(&'static str, usize)> = Vec::new();
+
+    // The date and time below the title are in the language of OneNote's user interface,
+    // rather than the page's.
+    let paragraphs = paragraphs(page)
+        .into_iter()
+        .filter(|text| !text.is_title_date() && !text.is_title_time());
+    for text in paragraphs {
+        let Some(language) = rich_text::paragraph_language(text) else {
+            continue;
+        };
+
+        let length = text.text().chars().count();
+        match lengths.iter_mut().find(|(tag, _)| *tag == language) {
+            Some((_, total)) => *total += length,
+            None => lengths.push((language, length)),
+        }
+    }
+
+    // Prefer the language that appears first if several have the same length
+    lengths
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, length)| *length)
+        .map(|(language, _)| language)
+}
@@ -1,11 +1,14 @@
 use crate::page::Renderer;
+use crate::templates::tag_summary::{ParagraphTag, TaggedParagraph};
 use crate::utils::{AttributeSet, StyleSet};
-use parser::contents::{NoteTag, OutlineElement};
+use parser::contents::{NoteTag, OutlineElement, RichText};
 use parser::property::common::ColorRef;
 use parser::property::note_tag::{ActionItemStatus, NoteTagShape};
 use parser_utils::log_warn;
 use std::borrow::Cow;
 
+const EXCERPT_MAX_CHARS: usize = 200;
+
 const COLOR_BLUE: &str = "#4673b7";
 const COLOR_GREEN: &str = "#369950";
 const COLOR_RED: &str = "#db5b4d";
@@ -35,7 +38,7 @@ const ICON_PAPER: &str = "📄";
 const ICON_PEN: &str = "🖊️";
 const ICON_PERSON: &str = "👤";
 const ICON_PHONE: &str = "📞";
-const ICON_QUESTION_MARK: &str = "❓";
+pub(crate) const ICON_QUESTION_MARK: &str = "❓";
 const ICON_SQUARE_RED: &str = "🟥";
 const ICON_SQUARE_YELLOW: &str = "🟨";
 const ICON_SQUARE_ORANGE: &str = "🟧";
@@ -178,6 +181,42 @@ impl<'a> Renderer<'a> {
         Some((markup, styles))
     }
 
+    /// Records a tagged paragraph for the notebook's tag summary and returns an anchor that
+    /// links to it.
+    pub(crate) fn add_tag_summary_anchor(&mut self, text: &RichText) -> String {
+        let anchor = format!("note-tag-{}", self.tagged_paragraphs.len());
+
+        let text_content = text.text().trim();
+        let mut excerpt: String = text_content.chars().take(EXCERPT_MAX_CHARS).collect();
+        if excerpt.len() < text_content.len() {
+            excerpt.push('…');
+        }
+
+        let tags = text
+            .note_tags()
+            .iter()
+            .filter_map(|note_tag| {
+                let definition = note_tag.definition()?;
+                let shape = definition.shape();
+                Some(ParagraphTag {
+                    label: definition.label().to_string(),
+                    shape,
+                    checked: shape
+                        .is_checkbox()
+                        .then(|| note_tag.item_status().completed()),
+                })
+            })
+            .collect();
+
+        self.tagged_paragraphs.push(TaggedParagraph {
+            anchor: anchor.clone(),
+            excerpt,
+            tags,
+        });
+
+        format!("<a id=\"{}\"></a>", anchor)
+    }
+
     pub(crate) fn has_note_tag(&self, element: &OutlineElement) -> bool {
         element
             .contents()
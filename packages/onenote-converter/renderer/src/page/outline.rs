@@ -1,10 +1,12 @@
 use crate::page::Renderer;
-use crate::utils::{AttributeSet, StyleSet, px};
+use crate::utils::{AttributeSet, StyleSet};
 use color_eyre::Result;
 use parser::contents::{Outline, OutlineElement, OutlineItem};
 
 impl<'a> Renderer<'a> {
     pub(crate) fn render_outline(&mut self, outline: &Outline) -> Result<String> {
+        self.counts.outlines += 1;
+
         let mut attrs = AttributeSet::new();
         let mut styles = StyleSet::new();
         let mut contents = String::new();
@@ -18,20 +20,26 @@ impl<'a> Renderer<'a> {
                 width.max(13.0)
             };
 
-            styles.set("width", px(outline_width));
+            // With the flow layout, outlines with a tight layout shrink to fit their contents,
+            // but not below OneNote's minimum widths
+            if self.flow_layout && outline.layout_tight_layout() {
+                let min_width = outline
+                    .layout_minimum_outline_width()
+                    .into_iter()
+                    .chain(outline.layout_reserved_width())
+                    .reduce(f32::max);
+                if let Some(min_width) = min_width {
+                    styles.set("min-width", self.px(min_width));
+                }
+
+                styles.set("width", "fit-content".to_string());
+                styles.set("max-width", self.px(outline_width));
+            } else {
+                styles.set("width", self.px(outline_width));
+            }
         };
 
-        if outline.offset_horizontal().is_some() || outline.offset_vertical().is_some() {
-            styles.set("position", "absolute".to_string());
-        }
-
-        if let Some(offset) = outline.offset_horizontal() {
-            styles.set("left", px(offset));
-        }
-
-        if let Some(offset) = outline.offset_vertical() {
-            styles.set("top", px(offset));
-        }
+        styles.extend(self.position_styles(outline.offset_horizontal(), outline.offset_vertical()));
 
         if styles.len() > 0 {
             attrs.set("style", styles.to_string());
@@ -81,9 +89,13 @@ impl<'a> Renderer<'a> {
         attrs.set("class", "outline-element".into());
 
         let mut styles = StyleSet::new();
-        styles.set("margin-left", px(indent_width));
+        styles.set("margin-left", self.px(indent_width));
         attrs.set("style", styles.to_string());
 
+        if let Some(value) = self.list_item_value.take() {
+            attrs.set("value", value.to_string());
+        }
+
         if is_list {
             contents.push_str(&format!("<li {}>", attrs));
         } else {
@@ -0,0 +1,44 @@
+use crate::page::Renderer;
+use crate::page::note_tag::ICON_QUESTION_MARK;
+use crate::utils::{AttributeSet, html_entities};
+use parser::contents::UnknownContent;
+
+impl<'a> Renderer<'a> {
+    /// Renders a block marking where content that couldn't be converted was on the page.
+    pub(crate) fn render_unknown_content(&mut self, content: &UnknownContent) -> String {
+        if !self.section.options.content_placeholders {
+            return String::new();
+        }
+
+        let label = match content.type_name() {
+            Some(name) => format!("Unsupported content ({})", name),
+            None => format!("Unsupported content (JCID 0x{:08X})", content.jcid()),
+        };
+
+        let mut styles =
+            self.position_styles(content.offset_horizontal(), content.offset_vertical());
+
+        if let Some(width) = content.layout_max_width() {
+            styles.set("width", self.px(width));
+        }
+
+        if let Some(height) = content.layout_max_height() {
+            styles.set("min-height", self.px(height));
+        }
+
+        let mut attrs = AttributeSet::new();
+        attrs.set("class", "content-placeholder".to_string());
+        attrs.set("data-onenote-jcid", format!("0x{:08X}", content.jcid()));
+
+        if styles.len() > 0 {
+            attrs.set("style", styles.to_string());
+        }
+
+        format!(
+            "<div {}><span class=\"note-tag-icon -normal\"><span class=\"text\">{}</span></span>{}</div>",
+            attrs,
+            ICON_QUESTION_MARK,
+            html_entities(&label)
+        )
+    }
+}
@@ -0,0 +1,77 @@
+use crate::page::Renderer;
+use crate::utils::{AttributeSet, StyleSet, html_entities};
+use color_eyre::Result;
+use itertools::Itertools;
+use parser::page::Printout;
+use parser_utils::{fs_driver, log};
+
+impl<'a> Renderer<'a> {
+    pub(crate) fn render_printout(&mut self, printout: &Printout) -> Result<String> {
+        self.counts.printouts += 1;
+
+        let mut content = String::new();
+
+        // Documents that are also attached to the page are linked by the attachment
+        if !printout.document_is_embedded_file()
+            && let Some(mut reader) = printout.read_document()?
+        {
+            let asset_dir = self.asset_dir()?;
+            let filename = self
+                .section
+                .to_unique_safe_filename(&asset_dir, printout.filename())?;
+            let path = fs_driver().join(&asset_dir, &filename);
+
+            log!("Rendering printout: {:?}", path);
+            fs_driver().stream_to_file(&path, &mut reader)?;
+
+            let mut styles = StyleSet::new();
+            styles.set("font-size", "11pt".into());
+            styles.set("line-height", "17px".into());
+
+            if let Some(first_page) = printout.pages().first() {
+                styles.extend(
+                    self.position_styles(
+                        first_page.offset_horizontal(),
+                        first_page.offset_vertical(),
+                    ),
+                );
+            }
+
+            let mut attrs = AttributeSet::new();
+            attrs.set("style", styles.to_string());
+            attrs.set("data-onenote-page-count", printout.page_count().to_string());
+
+            // Without previews, the page images aren't rendered, so their text is added to the
+            // link instead
+            let mut ocr_block = String::new();
+            if !printout.has_previews() {
+                let pages = printout.pages();
+                let text = pages.iter().filter_map(|page| page.text()).join("\n");
+                let language = pages.iter().find_map(|page| page.text_language_tag());
+                ocr_block = self.render_ocr_text(Some(&text), language, &mut attrs);
+            }
+
+            content.push_str(&format!(
+                "<p {}><a href=\"{}\">{}</a></p>",
+                attrs,
+                html_entities(&self.asset_url(&filename)),
+                html_entities(&filename)
+            ));
+            content.push_str(&ocr_block);
+        }
+
+        if printout.has_previews() {
+            for page in printout.pages() {
+                // The page images are named after the document (for example, `report_1.pdf`),
+                // but only contain a raster preview of the page
+                let name = page.image_filename().map(|name| {
+                    let (base_name, _) = fs_driver().split_file_name(name);
+                    format!("{}{}", base_name, page.extension().unwrap_or(".png"))
+                });
+                content.push_str(&self.render_image_as(page, name.as_deref())?);
+            }
+        }
+
+        Ok(content)
+    }
+}
@@ -1,7 +1,9 @@
 use crate::page::Renderer;
 use crate::page::ink::InkBuilder;
-use crate::utils::{AttributeSet, StyleSet, html_entities, px, url_encode};
+use crate::page::symbol_font::SymbolFont;
+use crate::utils::{AttributeSet, StyleSet, html_entities, url_encode};
 use color_eyre::Result;
+use itertools::Itertools;
 use once_cell::sync::Lazy;
 use parser::contents::{EmbeddedObject, RichText};
 use parser::property::common::ColorRef;
@@ -14,13 +16,17 @@ impl<'a> Renderer<'a> {
         let mut content_html = String::new();
         let mut attrs = AttributeSet::new();
         let mut style = self.parse_paragraph_styles(text);
+        let language = paragraph_language(text).or(self.language);
 
         if let Some((note_tag_html, note_tag_styles)) = self.render_note_tags(text.note_tags()) {
+            if self.section.options.tag_summary {
+                content_html.push_str(&self.add_tag_summary_anchor(text));
+            }
             content_html.push_str(&note_tag_html);
             style.extend(note_tag_styles);
         }
 
-        content_html.push_str(&self.parse_content(text)?);
+        content_html.push_str(&self.parse_content(text, language)?);
 
         if content_html.starts_with("http://") || content_html.starts_with("https://") {
             content_html = format!(
@@ -34,33 +40,37 @@ impl<'a> Renderer<'a> {
             attrs.set("style", style.to_string());
         }
 
+        if let Some(language) = language
+            && language != self.language.unwrap_or_default()
+            && !text.text().is_empty()
+        {
+            attrs.set("lang", language.to_string());
+        }
+
         match text.paragraph_style().style_id() {
             Some(t) if !self.in_list && is_tag(t) => {
                 Ok(format!("<{} {}>{}</{}>", t, attrs, content_html, t))
             }
-            _ if style.len() > 0 => Ok(format!(
-                "<span {}>{}</span>",
-                style.to_html_attr(),
-                content_html
-            )),
+            _ if !attrs.is_empty() => Ok(format!("<span {}>{}</span>", attrs, content_html)),
             _ => Ok(content_html),
         }
     }
 
-    fn parse_content(&mut self, data: &RichText) -> Result<String> {
+    /// Renders the paragraph's text. `language` is the paragraph's language.
+    fn parse_content(&mut self, data: &RichText, language: Option<&str>) -> Result<String> {
         if !data.embedded_objects().is_empty() {
             let mut result = vec![];
-            let mut ink_builder = InkBuilder::new(true);
+            let mut ink_builder = InkBuilder::new(true, self.section.options.px_per_half_inch);
 
             for object in data.embedded_objects() {
                 match object {
                     EmbeddedObject::Ink(container) => {
                         ink_builder.push(container.ink(), container.bounding_box());
-                        result.push(ink_builder.finish());
+                        result.push(self.render_ink(ink_builder.finish())?);
                     }
                     EmbeddedObject::InkSpace(space) => {
                         result.push(format!("<span class=\"ink-space\" style=\"padding-left: {}; padding-top: {};\"></span>",
-                                px(space.width()), px(space.height())));
+                                self.px(space.width()), self.px(space.height())));
                     }
                     EmbeddedObject::InkLineBreak => {
                         result.push("<span class=\"ink-linebreak\"><br></span>".to_string());
@@ -78,7 +88,7 @@ impl<'a> Renderer<'a> {
         let content = parts
             .iter()
             .map(|part| -> Result<String> {
-                let style = part
+                let mut style = part
                     .style()
                     .map(|style| self.parse_style(style))
                     .unwrap_or_default();
@@ -110,9 +120,34 @@ impl<'a> Renderer<'a> {
                         Ok("".into())
                     }
                 } else {
-                    let text_html = html_entities(part.text());
+                    let font = part
+                        .style()
+                        .and_then(|style| style.font())
+                        .or(data.paragraph_style().font());
+                    let symbol_text = font
+                        .and_then(SymbolFont::from_name)
+                        .and_then(|symbol_font| symbol_font.translate(part.text()));
+
+                    let text_html = match symbol_text {
+                        Some(text) => {
+                            style.set("font-family", font_with_fallback("Calibri"));
+                            html_entities(&text)
+                        }
+                        None => html_entities(part.text()),
+                    };
+                    let mut attrs = AttributeSet::new();
                     if style.len() > 0 {
-                        let style_attr = style.to_html_attr();
+                        attrs.set("style", style.to_string());
+                    }
+                    if let Some(run_language) = part.style().and_then(|style| style.language_tag())
+                        && Some(run_language) != language
+                    {
+                        attrs.set("lang", run_language.to_string());
+                    }
+
+                    if attrs.is_empty() {
+                        Ok(text_html)
+                    } else {
                         let tag = if style.is_bold() {
                             "strong"
                         } else if style.is_italic() {
@@ -121,9 +156,7 @@ impl<'a> Renderer<'a> {
                             "span"
                         };
 
-                        Ok(format!("<{tag} {style_attr}>{text_html}</{tag}>"))
-                    } else {
-                        Ok(text_html)
+                        Ok(format!("<{tag} {attrs}>{text_html}</{tag}>"))
                     }
                 }
             })
@@ -155,11 +188,11 @@ impl<'a> Renderer<'a> {
         }
 
         if text.paragraph_space_before() > 0.0 {
-            styles.set("padding-top", px(text.paragraph_space_before()))
+            styles.set("padding-top", self.px(text.paragraph_space_before()))
         }
 
         if text.paragraph_space_after() > 0.0 {
-            styles.set("padding-bottom", px(text.paragraph_space_after()))
+            styles.set("padding-bottom", self.px(text.paragraph_space_after()))
         }
 
         if let Some(line_spacing) = text.paragraph_line_spacing_exact() {
@@ -277,6 +310,24 @@ impl<'a> Renderer<'a> {
     }
 }
 
+/// Returns the language of a paragraph: the language of its text, if all of its text is in
+/// the same language, or the language OneNote set for the paragraph.
+pub(crate) fn paragraph_language(text: &RichText) -> Option<&'static str> {
+    let run_languages: Vec<_> = text
+        .text_run_formatting()
+        .iter()
+        .map(|style| style.language_tag())
+        .dedup()
+        .collect();
+
+    match run_languages[..] {
+        [Some(language)] => Some(language),
+        _ => text
+            .language_tag()
+            .or_else(|| text.paragraph_style().language_tag()),
+    }
+}
+
 fn is_tag(tag: &str) -> bool {
     !matches!(tag, "PageDateTime" | "PageTitle")
 }
@@ -0,0 +1,778 @@
+//! Translates text in symbol fonts (Symbol, Wingdings and Webdings) to Unicode.
+//!
+//! Symbol fonts draw pictures in place of the usual characters: in Wingdings, "ü" is a
+//! check mark. Unless the font is installed, the text shows up as the original characters,
+//! so it's translated to the matching Unicode characters instead. Characters without a
+//! close Unicode match aren't translated.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SymbolFont {
+    Symbol,
+    Wingdings,
+    Wingdings2,
+    Wingdings3,
+    Webdings,
+}
+
+impl SymbolFont {
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "symbol" => Some(SymbolFont::Symbol),
+            "wingdings" | "wingdings 1" => Some(SymbolFont::Wingdings),
+            "wingdings 2" => Some(SymbolFont::Wingdings2),
+            "wingdings 3" => Some(SymbolFont::Wingdings3),
+            "webdings" => Some(SymbolFont::Webdings),
+            _ => None,
+        }
+    }
+
+    /// Translates `text` to Unicode. Returns `None` if any character can't be translated,
+    /// since the text would then still need the symbol font.
+    pub(crate) fn translate(&self, text: &str) -> Option<String> {
+        text.chars()
+            .map(|c| {
+                if c.is_whitespace() {
+                    Some(c)
+                } else {
+                    self.translate_char(c)
+                }
+            })
+            .collect()
+    }
+
+    fn translate_char(&self, c: char) -> Option<char> {
+        // Symbol fonts can also be used through the private use area, at U+F020-U+F0FF
+        let code = match u32::from(c) {
+            code @ 0x20..=0xff => code,
+            code @ 0xf020..=0xf0ff => code - 0xf000,
+            _ => return None,
+        } as u8;
+
+        match self {
+            SymbolFont::Symbol => symbol(code),
+            SymbolFont::Wingdings => wingdings(code),
+            SymbolFont::Wingdings2 => wingdings_2(code),
+            SymbolFont::Wingdings3 => wingdings_3(code),
+            SymbolFont::Webdings => webdings(code),
+        }
+    }
+}
+
+/// Returns the character `offset` places after `first`, for ranges of consecutive characters.
+fn offset(first: char, offset: u8) -> Option<char> {
+    char::from_u32(u32::from(first) + u32::from(offset))
+}
+
+/// See the Adobe Symbol encoding.
+fn symbol(code: u8) -> Option<char> {
+    let c = match code {
+        0x20
+        | 0x21
+        | 0x23
+        | 0x25
+        | 0x26
+        | 0x28
+        | 0x29
+        | 0x2b
+        | 0x2c
+        | 0x2e..=0x3f
+        | 0x5b
+        | 0x5d
+        | 0x5f
+        | 0x7b..=0x7d => char::from(code),
+        0x22 => '∀',
+        0x24 => '∃',
+        0x27 => '∋',
+        0x2a => '∗',
+        0x2d => '−',
+        0x40 => '≅',
+        0x41 => 'Α',
+        0x42 => 'Β',
+        0x43 => 'Χ',
+        0x44 => 'Δ',
+        0x45 => 'Ε',
+        0x46 => 'Φ',
+        0x47 => 'Γ',
+        0x48 => 'Η',
+        0x49 => 'Ι',
+        0x4a => 'ϑ',
+        0x4b => 'Κ',
+        0x4c => 'Λ',
+        0x4d => 'Μ',
+        0x4e => 'Ν',
+        0x4f => 'Ο',
+        0x50 => 'Π',
+        0x51 => 'Θ',
+        0x52 => 'Ρ',
+        0x53 => 'Σ',
+        0x54 => 'Τ',
+        0x55 => 'Υ',
+        0x56 => 'ς',
+        0x57 => 'Ω',
+        0x58 => 'Ξ',
+        0x59 => 'Ψ',
+        0x5a => 'Ζ',
+        0x5c => '∴',
+        0x5e => '⊥',
+        0x60 => '‾',
+        0x61 => 'α',
+        0x62 => 'β',
+        0x63 => 'χ',
+        0x64 => 'δ',
+        0x65 => 'ε',
+        0x66 => 'φ',
+        0x67 => 'γ',
+        0x68 => 'η',
+        0x69 => 'ι',
+        0x6a => 'ϕ',
+        0x6b => 'κ',
+        0x6c => 'λ',
+        0x6d => 'μ',
+        0x6e => 'ν',
+        0x6f => 'ο',
+        0x70 => 'π',
+        0x71 => 'θ',
+        0x72 => 'ρ',
+        0x73 => 'σ',
+        0x74 => 'τ',
+        0x75 => 'υ',
+        0x76 => 'ϖ',
+        0x77 => 'ω',
+        0x78 => 'ξ',
+        0x79 => 'ψ',
+        0x7a => 'ζ',
+        0x7e => '∼',
+        0xa0 => '€',
+        0xa1 => 'ϒ',
+        0xa2 => '′',
+        0xa3 => '≤',
+        0xa4 => '⁄',
+        0xa5 => '∞',
+        0xa6 => 'ƒ',
+        0xa7 => '♣',
+        0xa8 => '♦',
+        0xa9 => '♥',
+        0xaa => '♠',
+        0xab => '↔',
+        0xac => '←',
+        0xad => '↑',
+        0xae => '→',
+        0xaf => '↓',
+        0xb0 => '°',
+        0xb1 => '±',
+        0xb2 => '″',
+        0xb3 => '≥',
+        0xb4 => '×',
+        0xb5 => '∝',
+        0xb6 => '∂',
+        0xb7 => '•',
+        0xb8 => '÷',
+        0xb9 => '≠',
+        0xba => '≡',
+        0xbb => '≈',
+        0xbc => '…',
+        0xbd => '⏐',
+        0xbe => '⎯',
+        0xbf => '↵',
+        0xc0 => 'ℵ',
+        0xc1 => 'ℑ',
+        0xc2 => 'ℜ',
+        0xc3 => '℘',
+        0xc4 => '⊗',
+        0xc5 => '⊕',
+        0xc6 => '∅',
+        0xc7 => '∩',
+        0xc8 => '∪',
+        0xc9 => '⊃',
+        0xca => '⊇',
+        0xcb => '⊄',
+        0xcc => '⊂',
+        0xcd => '⊆',
+        0xce => '∈',
+        0xcf => '∉',
+        0xd0 => '∠',
+        0xd1 => '∇',
+        0xd2 | 0xe2 => '®',
+        0xd3 | 0xe3 => '©',
+        0xd4 | 0xe4 => '™',
+        0xd5 => '∏',
+        0xd6 => '√',
+        0xd7 => '⋅',
+        0xd8 => '¬',
+        0xd9 => '∧',
+        0xda => '∨',
+        0xdb => '⇔',
+        0xdc => '⇐',
+        0xdd => '⇑',
+        0xde => '⇒',
+        0xdf => '⇓',
+        0xe0 => '◊',
+        0xe1 => '〈',
+        0xe5 => '∑',
+        0xe6 => '⎛',
+        0xe7 => '⎜',
+        0xe8 => '⎝',
+        0xe9 => '⎡',
+        0xea => '⎢',
+        0xeb => '⎣',
+        0xec => '⎧',
+        0xed => '⎨',
+        0xee => '⎩',
+        0xef => '⎪',
+        0xf1 => '〉',
+        0xf2 => '∫',
+        0xf3 => '⌠',
+        0xf4 => '⎮',
+        0xf5 => '⌡',
+        0xf6 => '⎞',
+        0xf7 => '⎟',
+        0xf8 => '⎠',
+        0xf9 => '⎤',
+        0xfa => '⎥',
+        0xfb => '⎦',
+        0xfc => '⎫',
+        0xfd => '⎬',
+        0xfe => '⎭',
+        _ => return None,
+    };
+
+    Some(c)
+}
+
+/// See http://www.alanwood.net/demos/wingdings.html
+fn wingdings(code: u8) -> Option<char> {
+    let c = match code {
+        0x21 => '🖉',
+        0x22 => '✂',
+        0x23 => '✁',
+        0x24 => '👓',
+        0x25 => '🕭',
+        0x26 => '🕮',
+        0x27 => '🕯',
+        0x28 => '🕿',
+        0x29 => '✆',
+        0x2a => '🖂',
+        0x2b => '🖃',
+        0x2c => '📪',
+        0x2d => '📫',
+        0x2e => '📬',
+        0x2f => '📭',
+        0x30 => '📁',
+        0x31 => '📂',
+        0x32 => '📄',
+        0x33 => '🗏',
+        0x34 => '🗐',
+        0x35 => '🗄',
+        0x36 => '⌛',
+        0x37 => '🖮',
+        0x38 => '🖰',
+        0x39 => '🖲',
+        0x3a => '🖳',
+        0x3b => '🖴',
+        0x3c => '🖫',
+        0x3d => '🖬',
+        0x3e => '✇',
+        0x3f => '✍',
+        0x40 => '🖎',
+        0x41 => '✌',
+        0x42 => '👌',
+        0x43 => '👍',
+        0x44 => '👎',
+        0x45 => '☜',
+        0x46 => '☞',
+        0x47 => '☝',
+        0x48 => '☟',
+        0x49 => '🖐',
+        0x4a => '☺',
+        0x4b => '😐',
+        0x4c => '☹',
+        0x4d => '💣',
+        0x4e => '☠',
+        0x4f => '🏳',
+        0x50 => '🏱',
+        0x51 => '✈',
+        0x52 => '☼',
+        0x53 => '💧',
+        0x54 => '❄',
+        0x55 => '🕆',
+        0x56 => '✞',
+        0x57 => '🕈',
+        0x58 => '✠',
+        0x59 => '✡',
+        0x5a => '☪',
+        0x5b => '☯',
+        0x5c => 'ॐ',
+        0x5d => '☸',
+        // Zodiac signs, from Aries to Pisces
+        0x5e..=0x69 => return offset('♈', code - 0x5e),
+        0x6a => '🙰',
+        0x6b => '🙵',
+        0x6c => '●',
+        0x6d => '🔾',
+        0x6e => '■',
+        0x6f => '□',
+        0x70 => '🞐',
+        0x71 => '❑',
+        0x72 => '❒',
+        0x73 => '⬧',
+        0x74 => '⧫',
+        0x75 => '◆',
+        0x76 => '❖',
+        0x77 => '⬥',
+        0x78 => '⌧',
+        0x79 => '⮹',
+        0x7a => '⌘',
+        0x7b => '🏵',
+        0x7c => '🏶',
+        0x7d => '🙶',
+        0x7e => '🙷',
+        0x80 => '⓪',
+        0x81..=0x8a => return offset('①', code - 0x81),
+        0x8b => '⓿',
+        0x8c..=0x95 => return offset('❶', code - 0x8c),
+        0x9e => '·',
+        0x9f => '•',
+        0xa1 => '○',
+        0xa2 => '🞆',
+        0xa3 => '🞈',
+        0xa4 => '◉',
+        0xa5 => '◎',
+        0xa6 => '🔿',
+        0xa7 => '▪',
+        0xa8 => '◻',
+        0xa9 => '🟂',
+        0xaa => '✦',
+        0xab => '★',
+        0xac => '✶',
+        0xad => '✴',
+        0xae => '✹',
+        0xaf => '✵',
+        // Clock faces, from one to twelve o'clock
+        0xb7..=0xc2 => return offset('🕐', code - 0xb7),
+        0xd7 => '⮘',
+        0xd8 => '⮚',
+        0xd9 => '⮙',
+        0xda => '⮛',
+        0xdb => '⮈',
+        0xdc => '⮊',
+        0xdd => '⮉',
+        0xde => '⮋',
+        0xdf => '🡨',
+        0xe0 => '🡪',
+        0xe1 => '🡩',
+        0xe2 => '🡫',
+        0xe3 => '🡬',
+        0xe4 => '🡭',
+        0xe5 => '🡯',
+        0xe6 => '🡮',
+        0xe7 => '🡸',
+        0xe8 => '🡺',
+        0xe9 => '🡹',
+        0xea => '🡻',
+        0xeb => '🡼',
+        0xec => '🡽',
+        0xed => '🡿',
+        0xee => '🡾',
+        0xef => '⇦',
+        0xf0 => '⇨',
+        0xf1 => '⇧',
+        0xf2 => '⇩',
+        0xf3 => '⬄',
+        0xf4 => '⇳',
+        0xfb => '✗',
+        0xfc => '✓',
+        0xfd => '☒',
+        0xfe => '☑',
+        _ => return None,
+    };
+
+    Some(c)
+}
+
+/// See http://www.alanwood.net/demos/wingdings-2.html
+fn wingdings_2(code: u8) -> Option<char> {
+    let c = match code {
+        0x21 => '🖊',
+        0x22 => '🖋',
+        0x23 => '🖌',
+        0x24 => '🖍',
+        0x25 => '✄',
+        0x26 => '✀',
+        0x27 => '🕾',
+        0x28 => '🕽',
+        0x29 => '🗅',
+        0x2a => '🗆',
+        0x2b => '🗇',
+        0x2c => '🗈',
+        0x2d => '🗉',
+        0x2e => '🗊',
+        0x2f => '🗋',
+        0x30 => '🗌',
+        0x31 => '🗍',
+        0x32 => '📋',
+        0x33 => '🗑',
+        0x34 => '🗔',
+        0x35 => '🖵',
+        0x36 => '🖶',
+        0x37 => '🖷',
+        0x38 => '🖸',
+        0x39 => '🖭',
+        0x3a => '🖯',
+        0x3b => '🖱',
+        0x3c => '🖒',
+        0x3d => '🖓',
+        0x3e => '🖘',
+        0x3f => '🖙',
+        0x40 => '🖚',
+        0x41 => '🖛',
+        0x42 => '👈',
+        0x43 => '👉',
+        0x44 => '🖜',
+        0x45 => '🖝',
+        0x46 => '🖞',
+        0x47 => '🖟',
+        0x48 => '🖠',
+        0x49 => '🖡',
+        0x4a => '👆',
+        0x4b => '👇',
+        0x4c => '🖢',
+        0x4d => '🖣',
+        0x4e => '🖑',
+        0x4f => '✗',
+        0x50 => '✓',
+        0x51 => '🗵',
+        0x52 => '☑',
+        0x53 => '⮽',
+        0x54 => '☒',
+        0x55 => '⮾',
+        0x56 => '⮿',
+        0x57 => '🛇',
+        0x58 => '⦸',
+        0x59 => '🙱',
+        0x5a => '🙴',
+        0x5b => '🙲',
+        0x5c => '🙳',
+        0x5d => '‽',
+        0x5e => '🙹',
+        0x5f => '🙺',
+        0x60 => '🙻',
+        0x61 => '🙦',
+        0x62 => '🙤',
+        0x63 => '🙥',
+        0x64 => '🙧',
+        0x65 => '🙚',
+        0x66 => '🙘',
+        0x67 => '🙙',
+        0x68 => '🙛',
+        0x69 => '⓪',
+        0x6a..=0x73 => return offset('①', code - 0x6a),
+        0x74 => '⓿',
+        0x75..=0x7e => return offset('❶', code - 0x75),
+        0x80 => '☉',
+        0x81 => '🌕',
+        0x82 => '☽',
+        0x83 => '☾',
+        0x84 => '⸿',
+        0x85 => '✝',
+        0x86 => '🕇',
+        // Clock faces, from half past one to half past twelve
+        0x87..=0x92 => return offset('🕜', code - 0x87),
+        0xae => '◆',
+        _ => return None,
+    };
+
+    Some(c)
+}
+
+/// See http://www.alanwood.net/demos/wingdings-3.html
+fn wingdings_3(code: u8) -> Option<char> {
+    let c = match code {
+        0x21 => '⭠',
+        0x22 => '⭢',
+        0x23 => '⭡',
+        0x24 => '⭣',
+        0x25 => '⭦',
+        0x26 => '⭧',
+        0x27 => '⭩',
+        0x28 => '⭨',
+        0x29 => '⭰',
+        0x2a => '⭲',
+        0x2b => '⭱',
+        0x2c => '⭳',
+        0x70 => '▲',
+        0x71 => '▼',
+        0x72 => '△',
+        0x73 => '▽',
+        0x74 => '◀',
+        0x75 => '▶',
+        0x76 => '◁',
+        0x77 => '▷',
+        0x78 => '◣',
+        0x79 => '◢',
+        0x7a => '◤',
+        0x7b => '◥',
+        0x7c => '🞀',
+        0x7d => '▶',
+        0x7e => '🞁',
+        0x80 => '🞃',
+        _ => return None,
+    };
+
+    Some(c)
+}
+
+/// See http://www.alanwood.net/demos/webdings.html
+fn webdings(code: u8) -> Option<char> {
+    let c = match code {
+        0x21 => '🕷',
+        0x22 => '🕸',
+        0x23 => '🕲',
+        0x24 => '🕶',
+        0x25 => '🏆',
+        0x26 => '🎖',
+        0x27 => '🖇',
+        0x28 => '🗨',
+        0x29 => '🗩',
+        0x2a => '🗰',
+        0x2b => '🗱',
+        0x2c => '🌶',
+        0x2d => '🎗',
+        0x2e => '🙾',
+        0x2f => '🙼',
+        0x30 => '🗕',
+        0x31 => '🗖',
+        0x32 => '🗗',
+        0x33 => '⏴',
+        0x34 => '⏵',
+        0x35 => '⏶',
+        0x36 => '⏷',
+        0x37 => '⏪',
+        0x38 => '⏩',
+        0x39 => '⏮',
+        0x3a => '⏭',
+        0x3b => '⏸',
+        0x3c => '⏹',
+        0x3d => '⏺',
+        0x3e => '🗚',
+        0x3f => '🗳',
+        0x40 => '🛠',
+        0x41 => '🏗',
+        0x42 => '🏘',
+        0x43 => '🏙',
+        0x44 => '🏚',
+        0x45 => '🏜',
+        0x46 => '🏭',
+        0x47 => '🏛',
+        0x48 => '🏠',
+        0x49 => '🏖',
+        0x4a => '🏝',
+        0x4b => '🛣',
+        0x4c => '🔍',
+        0x4d => '🏔',
+        0x4e => '👁',
+        0x4f => '👂',
+        0x50 => '🏞',
+        0x51 => '🏕',
+        0x52 => '🛤',
+        0x53 => '🏟',
+        0x54 => '🛳',
+        0x55 => '🕬',
+        0x56 => '🕫',
+        0x57 => '🕨',
+        0x58 => '🔈',
+        0x59 => '🎔',
+        0x5a => '🎕',
+        0x5b => '🗬',
+        0x5c => '🙽',
+        0x5d => '🗭',
+        0x5e => '🗪',
+        0x5f => '🗫',
+        0x60 => '⮔',
+        0x61 => '✔',
+        0x62 => '🚲',
+        0x63 => '⬜',
+        0x64 => '🛡',
+        0x65 => '📦',
+        0x66 => '🛱',
+        0x67 => '⬛',
+        0x68 => '🚑',
+        0x69 => '🛈',
+        0x6a => '🛩',
+        0x6b => '🛰',
+        0x6c => '🟈',
+        0x6d => '🕴',
+        0x6e => '⚫',
+        0x6f => '🛥',
+        0x70 => '🚔',
+        0x71 => '🗘',
+        0x72 => '🗙',
+        0x73 => '❓',
+        0x74 => '🛲',
+        0x75 => '🚇',
+        0x76 => '🚍',
+        0x77 => '⛳',
+        0x78 => '🛇',
+        0x79 => '⊖',
+        0x7a => '🚭',
+        0x7b => '🗮',
+        0x7d => '🗯',
+        0x7e => '🗲',
+        0x80 => '🚹',
+        0x81 => '🚺',
+        0x82 => '🛉',
+        0x83 => '🛊',
+        0x84 => '🚼',
+        0x85 => '👽',
+        0x86 => '🏋',
+        0x87 => '⛷',
+        0x88 => '🏂',
+        0x89 => '🏌',
+        0x8a => '🏊',
+        0x8b => '🏄',
+        0x8c => '🏍',
+        0x8d => '🏎',
+        0x8e => '🚘',
+        0x8f => '🗠',
+        0x90 => '🛢',
+        0x91 => '💰',
+        0x92 => '🏷',
+        0x93 => '💳',
+        0x94 => '👪',
+        0x95 => '🗡',
+        0x96 => '🗢',
+        0x97 => '🗣',
+        0x98 => '✯',
+        0x99 => '🖄',
+        0x9a => '🖅',
+        0x9b => '🖃',
+        0x9c => '🖆',
+        0x9d => '🖹',
+        0x9e => '🖺',
+        0x9f => '🖻',
+        0xa0 => '🕵',
+        0xa1 => '🕰',
+        0xa2 => '🖽',
+        0xa3 => '🖾',
+        0xa4 => '📋',
+        0xa5 => '🗒',
+        0xa6 => '🗓',
+        0xa7 => '📖',
+        0xa8 => '📚',
+        0xa9 => '🗞',
+        0xaa => '🗟',
+        0xab => '🗃',
+        0xac => '🗂',
+        0xad => '🖼',
+        0xae => '🎭',
+        0xaf => '🎜',
+        0xb0 => '🎘',
+        0xb1 => '🎙',
+        0xb2 => '🎧',
+        0xb3 => '💿',
+        0xb4 => '🎞',
+        0xb5 => '📷',
+        0xb6 => '🎟',
+        0xb7 => '🎬',
+        0xb8 => '📽',
+        0xb9 => '📹',
+        0xba => '📾',
+        0xbb => '📻',
+        0xbc => '🎚',
+        0xbd => '🎛',
+        0xbe => '📺',
+        0xbf => '💻',
+        0xc0 => '🖥',
+        0xc1 => '🖦',
+        0xc2 => '🖧',
+        0xc3 => '🕹',
+        0xc4 => '🎮',
+        0xc5 => '🕻',
+        0xc6 => '🕼',
+        0xc7 => '📟',
+        0xc8 => '🖁',
+        0xc9 => '🖀',
+        0xca => '🖨',
+        0xcb => '🖩',
+        0xcc => '🖿',
+        0xcd => '🖪',
+        0xce => '🗜',
+        0xcf => '🔒',
+        0xd0 => '🔓',
+        0xd1 => '🗝',
+        0xd2 => '📥',
+        0xd3 => '📤',
+        0xd4 => '🕳',
+        0xd5 => '🌣',
+        0xd6 => '🌤',
+        0xd7 => '🌥',
+        0xd8 => '🌦',
+        0xd9 => '☁',
+        0xda => '🌨',
+        0xdb => '🌧',
+        0xdc => '🌩',
+        0xdd => '🌪',
+        0xde => '🌬',
+        0xdf => '🌫',
+        0xe0 => '🌜',
+        0xe1 => '🌡',
+        0xe2 => '🛋',
+        0xe3 => '🛏',
+        0xe4 => '🍽',
+        0xe5 => '🍸',
+        0xe6 => '🛎',
+        0xe7 => '🛍',
+        0xe8 => 'Ⓟ',
+        0xe9 => '♿',
+        0xea => '🛆',
+        0xeb => '🖈',
+        0xec => '🎓',
+        0xed => '🗤',
+        0xee => '🗥',
+        0xef => '🗦',
+        0xf0 => '🗧',
+        0xf1 => '🛪',
+        0xf2 => '🐿',
+        0xf3 => '🐦',
+        0xf4 => '🐟',
+        0xf5 => '🐕',
+        0xf6 => '🐈',
+        0xf7 => '🙬',
+        0xf8 => '🙮',
+        0xf9 => '🙭',
+        0xfa => '🙯',
+        0xfb => '🗺',
+        0xfc => '🌍',
+        0xfd => '🌏',
+        0xfe => '🌎',
+        0xff => '🕊',
+        _ => return None,
+    };
+
+    Some(c)
+}
+
+#[cfg(test)]
+mod test {
+    use super::SymbolFont;
+
+    #[test]
+    fn should_translate_symbol_fonts() {
+        let wingdings = SymbolFont::from_name("Wingdings").unwrap();
+        assert_eq!(wingdings.translate("\u{fc} \u{fb}"), Some("✓ ✗".into()));
+        assert_eq!(wingdings.translate("\u{f0e8}"), Some("🡺".into()));
+        assert_eq!(wingdings.translate("\u{8a}"), Some("⑩".into()));
+
+        let symbol = SymbolFont::from_name("symbol").unwrap();
+        assert_eq!(symbol.translate("a+b=p"), Some("α+β=π".into()));
+
+        let webdings = SymbolFont::from_name("Webdings").unwrap();
+        assert_eq!(webdings.translate("\u{61} \u{73}"), Some("✔ ❓".into()));
+        assert_eq!(webdings.translate("\u{f0fc}"), Some("🌍".into()));
+        assert_eq!(webdings.translate("\u{ff}"), Some("🕊".into()));
+        assert_eq!(webdings.translate("\u{7c}"), None);
+
+        // Text with untranslatable characters should keep its font
+        assert_eq!(wingdings.translate("\u{c3}"), None);
+        assert_eq!(SymbolFont::from_name("Calibri"), None);
+    }
+}
@@ -1,10 +1,12 @@
 use crate::page::Renderer;
-use crate::utils::{AttributeSet, StyleSet, px};
+use crate::utils::{AttributeSet, StyleSet};
 use color_eyre::Result;
 use parser::contents::{OutlineElement, Table, TableCell};
 
 impl<'a> Renderer<'a> {
     pub(crate) fn render_table(&mut self, table: &Table) -> Result<String> {
+        self.counts.tables += 1;
+
         let mut content = String::new();
         let mut styles = StyleSet::new();
         styles.set("border-collapse", "collapse".to_string());
@@ -24,32 +26,32 @@ impl<'a> Renderer<'a> {
 
         content.push_str(&format!("<table {}>", attributes));
 
-        let locked_cols = calc_locked_cols(table.cols_locked(), table.cols());
+        // Rows can have more or fewer cells than the table has columns if the table is
+        // partially corrupt, so use the longest row
+        let col_count = table
+            .contents()
+            .iter()
+            .map(|row| row.contents().len())
+            .max()
+            .unwrap_or_default()
+            .max(table.cols() as usize);
 
         let mut col_widths = table.col_widths().to_vec();
-        col_widths.extend(vec![0.0; table.cols() as usize - col_widths.len()]);
-        let col_widths = &*col_widths;
+        col_widths.resize(col_count, 0.0);
 
         for row in table.contents() {
             content.push_str("<tr>");
 
-            assert_eq!(row.contents().len(), col_widths.len());
-
-            let cells = row
-                .contents()
-                .iter()
-                .zip(col_widths.iter().copied())
-                .zip(locked_cols.iter().copied())
-                .map(|((cell, width), locked)| {
-                    if locked {
-                        (cell, Some(width))
-                    } else {
-                        (cell, None)
-                    }
-                });
-
-            for (cell, width) in cells {
-                self.render_table_cell(&mut content, cell, width)?;
+            for (col, width) in col_widths.iter().enumerate() {
+                let width = table.is_col_locked(col).then_some(*width);
+
+                match row.contents().get(col) {
+                    Some(cell) => self.render_table_cell(&mut content, cell, width)?,
+                    // Pad short rows, so that the cells of the following rows stay in their
+                    // columns
+                    None => content
+                        .push_str(&format!("<td {}></td>", self.cell_attributes(None, width))),
+                }
             }
 
             content.push_str("</tr>");
@@ -66,25 +68,7 @@ impl<'a> Renderer<'a> {
         cell: &TableCell,
         width: Option<f32>,
     ) -> Result<()> {
-        let mut styles = StyleSet::new();
-        styles.set("padding", "2pt".to_string());
-        styles.set("vertical-align", "top".to_string());
-        styles.set("min-width", px(1.0));
-
-        if let Some(width) = width {
-            styles.set("width", px(width));
-        }
-
-        if let Some(color) = cell.background_color() {
-            styles.set(
-                "background",
-                format!("rgb({}, {}, {})", color.r(), color.g(), color.b()),
-            )
-        }
-
-        let mut attrs = AttributeSet::new();
-        attrs.set("style", styles.to_string());
-
+        let attrs = self.cell_attributes(Some(cell), width);
         contents.push_str(&format!("<td {}>", attrs));
 
         let cell_level = self.table_cell_level(cell.contents());
@@ -102,14 +86,26 @@ impl<'a> Renderer<'a> {
 
         if needs_nesting { 2 } else { 1 }
     }
-}
 
-fn calc_locked_cols(data: &[u8], count: u32) -> Vec<bool> {
-    if data.is_empty() {
-        return vec![false; count as usize];
-    }
+    fn cell_attributes(&self, cell: Option<&TableCell>, width: Option<f32>) -> AttributeSet {
+        let mut styles = StyleSet::new();
+        styles.set("padding", "2pt".to_string());
+        styles.set("vertical-align", "top".to_string());
+        styles.set("min-width", self.px(1.0));
 
-    (0..count)
-        .map(|i| data[i as usize / 8] & (1 << (i % 8)) == 1)
-        .collect()
+        if let Some(width) = width {
+            styles.set("width", self.px(width));
+        }
+
+        if let Some(color) = cell.and_then(|cell| cell.background_color()) {
+            styles.set(
+                "background",
+                format!("rgb({}, {}, {})", color.r(), color.g(), color.b()),
+            )
+        }
+
+        let mut attrs = AttributeSet::new();
+        attrs.set("style", styles.to_string());
+        attrs
+    }
 }
@@ -0,0 +1,44 @@
+//! Runs independent conversion jobs, such as rendering the sections of a notebook.
+//!
+//! With the `parallel` feature enabled on native targets, the jobs run on a thread pool.
+//! Otherwise (and always on WASM), they run one after another on the current thread.
+
+/// Whether [`map`] runs the jobs on a thread pool.
+pub(crate) const ENABLED: bool = cfg!(all(feature = "parallel", not(target_arch = "wasm32")));
+
+/// Applies `f` to each item, returning the results in the same order as `items`.
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+pub(crate) fn map<T, R, F>(items: Vec<T>, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync + Send,
+{
+    use rayon::prelude::*;
+
+    items.into_par_iter().map(f).collect()
+}
+
+/// Applies `f` to each item, returning the results in the same order as `items`.
+#[cfg(not(all(feature = "parallel", not(target_arch = "wasm32"))))]
+pub(crate) fn map<T, R, F>(items: Vec<T>, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync + Send,
+{
+    items.into_iter().map(f).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::map;
+
+    #[test]
+    fn should_preserve_item_order() {
+        let items: Vec<u32> = (0..100).collect();
+        let expected: Vec<u32> = items.iter().map(|item| item * 2).collect();
+
+        assert_eq!(map(items, |item| item * 2), expected);
+    }
+}
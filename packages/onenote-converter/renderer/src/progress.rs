@@ -0,0 +1,248 @@
+use crate::errors::{ErrorKind, Result};
+use js_sys::{Function, Object, Reflect};
+use parser_utils::log_warn;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use wasm_bindgen::JsValue;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// How far a conversion has progressed.
+///
+/// The number of sections and the number of bytes to convert are known when the conversion
+/// starts. Pages are only counted once the section that contains them has been parsed, so
+/// `pages_total` grows as the conversion progresses.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Progress {
+    /// The number of sections that have been converted (or failed to convert).
+    pub sections_done: u32,
+    /// The number of sections to convert.
+    pub sections_total: u32,
+    /// The number of pages that have been converted (or failed to convert).
+    pub pages_done: u32,
+    /// The number of pages in the sections that have been parsed so far.
+    pub pages_total: u32,
+    /// The size of the section files that have been converted.
+    pub bytes_done: u64,
+    /// The size of all section files to convert.
+    pub bytes_total: u64,
+}
+
+/// Receives progress updates while a notebook or section is converted.
+///
+/// With the `parallel` feature, updates may come from several threads at once.
+pub trait ProgressListener: Send + Sync {
+    fn on_progress(&self, progress: Progress);
+}
+
+impl<F: Fn(Progress) + Send + Sync> ProgressListener for F {
+    fn on_progress(&self, progress: Progress) {
+        self(progress)
+    }
+}
+
+/// Reports progress to a JavaScript callback, as a
+/// `{ sectionsDone, sectionsTotal, pagesDone, pagesTotal, bytesDone, bytesTotal }` object.
+pub(crate) struct JsProgressListener(pub(crate) Function);
+
+// SAFETY: JavaScript values can't be shared between threads, but the WASM build is
+// single-threaded (the `parallel` feature has no effect there), so the callback is only
+// ever called from the thread that created it.
+#[cfg(target_arch = "wasm32")]
+unsafe impl Send for JsProgressListener {}
+#[cfg(target_arch = "wasm32")]
+unsafe impl Sync for JsProgressListener {}
+
+#[cfg(target_arch = "wasm32")]
+impl ProgressListener for JsProgressListener {
+    fn on_progress(&self, progress: Progress) {
+        self.report(progress)
+    }
+}
+
+impl JsProgressListener {
+    /// Runs `convert` with a listener that reports to the JavaScript callback.
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn run<T>(self, convert: impl FnOnce(Arc<dyn ProgressListener>) -> T) -> T {
+        convert(Arc::new(self))
+    }
+
+    /// Runs `convert` with a listener that reports to the JavaScript callback.
+    ///
+    /// Outside of WASM the conversion may report progress from several threads, so it runs
+    /// on a separate thread and its progress is forwarded to the callback on this one.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn run<T: Send>(
+        self,
+        convert: impl FnOnce(Arc<dyn ProgressListener>) -> T + Send,
+    ) -> T {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let forward = move |progress: Progress| {
+            // The receiver is only dropped once the conversion has finished
+            let _ = sender.send(progress);
+        };
+
+        std::thread::scope(|scope| {
+            let conversion = scope.spawn(move || convert(Arc::new(forward)));
+            // Ends once the conversion has finished and dropped the listener
+            for progress in receiver {
+                self.report(progress);
+            }
+            conversion
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+        })
+    }
+
+    fn report(&self, progress: Progress) {
+        let object = Object::new();
+        let fields = [
+            ("sectionsDone", progress.sections_done as f64),
+            ("sectionsTotal", progress.sections_total as f64),
+            ("pagesDone", progress.pages_done as f64),
+            ("pagesTotal", progress.pages_total as f64),
+            ("bytesDone", progress.bytes_done as f64),
+            ("bytesTotal", progress.bytes_total as f64),
+        ];
+        for (key, value) in fields {
+            let _ = Reflect::set(&object, &key.into(), &value.into());
+        }
+
+        if let Err(error) = self.0.call1(&JsValue::NULL, &object) {
+            log_warn!("Progress callback failed: {:?}", error);
+        }
+    }
+}
+
+/// Allows cancelling a conversion from another thread, or from JavaScript.
+///
+/// Cancellation is checked between pages, so the page that is being converted when
+/// [`CancellationToken::cancel`] is called is still finished.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+#[wasm_bindgen]
+impl CancellationToken {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    #[wasm_bindgen(js_name = isCancelled)]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Tracks the progress of a conversion, and reports it to the listener (if any).
+#[derive(Default)]
+pub(crate) struct ProgressTracker {
+    listener: Option<Arc<dyn ProgressListener>>,
+    cancellation: CancellationToken,
+    progress: Mutex<Progress>,
+}
+
+impl ProgressTracker {
+    pub(crate) fn new(
+        listener: Arc<dyn ProgressListener>,
+        cancellation: CancellationToken,
+    ) -> Self {
+        Self {
+            listener: Some(listener),
+            cancellation,
+            progress: Default::default(),
+        }
+    }
+
+    /// Adds sections (with a total size of `bytes`) to the number of sections to convert.
+    pub(crate) fn add_sections(&self, count: u32, bytes: u64) {
+        self.update(|progress| {
+            progress.sections_total += count;
+            progress.bytes_total += bytes;
+        });
+    }
+
+    pub(crate) fn add_pages(&self, count: u32) {
+        self.update(|progress| progress.pages_total += count);
+    }
+
+    pub(crate) fn page_done(&self) {
+        self.update(|progress| progress.pages_done += 1);
+    }
+
+    pub(crate) fn section_done(&self, bytes: u64) {
+        self.update(|progress| {
+            progress.sections_done += 1;
+            progress.bytes_done += bytes;
+        });
+    }
+
+    /// Returns an error if the conversion has been cancelled.
+    pub(crate) fn check_cancelled(&self) -> Result<()> {
+        if self.cancellation.is_cancelled() {
+            Err(ErrorKind::Cancelled.into())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn update(&self, update: impl FnOnce(&mut Progress)) {
+        let progress = {
+            let mut progress = self.progress.lock().unwrap();
+            update(&mut progress);
+            *progress
+        };
+
+        // The lock is released before notifying the listener, so that a slow listener
+        // doesn't hold up other threads.
+        if let Some(listener) = &self.listener {
+            listener.on_progress(progress);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CancellationToken, Progress, ProgressTracker};
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn should_report_progress_and_cancellation() {
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let listener_updates = updates.clone();
+        let cancellation = CancellationToken::new();
+        let tracker = ProgressTracker::new(
+            Arc::new(move |progress| listener_updates.lock().unwrap().push(progress)),
+            cancellation.clone(),
+        );
+
+        tracker.add_sections(2, 1000);
+        tracker.add_pages(3);
+        tracker.page_done();
+        tracker.section_done(400);
+        assert!(tracker.check_cancelled().is_ok());
+
+        cancellation.cancel();
+        assert!(tracker.check_cancelled().is_err());
+
+        let updates = updates.lock().unwrap();
+        assert_eq!(updates.len(), 4);
+        assert_eq!(
+            updates.last(),
+            Some(&Progress {
+                sections_done: 1,
+                sections_total: 2,
+                pages_done: 1,
+                pages_total: 3,
+                bytes_done: 400,
+                bytes_total: 1000,
+            })
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use color_eyre::Result;
+use color_eyre::eyre::WrapErr;
+use serde::Serialize;
+
+/// The outcome of a conversion: which sections and pages were converted, and which failed.
+///
+/// Serialized to JSON (with camel-cased keys) for JavaScript callers.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversionReport {
+    pub sections: Vec<SectionReport>,
+}
+
+impl ConversionReport {
+    /// Whether any section or page failed to convert.
+    pub fn has_errors(&self) -> bool {
+        self.sections.iter().any(|section| section.has_errors())
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self).wrap_err("Failed to serialize the conversion report")
+    }
+}
+
+/// The outcome of converting a single section.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SectionReport {
+    /// The section's name.
+    pub name: String,
+    /// The path to the section file (within the archive, for `.onepkg` files).
+    pub source_path: String,
+    /// The path to the section's table of contents, if it was written.
+    pub output_path: Option<String>,
+    /// Why the section couldn't be converted at all, if it couldn't.
+    pub error: Option<String>,
+    pub pages: Vec<PageReport>,
+}
+
+impl SectionReport {
+    pub(crate) fn new(name: &str, source_path: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            source_path: source_path.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Whether the section, or any of its pages, failed to convert.
+    pub fn has_errors(&self) -> bool {
+        self.error.is_some() || self.pages.iter().any(|page| page.has_errors())
+    }
+
+    /// Whether any of the section's pages failed to convert or lost content.
+    pub(crate) fn has_page_problems(&self) -> bool {
+        self.pages
+            .iter()
+            .any(|page| page.has_errors() || !page.warnings.is_empty())
+    }
+}
+
+/// The outcome of converting a single page.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageReport {
+    /// The page's title. Missing for pages that couldn't be parsed, or that have no title.
+    pub title: Option<String>,
+    /// The path to the page's HTML file, if it was written.
+    pub output_path: Option<String>,
+    pub parse_error: Option<String>,
+    pub render_error: Option<String>,
+    /// Descriptions of the content on the page that couldn't be converted.
+    pub warnings: Vec<String>,
+    /// Properties that weren't recognised, on pages with content that couldn't be converted.
+    pub unknown_properties: Vec<String>,
+    /// The text that OneNote recognised in the page's images (OCR), one entry per image.
+    pub ocr_text: Vec<String>,
+    pub counts: ItemCounts,
+}
+
+impl PageReport {
+    pub fn has_errors(&self) -> bool {
+        self.parse_error.is_some() || self.render_error.is_some()
+    }
+}
+
+/// The number of each kind of item rendered on a page.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemCounts {
+    pub outlines: u32,
+    pub images: u32,
+    pub embedded_files: u32,
+    pub tables: u32,
+    pub ink_drawings: u32,
+    pub printouts: u32,
+}
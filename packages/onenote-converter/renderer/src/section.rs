@@ -1,27 +1,110 @@
 use crate::errors::{ErrorKind, Result};
+use crate::options::{ConvertOptions, ErrorPolicy, OcrTextMode, PageNaming, SubpageLayout};
+use crate::progress::ProgressTracker;
+use crate::report::{PageReport, SectionReport};
+use crate::tasks::{self, Task};
 use crate::templates::section::TocEntry;
+use crate::templates::tag_summary::TaggedPage;
 use crate::{page, templates};
-use parser::page::Page;
+use parser::Parser;
+use parser::page::{Page, PageNode, format_ocr_text};
+use parser::property::common::Color;
 use parser::section::Section;
-use parser_utils::fs_driver;
 use parser_utils::log;
 use parser_utils::log_warn;
+use parser_utils::{FileHandle, fs_driver};
 use std::collections::HashSet;
+use std::sync::Arc;
 
 pub(crate) struct Renderer {
     pub(crate) files: HashSet<String>,
+    pub(crate) options: ConvertOptions,
+    progress: Arc<ProgressTracker>,
+    tagged_pages: Vec<TaggedPage>,
 }
 
 pub(crate) struct RenderedSection {
     pub(crate) section_dir: String,
+    pub(crate) color: Option<Color>,
+    pub(crate) tasks: Vec<Task>,
+    pub(crate) tagged_pages: Vec<TaggedPage>,
+    /// The paths of the files written for the section.
+    pub(crate) files: HashSet<String>,
+    pub(crate) report: SectionReport,
+}
+
+impl RenderedSection {
+    fn failed(report: SectionReport) -> Self {
+        Self {
+            section_dir: String::new(),
+            color: None,
+            tasks: Vec::new(),
+            tagged_pages: Vec::new(),
+            files: HashSet::new(),
+            report,
+        }
+    }
+}
+
+/// Parses and renders the section in `file`, counting it towards the conversion's progress.
+///
+/// If the section can't be parsed, this is recorded in the section's report, unless the
+/// error policy is to abort.
+pub(crate) fn render_file(
+    file: Box<dyn FileHandle>,
+    path: &str,
+    output_dir: String,
+    options: &ConvertOptions,
+    progress: &Arc<ProgressTracker>,
+) -> Result<RenderedSection> {
+    progress.check_cancelled()?;
+
+    let bytes = file.byte_length();
+    let result = match Parser::new().parse_section_from_file(file, path) {
+        Ok(section) => {
+            Renderer::new(options.clone(), progress.clone()).render(&section, output_dir)
+        }
+        Err(error) if options.error_policy != ErrorPolicy::Abort => {
+            log_warn!("Section failed to parse: {:?}", error);
+            let file_name = fs_driver().get_file_name(path).unwrap_or_default();
+            let (name, _) = fs_driver().split_file_name(&file_name);
+
+            let mut report = SectionReport::new(&name, path);
+            report.error = Some(error.to_string());
+            Ok(RenderedSection::failed(report))
+        }
+        Err(error) => Err(error.into()),
+    };
+    progress.section_done(bytes);
+
+    let mut rendered = result?;
+    rendered.report.source_path = path.to_string();
+    Ok(rendered)
+}
+
+/// Lists the pages in `tree` in order, with their depth in the tree and whether they have
+/// subpages.
+fn flatten_page_tree<'a>(tree: &[PageNode<'a>]) -> Vec<(&'a Page, usize, bool)> {
+    let mut pages = Vec::new();
+    let mut stack: Vec<_> = tree.iter().rev().map(|node| (node, 0)).collect();
+
+    while let Some((node, depth)) = stack.pop() {
+        pages.push((node.page(), depth, !node.children().is_empty()));
+        stack.extend(node.children().iter().rev().map(|child| (child, depth + 1)));
+    }
+
+    pages
 }
 
 const ERRORS_NOTE_NAME: &str = "⚠️ Errors ⚠️";
 
 impl Renderer {
-    pub fn new() -> Self {
+    pub fn new(options: ConvertOptions, progress: Arc<ProgressTracker>) -> Self {
         Renderer {
             files: Default::default(),
+            options,
+            progress,
+            tagged_pages: Vec::new(),
         }
     }
 
@@ -43,71 +126,144 @@ impl Renderer {
 
         let mut toc = Vec::new();
         let mut fallback_title_index = 0;
-        let mut errors: Vec<String> = Vec::new();
+        let mut report = SectionReport::new(section.display_name(), "");
+        let mut section_tasks: Vec<Task> = Vec::new();
+
+        let page_count: usize = section
+            .page_series()
+            .iter()
+            .map(|page_series| page_series.pages().len())
+            .sum();
+        self.progress.add_pages(page_count as u32);
 
         for page_series in section.page_series() {
             let page_errors = page_series.errors();
             for error in page_errors {
                 log_warn!("Page failed to parse: {:?}", error);
-                errors.push(format!("Parse error: {:?}", error));
+                if self.options.error_policy == ErrorPolicy::Abort {
+                    return Err(ErrorKind::RenderFailed(format!("Parse error: {}", error)).into());
+                }
+
+                report.pages.push(PageReport {
+                    parse_error: Some(error.clone()),
+                    ..PageReport::default()
+                });
             }
 
-            for page in page_series.pages() {
+            // The folder that the pages at each depth of the page tree are written to
+            let mut page_dirs = vec![section_dir.clone()];
+
+            for (page, depth, has_subpages) in flatten_page_tree(&page_series.page_tree()) {
+                self.progress.check_cancelled()?;
+                page_dirs.truncate(depth + 1);
+                let page_dir = page_dirs[depth].clone();
+
+                let mut page_report = PageReport {
+                    title: page.title_text(),
+                    ..PageReport::default()
+                };
+                if self.options.ocr_text != OcrTextMode::Omit {
+                    page_report.ocr_text = page
+                        .ocr_text()
+                        .map(|text| format_ocr_text(text.text()))
+                        .collect();
+                }
+                Self::describe_content_loss(page, &mut page_report);
+
                 let render_result = self.render_page_to_file(
                     page,
                     toc.len() as u32,
-                    &section_dir,
+                    &page_dir,
                     &output_dir,
+                    &mut page_report,
                     || {
                         fallback_title_index += 1;
                         fallback_title_index
                     },
                 );
+                let mut subpage_dir = page_dir.clone();
                 match render_result {
-                    Ok(toc_entry) => {
+                    Ok(mut toc_entry) => {
+                        toc_entry.depth = depth;
+                        if has_subpages
+                            && self.options.subpage_layout == SubpageLayout::Folders
+                            && let Some(page_path) = &page_report.output_path
+                        {
+                            subpage_dir = page_path
+                                .strip_suffix(".html")
+                                .unwrap_or(page_path)
+                                .to_string();
+                            fs_driver().make_dir(&subpage_dir)?;
+                        }
+
+                        if self.options.task_report {
+                            section_tasks.extend(tasks::collect_tasks(
+                                page,
+                                section.display_name(),
+                                &toc_entry.name,
+                                &toc_entry.relative_path,
+                            ));
+                        }
                         toc.push(toc_entry);
                     }
                     Err(error) => {
                         log_warn!("Error rendering page: {:?}", error);
-                        let title = page.title_text().unwrap_or_default();
-                        errors.push(format!("Render error for page {}: {:?}", title, error));
+                        if self.options.error_policy == ErrorPolicy::Abort {
+                            let title = page.title_text().unwrap_or_default();
+                            return Err(ErrorKind::RenderFailed(format!(
+                                "Render error for page {}: {}",
+                                title, error
+                            ))
+                            .into());
+                        }
+
+                        page_report.render_error = Some(error.to_string());
                     }
                 }
+                if has_subpages {
+                    page_dirs.push(subpage_dir);
+                }
+                report.pages.push(page_report);
+                self.progress.page_done();
             }
         }
 
-        let errors_path = if !errors.is_empty() {
-            let error_toc_entry = self.render_errors_to_file(&errors, &output_dir)?;
-            let errors_path = fs_driver().join(&output_dir, &error_toc_entry.relative_path);
+        // Content loss is listed on the errors page too, but isn't counted as an error.
+        if self.options.error_policy == ErrorPolicy::ErrorPage && report.has_page_problems() {
+            let error_toc_entry = self.render_errors_to_file(&report, &output_dir)?;
             toc.push(error_toc_entry);
+        }
 
-            Some(errors_path)
-        } else {
-            None
-        };
-
-        let toc_html = templates::section::render(section.display_name(), toc)?;
+        let toc_html = templates::section::render(
+            section.display_name(),
+            toc,
+            self.options.subpage_layout.is_nested(),
+        )?;
         let toc_path = self.write_html_file(&output_dir, section.display_name(), &toc_html)?;
         log!("ToC: {}", toc_path);
+        report.output_path = Some(toc_path);
 
-        if errors_path.is_some() {
-            Err(ErrorKind::RenderFailed(format!(
-                "Some pages failed to render. First error: {:?}. Full error report written to {}",
-                errors.first(),
-                ERRORS_NOTE_NAME,
-            ))
-            .into())
-        } else {
-            Ok(RenderedSection { section_dir })
+        if !section_tasks.is_empty() {
+            self.write_task_report(&output_dir, section.display_name(), &section_tasks)?;
         }
+
+        Ok(RenderedSection {
+            section_dir,
+            color: section.color(),
+            tasks: section_tasks,
+            tagged_pages: std::mem::take(&mut self.tagged_pages),
+            files: std::mem::take(&mut self.files),
+            report,
+        })
     }
 
     fn render_page_to_file<F>(
         &mut self,
         page: &Page,
         page_order_index: u32,
-        section_dir: &str,
+        page_dir: &str,
         output_dir: &str,
+        report: &mut PageReport,
         fallback_title_idx: F,
     ) -> Result<TocEntry>
     where
@@ -118,28 +274,64 @@ impl Renderer {
             .map(|s| s.to_string())
             .unwrap_or_else(|| format!("Untitled Page {}", fallback_title_idx()));
 
-        let mut renderer = page::Renderer::new(section_dir.into(), self);
+        let mut renderer = page::Renderer::new(page_dir.into(), self);
         let page_html = renderer.render_page(page, page_order_index)?;
+        let tagged_paragraphs = std::mem::take(&mut renderer.tagged_paragraphs);
+        report.counts = renderer.counts;
 
-        let page_path = self.write_html_file(section_dir, &title, &page_html)?;
+        let file_name = match self.options.page_naming {
+            PageNaming::Title => title.clone(),
+            PageNaming::IndexAndTitle => format!("{:03} {}", page_order_index + 1, title),
+            PageNaming::Id => page.link_target_id(),
+        };
+        let page_path = self.write_html_file(page_dir, &file_name, &page_html)?;
         log!("Created page file: {:?}", page_path);
+        report.output_path = Some(page_path.clone());
 
         let page_path_without_basedir =
             String::from(fs_driver().remove_prefix(&page_path, output_dir));
+
+        if !tagged_paragraphs.is_empty() {
+            self.tagged_pages.push(TaggedPage {
+                title: title.clone(),
+                path: page_path_without_basedir.clone(),
+                paragraphs: tagged_paragraphs,
+            });
+        }
         Ok(TocEntry {
             name: title,
             is_error: false,
             relative_path: page_path_without_basedir,
             level: page.level(),
+            depth: 0,
         })
     }
 
+    fn describe_content_loss(page: &Page, report: &mut PageReport) {
+        let diagnostics = page.diagnostics();
+        // Unrecognised properties are common and usually harmless. Only list them for pages
+        // that are otherwise missing content.
+        if !diagnostics.has_content_loss() {
+            return;
+        }
+
+        report.warnings = diagnostics
+            .skipped_objects()
+            .iter()
+            .map(|object| object.to_string())
+            .collect();
+        report.unknown_properties = diagnostics
+            .unknown_properties()
+            .map(|(id, count)| format!("0x{:08X} (found {} times)", id, count))
+            .collect();
+    }
+
     fn render_errors_to_file(
         &mut self,
-        errors: &Vec<String>,
+        report: &SectionReport,
         output_dir: &str,
     ) -> Result<TocEntry> {
-        let error_html = templates::errors::render(errors)?;
+        let error_html = templates::errors::render(report)?;
         let errors_path = self.write_html_file(output_dir, "Errors", &error_html)?;
         log!("Errors: {}", errors_path);
 
@@ -148,16 +340,47 @@ impl Renderer {
             is_error: true,
             name: ERRORS_NOTE_NAME.into(),
             relative_path: fs_driver().remove_prefix(&errors_path, output_dir).into(),
+            depth: 0,
         })
     }
 
+    fn write_task_report(&mut self, output_dir: &str, name: &str, tasks: &[Task]) -> Result<()> {
+        let title = format!("{} tasks", name);
+        let json_path = self.write_file(
+            output_dir,
+            &title,
+            ".json",
+            tasks::to_json(tasks)?.as_bytes(),
+        )?;
+        let ics_path = self.write_file(
+            output_dir,
+            &title,
+            ".ics",
+            tasks::to_icalendar(tasks).as_bytes(),
+        )?;
+        log!("Tasks: {}, {}", json_path, ics_path);
+
+        Ok(())
+    }
+
     fn write_html_file(&mut self, parent_dir: &str, title: &str, html: &str) -> Result<String> {
-        let filename = self.title_to_unique_safe_filename(parent_dir, title, ".html")?;
+        self.write_file(parent_dir, title, ".html", html.as_bytes())
+    }
+
+    fn write_file(
+        &mut self,
+        parent_dir: &str,
+        title: &str,
+        extension: &str,
+        data: &[u8],
+    ) -> Result<String> {
+        let filename = self.title_to_unique_safe_filename(parent_dir, title, extension)?;
         let path = fs_driver().join(parent_dir, &filename);
-        fs_driver().write_file(&path, html.as_bytes())?;
+        fs_driver().write_file(&path, data)?;
         Ok(path)
     }
 
+    #[allow(clippy::wrong_self_convention)]
     pub(crate) fn to_unique_safe_filename(
         &mut self,
         parent_dir: &str,
@@ -173,23 +396,39 @@ impl Renderer {
         filename_base: &str,
         extension: &str,
     ) -> Result<String> {
-        let filename = filename_base.trim().replace("/", "_");
-        let mut i = 0;
-        let mut current_filename =
-            fs_driver().sanitize_file_name(&format!("{}{}", filename, extension));
-
-        loop {
-            let current_full_path = fs_driver().join(parent_dir, &current_filename);
-            if !self.files.contains(&current_full_path) {
-                self.files.insert(current_full_path);
-                break;
-            }
+        Ok(unique_safe_filename(
+            &mut self.files,
+            parent_dir,
+            filename_base,
+            extension,
+        ))
+    }
+}
+
+/// Returns a file name in `parent_dir` for `filename_base` that isn't in `files`, and adds
+/// its path to `files`.
+pub(crate) fn unique_safe_filename(
+    files: &mut HashSet<String>,
+    parent_dir: &str,
+    filename_base: &str,
+    extension: &str,
+) -> String {
+    let filename = filename_base.trim().replace("/", "_");
+    let mut i = 0;
+    let mut current_filename =
+        fs_driver().sanitize_file_name(&format!("{}{}", filename, extension));
 
-            i += 1;
-            current_filename =
-                fs_driver().sanitize_file_name(&format!("{}_{}{}", filename, i, extension));
+    loop {
+        let current_full_path = fs_driver().join(parent_dir, &current_filename);
+        if !files.contains(&current_full_path) {
+            files.insert(current_full_path);
+            break;
         }
 
-        Ok(current_filename)
+        i += 1;
+        current_filename =
+            fs_driver().sanitize_file_name(&format!("{}_{}{}", filename, i, extension));
     }
+
+    current_filename
 }
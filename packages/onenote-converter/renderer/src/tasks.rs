@@ -0,0 +1,309 @@
+//! Task reports: lists of to-do and Outlook task note tags, written as JSON and as
+//! iCalendar (`VTODO`) files.
+
+use crate::errors::{ErrorKind, Result};
+use crate::page;
+use parser::contents::NoteTag;
+use parser::page::Page;
+use parser::property::note_tag::ActionItemType;
+use serde::{Serialize, Serializer};
+use time::UtcDateTime;
+
+/// A task-type note tag and the paragraph it's attached to.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Task {
+    id: String,
+    text: String,
+    label: Option<String>,
+    section: String,
+    page_title: String,
+    /// The rendered page, relative to the output directory.
+    page_path: String,
+    completed: bool,
+    due_type: Option<&'static str>,
+    #[serde(serialize_with = "serialize_time")]
+    created_at: UtcDateTime,
+    #[serde(serialize_with = "serialize_optional_time")]
+    due_at: Option<UtcDateTime>,
+    #[serde(serialize_with = "serialize_optional_time")]
+    completed_at: Option<UtcDateTime>,
+}
+
+impl Task {
+    /// Prefixes the path of the task's page with `dir`, for reports written to a parent of
+    /// the directory the path is relative to.
+    pub(crate) fn prefix_page_path(&mut self, dir: &str) {
+        self.page_path = format!("{}{}", dir, self.page_path);
+    }
+}
+
+/// Finds the tasks on a rendered page.
+pub(crate) fn collect_tasks(
+    page: &Page,
+    section_name: &str,
+    page_title: &str,
+    page_path: &str,
+) -> Vec<Task> {
+    page::paragraphs(page)
+        .into_iter()
+        .flat_map(|text| text.note_tags().iter().map(move |tag| (text, tag)))
+        .filter(|(_, tag)| is_task(tag))
+        .enumerate()
+        .map(|(index, (text, tag))| Task {
+            id: format!("{}-{}", page.link_target_id(), index),
+            text: text.text().trim().to_string(),
+            label: tag.definition().map(|def| def.label().to_string()),
+            section: section_name.to_string(),
+            page_title: page_title.to_string(),
+            page_path: page_path.to_string(),
+            completed: tag.item_status().completed(),
+            due_type: tag
+                .definition()
+                .and_then(|def| due_type_name(def.action_item_type())),
+            created_at: tag.created_at(),
+            due_at: tag.due_at(),
+            // Unchecked tasks can have a placeholder completion time.
+            completed_at: tag
+                .completed_at()
+                .filter(|_| tag.item_status().completed())
+                .map(UtcDateTime::from),
+        })
+        .collect()
+}
+
+fn is_task(tag: &NoteTag) -> bool {
+    if tag.item_status().task_tag() || tag.due_at().is_some() {
+        return true;
+    }
+
+    tag.definition().is_some_and(|def| {
+        def.shape().is_checkbox() || due_type_name(def.action_item_type()).is_some()
+    })
+}
+
+fn due_type_name(item_type: ActionItemType) -> Option<&'static str> {
+    match item_type {
+        ActionItemType::DueToday => Some("today"),
+        ActionItemType::DueTomorrow => Some("tomorrow"),
+        ActionItemType::DueThisWeek => Some("thisWeek"),
+        ActionItemType::DueNextWeek => Some("nextWeek"),
+        ActionItemType::NoDueDate => Some("none"),
+        ActionItemType::CustomDueDate => Some("custom"),
+        ActionItemType::Numeric(_) | ActionItemType::Unknown => None,
+    }
+}
+
+/// Formats the tasks as a JSON array.
+pub(crate) fn to_json(tasks: &[Task]) -> Result<String> {
+    serde_json::to_string_pretty(tasks).map_err(|error| {
+        ErrorKind::RenderFailed(format!("Unable to write tasks: {}", error)).into()
+    })
+}
+
+/// Formats the tasks as an iCalendar file with one `VTODO` per task.
+///
+/// See RFC 5545, section 3.6.2.
+pub(crate) fn to_icalendar(tasks: &[Task]) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Joplin//OneNote Converter//EN".to_string(),
+    ];
+
+    for task in tasks {
+        lines.push("BEGIN:VTODO".into());
+        lines.push(format!("UID:{}", task.id));
+        lines.push(format!(
+            "DTSTAMP:{}",
+            format_icalendar_time(task.created_at)
+        ));
+        lines.push(format!(
+            "CREATED:{}",
+            format_icalendar_time(task.created_at)
+        ));
+        lines.push(format!("SUMMARY:{}", escape_icalendar_text(&task.text)));
+        lines.push(format!(
+            "DESCRIPTION:{}",
+            escape_icalendar_text(&format!(
+                "{} > {} ({})",
+                task.section, task.page_title, task.page_path
+            ))
+        ));
+        if let Some(label) = &task.label {
+            lines.push(format!("CATEGORIES:{}", escape_icalendar_text(label)));
+        }
+        if let Some(due_at) = task.due_at {
+            lines.push(format!("DUE:{}", format_icalendar_time(due_at)));
+        }
+        if task.completed {
+            lines.push("STATUS:COMPLETED".into());
+        } else {
+            lines.push("STATUS:NEEDS-ACTION".into());
+        }
+        if let Some(completed_at) = task.completed_at {
+            lines.push(format!("COMPLETED:{}", format_icalendar_time(completed_at)));
+        }
+        lines.push("END:VTODO".into());
+    }
+
+    lines.push("END:VCALENDAR".into());
+
+    let mut output = String::new();
+    for line in lines {
+        output.push_str(&fold_icalendar_line(&line));
+        output.push_str("\r\n");
+    }
+    output
+}
+
+fn escape_icalendar_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace(['\r', '\n'], "\\n")
+}
+
+/// Splits lines longer than 75 bytes, as required by RFC 5545, section 3.1.
+fn fold_icalendar_line(line: &str) -> String {
+    let mut output = String::new();
+    let mut line_length = 0;
+
+    for c in line.chars() {
+        if line_length + c.len_utf8() > 75 {
+            output.push_str("\r\n ");
+            line_length = 1;
+        }
+        output.push(c);
+        line_length += c.len_utf8();
+    }
+
+    output
+}
+
+fn format_icalendar_time(time: UtcDateTime) -> String {
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+        time.year(),
+        time.month() as u8,
+        time.day(),
+        time.hour(),
+        time.minute(),
+        time.second()
+    )
+}
+
+fn format_iso_time(time: UtcDateTime) -> String {
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        time.year(),
+        time.month() as u8,
+        time.day(),
+        time.hour(),
+        time.minute(),
+        time.second()
+    )
+}
+
+fn serialize_time<S: Serializer>(
+    time: &UtcDateTime,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format_iso_time(*time))
+}
+
+fn serialize_optional_time<S: Serializer>(
+    time: &Option<UtcDateTime>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    match time {
+        Some(time) => serialize_time(time, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{collect_tasks, escape_icalendar_text, fold_icalendar_line};
+    use parser::Parser;
+    use parser_utils::fs_driver;
+    use time::macros::utc_datetime;
+
+    #[test]
+    fn should_collect_checkbox_tasks() {
+        let data = fs_driver()
+            .read_file("../test-data/Tagged lines.one")
+            .unwrap();
+        let section = Parser::new()
+            .parse_section_from_data(&data, "Tagged lines.one")
+            .unwrap();
+        let page = section
+            .page_series()
+            .iter()
+            .flat_map(|page_series| page_series.pages())
+            .find(|page| page.title_text().as_deref() == Some("Checklists"))
+            .unwrap();
+
+        let tasks = collect_tasks(
+            page,
+            "Tagged lines",
+            "Checklists",
+            "Tagged lines/Checklists.html",
+        );
+        let summary: Vec<_> = tasks
+            .iter()
+            .map(|task| (task.text.as_str(), task.label.as_deref(), task.completed))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "Checkbox with exclamation point",
+                    Some("Client request"),
+                    false
+                ),
+                ("Checkbox (complete)", Some("To Do"), true),
+                ("Sub-checkbox (incomplete)", Some("To Do"), false),
+                (
+                    "Sub-checkbox, with arrow (complete)",
+                    Some("Schedule meeting"),
+                    true
+                ),
+                (
+                    "Sub-checkbox, with arrow (complete)",
+                    Some("Schedule meeting"),
+                    true
+                ),
+            ]
+        );
+
+        let task = &tasks[1];
+        assert_eq!(task.id, format!("{}-1", page.link_target_id()));
+        assert_eq!(task.section, "Tagged lines");
+        assert_eq!(task.page_path, "Tagged lines/Checklists.html");
+        assert_eq!(task.due_at, None);
+        assert_eq!(task.completed_at, Some(utc_datetime!(2026-04-22 21:29:32)));
+        assert!(task.created_at <= task.completed_at.unwrap());
+
+        // Unchecked tasks have no completion time
+        assert_eq!(tasks[0].completed_at, None);
+        assert_eq!(tasks[2].completed_at, None);
+    }
+
+    #[test]
+    fn should_escape_and_fold_icalendar_text() {
+        assert_eq!(
+            escape_icalendar_text("Buy milk, eggs; bread\nthen\\done"),
+            "Buy milk\\, eggs\\; bread\\nthen\\\\done"
+        );
+
+        let line = format!("SUMMARY:{}", "a".repeat(100));
+        let folded = fold_icalendar_line(&line);
+        let folded_lines: Vec<&str> = folded.split("\r\n").collect();
+        assert_eq!(folded_lines.len(), 2);
+        assert_eq!(folded_lines[0].len(), 75);
+        assert!(folded_lines[1].starts_with(' '));
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::report::SectionReport;
 use askama::Template;
 use color_eyre::Result;
 use color_eyre::eyre::WrapErr;
@@ -5,11 +6,45 @@ use color_eyre::eyre::WrapErr;
 #[derive(Template)]
 #[template(path = "errors.html")]
 struct ErrorPageTemplate<'a> {
-    errors: &'a Vec<String>,
+    errors: Vec<String>,
+    content_loss: Vec<PageContentLoss<'a>>,
 }
 
-pub(crate) fn render(errors: &Vec<String>) -> Result<String> {
-    ErrorPageTemplate { errors }
-        .render()
-        .wrap_err("Failed to render error list template")
+/// Describes the content that couldn't be imported from a page.
+struct PageContentLoss<'a> {
+    page_title: &'a str,
+    skipped_objects: &'a [String],
+    unknown_properties: &'a [String],
+}
+
+/// Renders a page that lists the pages in `report` that failed to convert or lost content.
+pub(crate) fn render(report: &SectionReport) -> Result<String> {
+    let mut errors = Vec::new();
+    let mut content_loss = Vec::new();
+
+    for page in &report.pages {
+        let page_title = page.title.as_deref().unwrap_or("Untitled Page");
+
+        if let Some(error) = &page.parse_error {
+            errors.push(format!("Parse error: {}", error));
+        }
+        if let Some(error) = &page.render_error {
+            errors.push(format!("Render error for page {}: {}", page_title, error));
+        }
+
+        if !page.warnings.is_empty() {
+            content_loss.push(PageContentLoss {
+                page_title,
+                skipped_objects: &page.warnings,
+                unknown_properties: &page.unknown_properties,
+            });
+        }
+    }
+
+    ErrorPageTemplate {
+        errors,
+        content_loss,
+    }
+    .render()
+    .wrap_err("Failed to render error list template")
 }
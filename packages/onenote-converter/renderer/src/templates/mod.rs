@@ -2,3 +2,4 @@ pub(crate) mod errors;
 pub(crate) mod notebook;
 pub(crate) mod page;
 pub(crate) mod section;
+pub(crate) mod tag_summary;
@@ -1,3 +1,4 @@
+use crate::options::OutputFormat;
 use crate::utils::{StyleSet, html_entities};
 use askama::Template;
 use color_eyre::Result;
@@ -18,30 +19,68 @@ struct PageTemplate<'a> {
     created_date_attr: &'a str,
     updated_date_attr: &'a str,
     name: &'a str,
+    language: Option<&'a str>,
     content: &'a str,
     global_styles: Vec<(&'a String, &'a StyleSet)>,
 }
 
+#[derive(Template)]
+#[template(path = "page_fragment.html", escape = "none")]
+struct PageFragmentTemplate<'a> {
+    page_id_attr: &'a str,
+    page_order_index_attr: &'a str,
+    created_date_attr: &'a str,
+    updated_date_attr: &'a str,
+    name: &'a str,
+    language: Option<&'a str>,
+    content: &'a str,
+    global_styles: Vec<(&'a String, &'a StyleSet)>,
+}
+
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn render(
+    output_format: OutputFormat,
     page_id: &str,
     page_order_index: u32,
     timestamps: &PageTimestamps,
     name: &str,
+    language: Option<&str>,
     content: &str,
     global_styles: &HashMap<String, StyleSet>,
 ) -> Result<String> {
-    PageTemplate {
-        content,
-        name: &html_entities(name),
-        page_id_attr: &html_entities(page_id),
-        page_order_index_attr: &page_order_index.to_string(),
-        created_date_attr: &timestamps.created_time.to_string(),
-        updated_date_attr: &timestamps.updated_time.to_string(),
-        global_styles: global_styles
-            .iter()
-            .sorted_by(|(a, _), (b, _)| Ord::cmp(a, b))
-            .collect(),
+    let name = &html_entities(name);
+    let page_id_attr = &html_entities(page_id);
+    let page_order_index_attr = &page_order_index.to_string();
+    let created_date_attr = &timestamps.created_time.to_string();
+    let updated_date_attr = &timestamps.updated_time.to_string();
+    let global_styles = global_styles
+        .iter()
+        .sorted_by(|(a, _), (b, _)| Ord::cmp(a, b))
+        .collect();
+
+    match output_format {
+        OutputFormat::Html => PageTemplate {
+            content,
+            name,
+            language,
+            page_id_attr,
+            page_order_index_attr,
+            created_date_attr,
+            updated_date_attr,
+            global_styles,
+        }
+        .render(),
+        OutputFormat::HtmlFragment => PageFragmentTemplate {
+            content,
+            name,
+            language,
+            page_id_attr,
+            page_order_index_attr,
+            created_date_attr,
+            updated_date_attr,
+            global_styles,
+        }
+        .render(),
     }
-    .render()
     .wrap_err("Failed to render page template")
 }
@@ -6,7 +6,8 @@ use color_eyre::eyre::WrapErr;
 #[template(path = "section.html")]
 struct NotebookTemplate<'a> {
     name: &'a str,
-    pages: Vec<TocEntry>,
+    pages: Vec<TocItem<'a>>,
+    nested: bool,
 }
 
 pub(crate) struct TocEntry {
@@ -14,10 +15,45 @@ pub(crate) struct TocEntry {
     pub(crate) is_error: bool,
     pub(crate) relative_path: String,
     pub(crate) level: i32,
+    /// How deeply the page is nested below other pages in the section's page tree.
+    pub(crate) depth: usize,
 }
 
-pub(crate) fn render(name: &str, pages: Vec<TocEntry>) -> Result<String> {
-    let template = NotebookTemplate { name, pages };
+struct TocItem<'a> {
+    page: &'a TocEntry,
+    /// Whether the entry's subpages follow it, in a list within the entry.
+    opens_list: bool,
+    /// The number of subpage lists that end after the entry.
+    closes_lists: usize,
+}
+
+/// Renders a section's table of contents. If `nested` is true, subpages are listed within
+/// their parent page's entry. Otherwise, they're only indented.
+pub(crate) fn render(name: &str, pages: Vec<TocEntry>, nested: bool) -> Result<String> {
+    let pages = pages
+        .iter()
+        .enumerate()
+        .map(|(index, page)| {
+            let next_depth = pages.get(index + 1).map_or(0, |next| next.depth);
+            let opens_list = nested && next_depth > page.depth;
+            let closes_lists = if nested && !opens_list {
+                page.depth.saturating_sub(next_depth)
+            } else {
+                0
+            };
+
+            TocItem {
+                page,
+                opens_list,
+                closes_lists,
+            }
+        })
+        .collect();
+    let template = NotebookTemplate {
+        name,
+        pages,
+        nested,
+    };
 
     template
         .render()
@@ -0,0 +1,129 @@
+use askama::Template;
+use color_eyre::Result;
+use color_eyre::eyre::WrapErr;
+use parser::property::note_tag::NoteTagShape;
+use std::collections::BTreeMap;
+
+#[derive(Template)]
+#[template(path = "tag_summary.html")]
+struct TagSummaryTemplate<'a> {
+    name: &'a str,
+    groups: Vec<TagGroup<'a>>,
+}
+
+/// The tagged paragraphs of a rendered page.
+pub(crate) struct TaggedPage {
+    pub(crate) title: String,
+    /// The rendered page, relative to the output directory.
+    pub(crate) path: String,
+    pub(crate) paragraphs: Vec<TaggedParagraph>,
+}
+
+/// A paragraph with one or more note tags.
+pub(crate) struct TaggedParagraph {
+    /// The ID of the element the paragraph starts with.
+    pub(crate) anchor: String,
+    pub(crate) excerpt: String,
+    pub(crate) tags: Vec<ParagraphTag>,
+}
+
+pub(crate) struct ParagraphTag {
+    pub(crate) label: String,
+    pub(crate) shape: NoteTagShape,
+    /// Whether the tag is checked, for checkbox tags.
+    pub(crate) checked: Option<bool>,
+}
+
+struct TagGroup<'a> {
+    label: &'a str,
+    shape: String,
+    entries: Vec<TagEntry<'a>>,
+}
+
+struct TagEntry<'a> {
+    page: &'a TaggedPage,
+    paragraph: &'a TaggedParagraph,
+    checked: Option<bool>,
+}
+
+pub(crate) fn render(name: &str, pages: &[TaggedPage]) -> Result<String> {
+    let mut groups: BTreeMap<(&str, NoteTagShape), Vec<TagEntry>> = BTreeMap::new();
+
+    for page in pages {
+        for paragraph in &page.paragraphs {
+            for tag in &paragraph.tags {
+                groups
+                    .entry((&tag.label, tag.shape))
+                    .or_default()
+                    .push(TagEntry {
+                        page,
+                        paragraph,
+                        checked: tag.checked,
+                    });
+            }
+        }
+    }
+
+    let groups = groups
+        .into_iter()
+        .map(|((label, shape), entries)| TagGroup {
+            label,
+            shape: format!("{:?}", shape),
+            entries,
+        })
+        .collect();
+
+    TagSummaryTemplate { name, groups }
+        .render()
+        .wrap_err("Failed to render tag summary template")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ParagraphTag, TaggedPage, TaggedParagraph, render};
+    use parser::property::note_tag::NoteTagShape;
+
+    fn paragraph(
+        anchor: &str,
+        excerpt: &str,
+        label: &str,
+        checked: Option<bool>,
+    ) -> TaggedParagraph {
+        TaggedParagraph {
+            anchor: anchor.into(),
+            excerpt: excerpt.into(),
+            tags: vec![ParagraphTag {
+                label: label.into(),
+                shape: if checked.is_some() {
+                    NoteTagShape::BlueCheckBox
+                } else {
+                    NoteTagShape::YellowStar
+                },
+                checked,
+            }],
+        }
+    }
+
+    #[test]
+    fn should_group_paragraphs_by_tag() {
+        let pages = vec![TaggedPage {
+            title: "Plans".into(),
+            path: "Section/Plans.html".into(),
+            paragraphs: vec![
+                paragraph("note-tag-0", "Buy milk", "To Do", Some(true)),
+                paragraph("note-tag-1", "Remember this", "Important", None),
+                paragraph("note-tag-2", "Call back", "To Do", Some(false)),
+            ],
+        }];
+
+        let html = render("Notebook", &pages).unwrap();
+
+        let important = html.find("Important").unwrap();
+        let to_do = html.find("To Do").unwrap();
+        assert!(important < to_do, "groups should be sorted by label");
+        assert!(html.find("Buy milk").unwrap() < html.find("Call back").unwrap());
+        assert!(html.contains("aria-checked=\"true\""));
+        assert!(html.contains("aria-checked=\"false\""));
+        assert!(html.contains("href=\"Section/Plans.html#note-tag-2\""));
+    }
+}
@@ -6,8 +6,9 @@ use std::fmt;
 use std::fmt::Display;
 use widestring::U16CString;
 
-pub(crate) fn px(inches: f32) -> String {
-    format!("{}px", (inches * 48.0).round())
+/// Converts a length in half-inch increments to pixels.
+pub(crate) fn px(half_inches: f32, px_per_half_inch: f32) -> String {
+    format!("{}px", (half_inches * px_per_half_inch).round())
 }
 
 #[derive(Clone)]
@@ -21,6 +22,10 @@ impl AttributeSet {
     pub(crate) fn set(&mut self, attribute: &'static str, value: String) {
         self.0.insert(attribute, value);
     }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
 }
 
 impl<const N: usize> From<[(&'static str, String); N]> for AttributeSet {
@@ -129,18 +134,6 @@ pub(crate) fn url_encode(url: &str) -> String {
     utf8_percent_encode(url, ENCODED_CHARS).to_string()
 }
 
-pub(crate) fn detect_png(header: &[u8]) -> bool {
-    // PNGs start with a specific set of bytes. See https://en.wikipedia.org/wiki/PNG
-    header.len() > 6
-        && header[0] == 0x89
-        && header[1] == 0x50 // 'P'
-        && header[2] == 0x4E // 'N'
-        && header[3] == 0x47 // 'G'
-        && header[4] == 0x0D // \r
-        && header[5] == 0x0A // \n
-        && header[6] == 0x1A
-}
-
 #[cfg(test)]
 mod test {
     use crate::utils::{AttributeSet, url_encode};
@@ -1,6 +1,11 @@
-use renderer::convert;
+use renderer::{
+    AssetLayout, CancellationToken, ConvertOptions, ErrorPolicy, InkMode, OcrTextMode,
+    OutputFormat, PageLayout, PageNaming, Progress, SubpageLayout, convert, convert_with_options,
+    convert_with_progress,
+};
 use std::fs;
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 
 struct TestResources {
     output_dir: PathBuf,
@@ -76,6 +81,43 @@ fn convert_desktop_export() {
     );
 }
 
+#[test]
+fn convert_ink_with_options() {
+    let TestResources {
+        output_dir,
+        test_data_dir,
+    } = setup("ink_with_options");
+
+    convert_with_options(
+        &test_data_dir.join("ink.one").to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &test_data_dir.to_string_lossy(),
+        &ConvertOptions {
+            output_format: OutputFormat::HtmlFragment,
+            asset_layout: AssetLayout::Subfolder,
+            ink_mode: InkMode::SvgFile,
+            page_naming: PageNaming::IndexAndTitle,
+            ..ConvertOptions::default()
+        },
+    )
+    .unwrap();
+
+    // Should name the page after its position and title
+    let content_file = output_dir.join("ink").join("001 Testing….html");
+    let rendered_file = fs::read_to_string(content_file).expect("should read the content file");
+
+    // Should write a fragment, rather than a full document
+    assert!(rendered_file.starts_with("<div class=\"onenote-page\""));
+    assert!(!rendered_file.contains("<html>"));
+
+    // Should write the ink to SVG files in the assets folder
+    assert!(!rendered_file.contains("<svg"));
+    assert!(rendered_file.contains("<img alt=\"\" src=\"assets/ink.svg\""));
+    let ink_file = fs::read_to_string(output_dir.join("ink").join("assets").join("ink.svg"))
+        .expect("should read the ink file");
+    assert!(ink_file.starts_with("<svg "));
+}
+
 #[test]
 fn convert_page_versions() {
     let TestResources {
@@ -144,17 +186,22 @@ fn convert_printout() {
     let rendered_file = fs::read_to_string(content_file).expect("should read the content file");
     // Should correctly detect the file extension:
     assert!(
-        !rendered_file.contains("<img src=\"test4_1.pdf\""),
+        !rendered_file.contains(" src=\"test4_1.pdf\""),
         "should not use the PDF extension for printout pages"
     );
     assert!(
-        rendered_file.contains("<img src=\"test4_1.pdf.png\""),
+        rendered_file.contains(" src=\"test4_1.png\""),
         "should import as a PNG"
     );
+    assert_eq!(
+        rendered_file.matches("href=\"test4.pdf\"").count(),
+        1,
+        "should link to the printed document once"
+    );
 
     // Should correctly create the PNG
     let png_data =
-        fs::read(output_dir.join("Printout").join("test4_1.pdf.png")).expect("should read the PNG");
+        fs::read(output_dir.join("Printout").join("test4_1.png")).expect("should read the PNG");
     assert_eq!(
         png_data[0..4],
         [0x89, 0x50, 0x4E, 0x47],
@@ -166,3 +213,583 @@ fn convert_printout() {
         "PNG should have the correct byte length"
     );
 }
+
+#[test]
+fn convert_ocr_text_to_hidden_block() {
+    let TestResources {
+        output_dir,
+        test_data_dir,
+    } = setup("ocr_text");
+
+    let options = ConvertOptions {
+        ocr_text: OcrTextMode::HiddenBlock,
+        ..ConvertOptions::default()
+    };
+    let report = convert_with_options(
+        &test_data_dir.join("Printout.one").to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &test_data_dir.to_string_lossy(),
+        &options,
+    )
+    .unwrap();
+
+    let content_file = output_dir.join("Printout").join("Test.html");
+    let rendered_file = fs::read_to_string(content_file).expect("should read the content file");
+    assert!(rendered_file.contains("<div class=\"ocr-text\" hidden>Test\nTest!\nmaln</div>"));
+    assert!(!rendered_file.contains("data-onenote-ocr-text"));
+
+    assert_eq!(report.sections[0].pages[0].ocr_text, ["Test\nTest!\nmaln"]);
+}
+
+#[test]
+fn convert_grid_page() {
+    let TestResources {
+        output_dir,
+        test_data_dir,
+    } = setup("grid_page");
+
+    convert(
+        &test_data_dir.join("Grid page.one").to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &test_data_dir.to_string_lossy(),
+    )
+    .unwrap();
+
+    assert!(output_dir.join("Grid page.html").exists());
+
+    // Should draw the grid as a page background
+    let content_file = output_dir
+        .join("Grid page")
+        .join("Ink Missing - only one example missing part.html");
+    let rendered_file = fs::read_to_string(content_file).expect("should read the content file");
+    assert!(rendered_file.contains("background-size: 100% 16px, 16px 100%;"));
+}
+
+#[test]
+fn convert_locked_columns() {
+    let TestResources {
+        output_dir,
+        test_data_dir,
+    } = setup("locked_columns");
+
+    convert(
+        &test_data_dir.join("Locked columns.one").to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &test_data_dir.to_string_lossy(),
+    )
+    .unwrap();
+
+    // The first and third columns are locked, so only their cells should have a fixed width
+    let content_file = output_dir.join("Locked columns").join("Test.html");
+    let rendered_file = fs::read_to_string(content_file).expect("should read the content file");
+    let rows: Vec<&str> = rendered_file.split("<tr>").skip(1).collect();
+    assert_eq!(rows.len(), 2);
+    for row in rows {
+        let styles: Vec<&str> = row
+            .split("<td ")
+            .skip(1)
+            .map(|cell| cell.split('>').next().unwrap())
+            .collect();
+        assert_eq!(styles.len(), 3);
+        assert!(styles[0].contains(" width: 85px;"), "{}", styles[0]);
+        assert!(!styles[1].contains(" width:"), "{}", styles[1]);
+        assert!(styles[2].contains(" width: 72px;"), "{}", styles[2]);
+    }
+    assert_eq!(rendered_file.matches(" width: 85px;").count(), 2);
+    assert_eq!(rendered_file.matches(" width: 72px;").count(), 2);
+}
+
+#[test]
+fn convert_with_custom_scale() {
+    let TestResources {
+        output_dir,
+        test_data_dir,
+    } = setup("custom_scale");
+
+    convert_with_options(
+        &test_data_dir.join("Locked columns.one").to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &test_data_dir.to_string_lossy(),
+        &ConvertOptions {
+            px_per_half_inch: 96.0,
+            ..ConvertOptions::default()
+        },
+    )
+    .unwrap();
+
+    // Sizes and positions should be twice as large as with the default scale
+    let content_file = output_dir.join("Locked columns").join("Test.html");
+    let rendered_file = fs::read_to_string(content_file).expect("should read the content file");
+    assert_eq!(rendered_file.matches("min-width: 96px;").count(), 6);
+    assert!(
+        rendered_file
+            .contains(r#"class="title" style="left: 96px; position: absolute; top: 48px;""#)
+    );
+}
+
+#[test]
+fn convert_ragged_table() {
+    let TestResources {
+        output_dir,
+        test_data_dir,
+    } = setup("ragged_table");
+
+    convert(
+        &test_data_dir.join("Ragged table.one").to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &test_data_dir.to_string_lossy(),
+    )
+    .unwrap();
+
+    // The second row only has one cell, so it should be padded with an empty cell
+    let content_file = output_dir.join("Ragged table").join("Test.html");
+    let rendered_file = fs::read_to_string(content_file).expect("should read the content file");
+    let rows: Vec<&str> = rendered_file.split("<tr>").skip(1).collect();
+    assert_eq!(rows.len(), 2);
+    for row in rows {
+        assert_eq!(row.matches("<td ").count(), 2);
+    }
+    assert!(rendered_file.contains("></td></tr>"));
+}
+
+#[test]
+fn convert_grid_page_with_flow_layout() {
+    let TestResources {
+        output_dir,
+        test_data_dir,
+    } = setup("grid_page_flow_layout");
+
+    convert_with_options(
+        &test_data_dir.join("Grid page.one").to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &test_data_dir.to_string_lossy(),
+        &ConvertOptions {
+            page_layout: PageLayout::Flow,
+            ..ConvertOptions::default()
+        },
+    )
+    .unwrap();
+
+    let content_file = output_dir
+        .join("Grid page")
+        .join("Ink Missing - only one example missing part.html");
+    let rendered_file = fs::read_to_string(content_file).expect("should read the content file");
+    assert!(rendered_file.contains("<div class=\"title\" style=\"margin-left: 48px;\">"));
+
+    // The outlines should follow each other from top to bottom, keeping their horizontal
+    // offsets
+    let outline_offsets: Vec<_> = rendered_file
+        .match_indices("<div class=\"container-outline\" style=\"display: block; margin-left: ")
+        .map(|(index, pattern)| {
+            let offset = &rendered_file[index + pattern.len()..];
+            &offset[..offset.find(';').unwrap()]
+        })
+        .collect();
+    assert_eq!(outline_offsets, ["262px", "560px", "285px", "277px"]);
+    assert!(!rendered_file.contains("class=\"container-outline\" style=\"left:"));
+}
+
+#[test]
+fn convert_subpages_into_folders() {
+    let TestResources {
+        output_dir,
+        test_data_dir,
+    } = setup("subpages_into_folders");
+
+    convert_with_options(
+        &test_data_dir.join("Subpages.one").to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &test_data_dir.to_string_lossy(),
+        &ConvertOptions {
+            subpage_layout: SubpageLayout::Folders,
+            ..ConvertOptions::default()
+        },
+    )
+    .unwrap();
+
+    // Should write subpages to a folder named after their parent page
+    let section_dir = output_dir.join("Section");
+    assert!(section_dir.join("Page 2.html").exists());
+    assert!(section_dir.join("Page 2").join("Page 2-a.html").exists());
+    assert!(
+        section_dir
+            .join("Page 2")
+            .join("Page 2-a")
+            .join("Page 2-a-b.html")
+            .exists()
+    );
+    assert!(!section_dir.join("Page 1-a").exists());
+
+    // Should nest subpages in the table of contents
+    let toc = fs::read_to_string(output_dir.join("Section.html")).unwrap();
+    let page_2_a = toc.find("title=\"Page 2-a\"").unwrap();
+    let page_2_a_b = toc.find("title=\"Page 2-a-b\"").unwrap();
+    let page_2_b = toc.find("title=\"Page 2-b\"").unwrap();
+    assert!(toc[page_2_a..page_2_a_b].contains("<ul>"));
+    assert!(toc[page_2_a_b..page_2_b].contains("</ul></li>"));
+}
+
+#[test]
+fn convert_audio_recording() {
+    let TestResources {
+        output_dir,
+        test_data_dir,
+    } = setup("audio_recording");
+
+    convert_with_options(
+        &test_data_dir.join("Audio recording.one").to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &test_data_dir.to_string_lossy(),
+        &ConvertOptions {
+            media_elements: true,
+            ..ConvertOptions::default()
+        },
+    )
+    .unwrap();
+
+    let section_dir = output_dir.join("Audio recording");
+    assert!(section_dir.join("Audio Recording.wav").exists());
+
+    // Should render the recording as an audio element
+    let content_file = fs::read_dir(&section_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| path.extension().is_some_and(|ext| ext == "html"))
+        .expect("should convert the page");
+    let rendered_file = fs::read_to_string(content_file).expect("should read the content file");
+    assert!(rendered_file.contains("<audio controls"));
+    assert!(rendered_file.contains("type=\"audio/wav\""));
+    assert!(rendered_file.contains("data-onenote-duration=\"5168\""));
+}
+
+#[test]
+fn convert_onepkg() {
+    let TestResources {
+        output_dir,
+        test_data_dir,
+    } = setup("onepkg");
+
+    convert_with_options(
+        &test_data_dir.join("test.onepkg").to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &test_data_dir.to_string_lossy(),
+        &ConvertOptions {
+            stream_buffer_size: 4096,
+            ..ConvertOptions::default()
+        },
+    )
+    .unwrap();
+
+    // Should convert sections in section groups
+    assert!(output_dir.join("Section group").join("A.html").exists());
+    assert!(
+        output_dir
+            .join("Another section")
+            .join("Page 1.html")
+            .exists()
+    );
+
+    // Should extract the sections outside of the output directory
+    let has_temporary_files = fs::read_dir(&output_dir)
+        .unwrap()
+        .chain(fs::read_dir(output_dir.join("Section group")).unwrap())
+        .any(|entry| {
+            entry
+                .unwrap()
+                .path()
+                .extension()
+                .is_some_and(|ext| ext == "one" || ext == "tmp")
+        });
+    assert!(!has_temporary_files);
+}
+
+#[test]
+fn convert_with_progress_reports_progress() {
+    let TestResources {
+        output_dir,
+        test_data_dir,
+    } = setup("progress");
+
+    let updates = Arc::new(Mutex::new(Vec::new()));
+    let listener_updates = updates.clone();
+    convert_with_progress(
+        &test_data_dir.join("test.onepkg").to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &test_data_dir.to_string_lossy(),
+        &ConvertOptions::default(),
+        Arc::new(move |progress| listener_updates.lock().unwrap().push(progress)),
+        CancellationToken::new(),
+    )
+    .unwrap();
+
+    let updates = updates.lock().unwrap();
+    let last: Progress = *updates.last().unwrap();
+    assert!(last.sections_total > 1);
+    assert_eq!(last.sections_done, last.sections_total);
+    assert!(last.pages_total > 0);
+    assert_eq!(last.pages_done, last.pages_total);
+    assert!(last.bytes_total > 0);
+    assert_eq!(last.bytes_done, last.bytes_total);
+}
+
+#[test]
+fn convert_with_progress_can_be_cancelled() {
+    let TestResources {
+        output_dir,
+        test_data_dir,
+    } = setup("cancellation");
+
+    // Cancel as soon as the first page has been converted
+    let cancellation = CancellationToken::new();
+    let listener_cancellation = cancellation.clone();
+    let last_progress = Arc::new(Mutex::new(Progress::default()));
+    let listener_last_progress = last_progress.clone();
+    let result = convert_with_progress(
+        &test_data_dir.join("test.onepkg").to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &test_data_dir.to_string_lossy(),
+        &ConvertOptions::default(),
+        Arc::new(move |progress: Progress| {
+            *listener_last_progress.lock().unwrap() = progress;
+            if progress.pages_done > 0 {
+                listener_cancellation.cancel();
+            }
+        }),
+        cancellation,
+    );
+
+    let error = result.unwrap_err();
+    assert!(error.to_string().contains("Conversion cancelled"));
+    // With the `parallel` feature, pages of other sections may finish before the
+    // cancellation is noticed
+    let last_progress = *last_progress.lock().unwrap();
+    assert!(last_progress.pages_done >= 1);
+    assert!(last_progress.pages_done < last_progress.pages_total);
+}
+
+#[test]
+fn convert_returns_report() {
+    let TestResources {
+        output_dir,
+        test_data_dir,
+    } = setup("report");
+    let test_data_dir = test_data_dir.join("onenote-2016");
+
+    let report = convert(
+        &test_data_dir.join("OneWithFileData.one").to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &test_data_dir.to_string_lossy(),
+    )
+    .unwrap();
+
+    assert!(!report.has_errors());
+    assert_eq!(report.sections.len(), 1);
+    let section = &report.sections[0];
+    assert_eq!(section.name, "OneWithFileData");
+    assert!(section.source_path.ends_with("OneWithFileData.one"));
+
+    let page = &section.pages[0];
+    let page_path = PathBuf::from(page.output_path.as_ref().unwrap());
+    assert_eq!(
+        page_path,
+        output_dir
+            .join("OneWithFileData")
+            .join("Untitled Page 1.html")
+    );
+    assert!(page.counts.outlines > 0);
+    assert!(page.counts.embedded_files > 0);
+}
+
+#[test]
+fn convert_reports_sections_that_fail_to_parse() {
+    let TestResources {
+        output_dir,
+        test_data_dir,
+    } = setup("report_parse_failure");
+
+    let truncated_path = output_dir.join("Truncated.one");
+    let data = fs::read(test_data_dir.join("ink.one")).unwrap();
+    fs::write(&truncated_path, &data[..data.len() / 2]).unwrap();
+
+    let report = convert(
+        &truncated_path.to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &output_dir.to_string_lossy(),
+    )
+    .unwrap();
+
+    // Should record the failure, rather than failing the conversion
+    assert!(report.has_errors());
+    assert_eq!(report.sections[0].name, "Truncated");
+    assert!(report.sections[0].error.is_some());
+    assert!(report.to_json().unwrap().contains(r#""error":"#));
+
+    let result = convert_with_options(
+        &truncated_path.to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &ConvertOptions {
+            error_policy: ErrorPolicy::Abort,
+            ..ConvertOptions::default()
+        },
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn convert_notebook_task_report() {
+    let TestResources {
+        output_dir,
+        test_data_dir,
+    } = setup("notebook_task_report");
+
+    // A notebook with the same tasks in a section and in a section group
+    convert_with_options(
+        &test_data_dir
+            .join("Section group notebook")
+            .join("Open Notebook.onetoc2")
+            .to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &test_data_dir.to_string_lossy(),
+        &ConvertOptions {
+            task_report: true,
+            ..ConvertOptions::default()
+        },
+    )
+    .unwrap();
+
+    let notebook_dir = output_dir.join("Section group notebook");
+    assert!(
+        notebook_dir
+            .join("Section A")
+            .join("Section A1 tasks.ics")
+            .exists()
+    );
+    let report = fs::read_to_string(notebook_dir.join("Section group notebook tasks.json"))
+        .expect("should write the notebook's task report");
+    let tasks: Vec<serde_json::Value> = serde_json::from_str(&report).unwrap();
+    assert_eq!(tasks.len(), 10);
+
+    // Page paths should be relative to the notebook's output directory
+    let page_paths: Vec<&str> = tasks
+        .iter()
+        .map(|task| task["pagePath"].as_str().unwrap())
+        .collect();
+    assert!(page_paths.contains(&"/Section/Checklists.html"));
+    assert!(page_paths.contains(&"/Section A/Section A1/Checklists.html"));
+    for page_path in page_paths {
+        assert!(
+            notebook_dir
+                .join(page_path.trim_start_matches('/'))
+                .exists()
+        );
+    }
+}
+
+#[test]
+fn convert_notebook_tag_summary() {
+    let TestResources {
+        output_dir,
+        test_data_dir,
+    } = setup("notebook_tag_summary");
+
+    // A notebook with the same tagged paragraphs in a section and in a section group
+    convert_with_options(
+        &test_data_dir
+            .join("Section group notebook")
+            .join("Open Notebook.onetoc2")
+            .to_string_lossy(),
+        &output_dir.to_string_lossy(),
+        &test_data_dir.to_string_lossy(),
+        &ConvertOptions {
+            tag_summary: true,
+            ..ConvertOptions::default()
+        },
+    )
+    .unwrap();
+
+    let notebook_dir = output_dir.join("Section group notebook");
+    let summary = fs::read_to_string(notebook_dir.join("Section group notebook tags.html"))
+        .expect("should write the tag summary");
+    let links: Vec<&str> = summary
+        .split("href=\"")
+        .skip(1)
+        .map(|link| link.split('"').next().unwrap())
+        .collect();
+    assert!(!links.is_empty());
+
+    // Links should point to the anchors on the pages, relative to the notebook's output
+    // directory
+    for link in &links {
+        let (path, anchor) = link.split_once('#').unwrap();
+        let page_file = notebook_dir.join(path.trim_start_matches('/').replace("%20", " "));
+        let page = fs::read_to_string(&page_file).expect("should link to an existing page");
+        assert!(page.contains(&format!("id=\"{}\"", anchor)), "{}", link);
+    }
+    assert!(
+        links
+            .iter()
+            .any(|link| link.starts_with("/Section/Checklists.html#"))
+    );
+    assert!(
+        links
+            .iter()
+            .any(|link| link.starts_with("/Section%20A/Section%20A1/Checklists.html#"))
+    );
+}
+
+#[test]
+fn convert_unknown_content_placeholders() {
+    let TestResources {
+        output_dir,
+        test_data_dir,
+    } = setup("unknown_content_placeholders");
+
+    // A section where the page's outline has the type of an iframe, which isn't supported as
+    // page content
+    let input = test_data_dir.join("Unsupported content.one");
+    let default_dir = output_dir.join("default");
+    let disabled_dir = output_dir.join("without_placeholders");
+    fs::create_dir_all(&default_dir).unwrap();
+    fs::create_dir_all(&disabled_dir).unwrap();
+
+    convert(
+        &input.to_string_lossy(),
+        &default_dir.to_string_lossy(),
+        &test_data_dir.to_string_lossy(),
+    )
+    .unwrap();
+
+    // Should mark where the content was on the page
+    let page_file = output_dir
+        .join("default")
+        .join("Unsupported content")
+        .join("Bold & italic.html");
+    let rendered_file = fs::read_to_string(page_file).expect("should read the page file");
+    assert!(rendered_file.contains(
+        "<div class=\"content-placeholder\" data-onenote-jcid=\"0x00060058\" \
+         style=\"left: 48px; min-height: 29px; position: absolute; top: 115px; width: 720px;\">"
+    ));
+    assert!(rendered_file.contains("Unsupported content (IFrameNode)"));
+
+    convert_with_options(
+        &input.to_string_lossy(),
+        &disabled_dir.to_string_lossy(),
+        &test_data_dir.to_string_lossy(),
+        &ConvertOptions {
+            content_placeholders: false,
+            ..ConvertOptions::default()
+        },
+    )
+    .unwrap();
+
+    // Should leave the content out
+    let page_file = output_dir
+        .join("without_placeholders")
+        .join("Unsupported content")
+        .join("Bold & italic.html");
+    let rendered_file = fs::read_to_string(page_file).expect("should read the page file");
+    assert!(!rendered_file.contains("<div class=\"content-placeholder\""));
+    assert!(!rendered_file.contains("data-onenote-jcid"));
+    assert!(!rendered_file.contains("Unsupported content ("));
+}